@@ -34,11 +34,13 @@ export class JsBindgen {
 				},
 			},
 			web_sys: {
-				'console.log2': globalThis.console.log,
 				'console.log': globalThis.console.log,
 				'console.log0': globalThis.console.log,
+				'console.log2': globalThis.console.log,
 			},
 		};
+        Object.assign(this, {
+		});
     }
     get importObject() {
         if (this.#finished) {
@@ -81,11 +83,18 @@ export class JsBindgen {
             throw "create a new `JsBindgen` class";
         }
         this.#finished = true;
-        return WebAssembly.instantiate(this.#module, this.#importObject);
+        return WebAssembly.instantiate(this.#module, this.#importObject).then((instance) => {
+            this.#instance = instance;
+            return instance;
+        });
     }
     static async instantiateStreaming(...args) {
         let response;
         if (args.length === 0) {
+            const wasm = undefined;
+            if (wasm !== undefined) {
+                return new JsBindgen(await WebAssembly.compile(wasm)).instantiate();
+            }
             const url = import.meta.url.replace(/\.mjs$/, ".wasm");
             response = fetch(url);
         }
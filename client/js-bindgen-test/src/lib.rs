@@ -1,7 +1,8 @@
 use std::panic::PanicHookInfo;
 
 pub use js_bindgen_test_macro::test;
-use js_sys::{JsString, js_sys};
+use js_sys::hazard::ExportOutput;
+use js_sys::{JsPromise, JsString, JsValue, js_sys};
 
 #[js_sys]
 extern "C" {
@@ -36,3 +37,17 @@ pub fn set_panic_hook() {
 		}));
 	});
 }
+
+/// Runs an async test, returns the `externref` table slot of its promise.
+#[doc(hidden)]
+pub fn run_async<F>(test: F) -> i32
+where
+	F: 'static + Future<Output = ()>,
+{
+	let promise: JsPromise = js_sys::future_to_promise(async {
+		test.await;
+		Ok(JsValue::UNDEFINED)
+	});
+
+	promise.into_raw()
+}
@@ -1,9 +1,9 @@
+use alloc::vec::Vec;
 use core::marker::PhantomData;
 
 use js_sys_macro::js_sys;
 
-use crate::JsValue;
-use crate::util::PtrLength;
+use crate::{JsCast, JsValue, r#macro};
 
 impl<T> JsArray<T> {
 	#[must_use]
@@ -13,45 +13,102 @@ impl<T> JsArray<T> {
 			_type: PhantomData,
 		}
 	}
+
+	fn as_any_ref(&self) -> &JsArray {
+		r#macro::cast_ref(self)
+	}
+
+	/// Returns the number of elements in this array.
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.as_any_ref().length() as usize
+	}
+
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+}
+
+impl<T: JsCast> JsArray<T> {
+	/// Creates a new empty array.
+	#[must_use]
+	pub fn new() -> Self {
+		r#macro::cast(array_new())
+	}
+
+	/// Returns the element at `index`, or [`None`] if it is out of bounds.
+	///
+	/// The element is not type-checked.
+	#[must_use]
+	pub fn get(&self, index: usize) -> Option<T> {
+		(index < self.len()).then(|| {
+			#[expect(
+				clippy::cast_possible_truncation,
+				reason = "JS arrays are limited to `u32` indices"
+			)]
+			array_get(self.as_any_ref(), index as u32).unchecked_into()
+		})
+	}
+
+	/// Appends `value` to the end of this array.
+	pub fn push(&self, value: &T) {
+		self.as_any_ref().js_push(value.as_ref());
+	}
+
+	/// Returns an iterator over the elements of this array.
+	///
+	/// The elements are not type-checked.
+	#[must_use]
+	pub fn iter(&self) -> impl DoubleEndedIterator<Item = T> + ExactSizeIterator {
+		#[expect(
+			clippy::cast_possible_truncation,
+			reason = "JS arrays are limited to `u32` indices"
+		)]
+		(0..self.len()).map(|index| array_get(self.as_any_ref(), index as u32).unchecked_into())
+	}
+
+	/// Copies the elements of this array into a new [`Vec`].
+	#[must_use]
+	pub fn to_vec(&self) -> Vec<T> {
+		self.iter().collect()
+	}
+}
+
+impl<T: JsCast> Default for JsArray<T> {
+	fn default() -> Self {
+		Self::new()
+	}
 }
 
-impl From<&[u32]> for JsArray<u32> {
-	fn from(value: &[u32]) -> Self {
-		js_bindgen::embed_js!(
-			name = "array.u32.decode",
-			js_embed = "array.isLittleEndian",
-			"(ptr, len) => {{",
-			"	if (this.#jsEmbed.js_sys['array.isLittleEndian']) {{",
-			"		const view = new Uint32Array(this.#memory.buffer, ptr, len)",
-			"		return Array.from(view)",
-			"	}} else {{",
-			"		const view = new DataView(this.#memory.buffer, ptr, len * 4)",
-			"		const array = new Array(len)",
-			"		for (let i = 0; i < len; i++) {{",
-			"			array[i] = view.getUint32(i * 4, true)",
-			"		}}",
-			"		return array",
-			"	}}",
-			"}}",
-		);
-
-		array_u32_decode(value.as_ptr(), PtrLength::new(value.as_ptr(), value.len()))
+impl<T: JsCast> FromIterator<T> for JsArray<T> {
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+		let array = Self::new();
+
+		for value in iter {
+			array.push(&value);
+		}
+
+		array
 	}
 }
 
-js_bindgen::embed_js!(
-	name = "array.isLittleEndian",
-	"(() => {{",
-	"	const buffer = new ArrayBuffer(2)",
-	"	new DataView(buffer).setInt16(0, 256, true)",
-	"	return new Int16Array(buffer)[0] === 256;",
-	"}})()",
-);
+js_bindgen::embed_js!(name = "array.get", "(array, index) => array[index]");
 
 #[js_sys(js_sys = crate)]
 extern "C" {
+	#[js_sys(is_type_of = "Array.isArray", ts_type = "unknown[]")]
 	pub type JsArray<T = JsValue>;
 
-	#[js_sys(js_embed = "array.u32.decode")]
-	fn array_u32_decode(array: *const u32, len: PtrLength) -> JsArray<u32>;
+	#[js_sys(js_name = "Array")]
+	fn array_new() -> JsArray;
+
+	#[js_sys(js_embed = "array.get")]
+	fn array_get(array: &JsArray, index: u32) -> JsValue;
+
+	#[js_sys(getter)]
+	fn length(self: &JsArray) -> u32;
+
+	#[js_sys(method, js_name = "push")]
+	fn js_push(self: &JsArray, value: &JsValue) -> u32;
 }
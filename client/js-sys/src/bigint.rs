@@ -0,0 +1,321 @@
+use core::cmp::Ordering;
+use core::error::Error;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::mem::ManuallyDrop;
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use js_sys_macro::js_sys;
+
+use crate::hazard::{Input, Output};
+use crate::panic::panic;
+use crate::{JsString, JsValue};
+
+macro_rules! from {
+	($from:ident: $($ty:ty),*) => {$(
+		impl From<$ty> for JsBigInt {
+			fn from(value: $ty) -> Self {
+				$from(value.into())
+			}
+		}
+	)*};
+}
+
+from!(bigint_from_i64: i8, i16, i32, i64);
+from!(bigint_from_u64: u8, u16, u32, u64);
+
+impl From<i128> for JsBigInt {
+	#[expect(
+		clippy::cast_possible_truncation,
+		clippy::cast_sign_loss,
+		reason = "split into halves"
+	)]
+	fn from(value: i128) -> Self {
+		bigint_from_i128(value as u64, (value >> 64) as i64)
+	}
+}
+
+impl From<u128> for JsBigInt {
+	#[expect(clippy::cast_possible_truncation, reason = "split into halves")]
+	fn from(value: u128) -> Self {
+		bigint_from_u128(value as u64, (value >> 64) as u64)
+	}
+}
+
+macro_rules! try_from {
+	($($ty:ty: $bits:literal, $signed:literal, |$value:ident| $convert:expr;)*) => {$(
+		impl TryFrom<&JsBigInt> for $ty {
+			type Error = TryFromBigIntError;
+
+			fn try_from($value: &JsBigInt) -> Result<Self, Self::Error> {
+				if bigint_fits($value, $bits, $signed) {
+					Ok($convert)
+				} else {
+					Err(TryFromBigIntError(()))
+				}
+			}
+		}
+
+		impl TryFrom<JsBigInt> for $ty {
+			type Error = TryFromBigIntError;
+
+			fn try_from(value: JsBigInt) -> Result<Self, Self::Error> {
+				Self::try_from(&value)
+			}
+		}
+	)*};
+}
+
+try_from!(
+	i64: 64, true, |value| bigint_to_i64(value);
+	u64: 64, false, |value| bigint_to_u64(value);
+	i128: 128, true, |value| {
+		(i128::from(bigint_high_i64(value)) << 64) | i128::from(bigint_to_u64(value))
+	};
+	u128: 128, false, |value| {
+		(u128::from(bigint_high_u64(value)) << 64) | u128::from(bigint_to_u64(value))
+	};
+);
+
+/// The error returned when a [`JsBigInt`] doesn't fit into the target integer
+/// type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TryFromBigIntError(());
+
+impl Display for TryFromBigIntError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.write_str("out of range integral type conversion attempted")
+	}
+}
+
+impl Error for TryFromBigIntError {}
+
+macro_rules! binary_op {
+	($($op:ident::$fn:ident => $js:ident;)*) => {$(
+		impl $op for &JsBigInt {
+			type Output = JsBigInt;
+
+			fn $fn(self, rhs: Self) -> Self::Output {
+				$js(self, rhs)
+			}
+		}
+
+		impl $op for JsBigInt {
+			type Output = Self;
+
+			fn $fn(self, rhs: Self) -> Self::Output {
+				$js(&self, &rhs)
+			}
+		}
+	)*};
+}
+
+// Division by zero throws a JS `RangeError`.
+binary_op!(
+	Add::add => bigint_add;
+	Sub::sub => bigint_sub;
+	Mul::mul => bigint_mul;
+	Div::div => bigint_div;
+	Rem::rem => bigint_rem;
+);
+
+impl Neg for &JsBigInt {
+	type Output = JsBigInt;
+
+	fn neg(self) -> Self::Output {
+		bigint_neg(self)
+	}
+}
+
+impl Neg for JsBigInt {
+	type Output = Self;
+
+	fn neg(self) -> Self::Output {
+		bigint_neg(&self)
+	}
+}
+
+impl PartialEq for JsBigInt {
+	fn eq(&self, other: &Self) -> bool {
+		self.cmp(other) == Ordering::Equal
+	}
+}
+
+impl Eq for JsBigInt {}
+
+impl PartialOrd for JsBigInt {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for JsBigInt {
+	fn cmp(&self, other: &Self) -> Ordering {
+		bigint_compare(self, other).cmp(&0)
+	}
+}
+
+impl Display for JsBigInt {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		Display::fmt(&self.js_to_string(), f)
+	}
+}
+
+impl Debug for JsBigInt {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "{}n", self.js_to_string())
+	}
+}
+
+// SAFETY: JS receives a `BigInt` combined from both halves. Ownership of the
+// `externref` table slot is passed to the import.
+unsafe impl Input for i128 {
+	const IMPORT_FUNC: &str = ".functype js_sys.externref.take (i32) -> (externref)";
+	const IMPORT_TYPE: &str = "externref";
+	const TYPE: &str = "i32";
+	const CONV: &str = "call js_sys.externref.take";
+
+	type Type = i32;
+
+	fn into_raw(self) -> Self::Type {
+		ManuallyDrop::new(JsValue::from(JsBigInt::from(self))).index
+	}
+}
+
+// SAFETY: See `Input for i128`.
+unsafe impl Input for u128 {
+	const IMPORT_FUNC: &str = <i128 as Input>::IMPORT_FUNC;
+	const IMPORT_TYPE: &str = <i128 as Input>::IMPORT_TYPE;
+	const TYPE: &str = <i128 as Input>::TYPE;
+	const CONV: &str = <i128 as Input>::CONV;
+
+	type Type = i32;
+
+	fn into_raw(self) -> Self::Type {
+		ManuallyDrop::new(JsValue::from(JsBigInt::from(self))).index
+	}
+}
+
+// SAFETY: JS `BigInt`s are received as `JsBigInt`, values out of range are
+// rejected.
+unsafe impl Output for i128 {
+	const IMPORT_FUNC: &str = <JsBigInt as Output>::IMPORT_FUNC;
+	const IMPORT_TYPE: &str = <JsBigInt as Output>::IMPORT_TYPE;
+	const TYPE: &str = <JsBigInt as Output>::TYPE;
+	const CONV: &str = <JsBigInt as Output>::CONV;
+
+	type Type = i32;
+
+	fn from_raw(raw: Self::Type) -> Self {
+		Self::try_from(JsBigInt::from_raw(raw))
+			.unwrap_or_else(|_| panic("found `BigInt` out of range for `i128`"))
+	}
+
+	fn discard(raw: Self::Type) {
+		drop(JsValue::from_raw(raw));
+	}
+}
+
+// SAFETY: See `Output for i128`.
+unsafe impl Output for u128 {
+	const IMPORT_FUNC: &str = <JsBigInt as Output>::IMPORT_FUNC;
+	const IMPORT_TYPE: &str = <JsBigInt as Output>::IMPORT_TYPE;
+	const TYPE: &str = <JsBigInt as Output>::TYPE;
+	const CONV: &str = <JsBigInt as Output>::CONV;
+
+	type Type = i32;
+
+	fn from_raw(raw: Self::Type) -> Self {
+		Self::try_from(JsBigInt::from_raw(raw))
+			.unwrap_or_else(|_| panic("found `BigInt` out of range for `u128`"))
+	}
+
+	fn discard(raw: Self::Type) {
+		drop(JsValue::from_raw(raw));
+	}
+}
+
+js_bindgen::embed_js!(
+	name = "bigint.from128",
+	"(low, high) => (high << 64n) | low",
+);
+
+js_bindgen::embed_js!(name = "bigint.high", "(value) => value >> 64n");
+
+js_bindgen::embed_js!(
+	name = "bigint.fits",
+	"(value, bits, signed) => {{",
+	"	const truncated = signed ? BigInt.asIntN(bits, value) : BigInt.asUintN(bits, value)",
+	"	return truncated === value",
+	"}}",
+);
+
+js_bindgen::embed_js!(name = "bigint.add", "(a, b) => a + b");
+js_bindgen::embed_js!(name = "bigint.sub", "(a, b) => a - b");
+js_bindgen::embed_js!(name = "bigint.mul", "(a, b) => a * b");
+js_bindgen::embed_js!(name = "bigint.div", "(a, b) => a / b");
+js_bindgen::embed_js!(name = "bigint.rem", "(a, b) => a % b");
+js_bindgen::embed_js!(name = "bigint.neg", "(value) => -value");
+js_bindgen::embed_js!(
+	name = "bigint.compare",
+	"(a, b) => a < b ? -1 : a > b ? 1 : 0",
+);
+
+#[js_sys(js_sys = crate)]
+extern "C" {
+	#[js_sys(
+		is_type_of = "(value) => typeof value === 'bigint'",
+		ts_type = "bigint"
+	)]
+	pub type JsBigInt;
+
+	#[js_sys(js_embed = "identity")]
+	fn bigint_from_i64(value: i64) -> JsBigInt;
+
+	#[js_sys(js_embed = "identity")]
+	fn bigint_from_u64(value: u64) -> JsBigInt;
+
+	#[js_sys(js_embed = "bigint.from128")]
+	fn bigint_from_i128(low: u64, high: i64) -> JsBigInt;
+
+	#[js_sys(js_embed = "bigint.from128")]
+	fn bigint_from_u128(low: u64, high: u64) -> JsBigInt;
+
+	#[js_sys(js_embed = "identity")]
+	fn bigint_to_i64(value: &JsBigInt) -> i64;
+
+	#[js_sys(js_embed = "identity")]
+	fn bigint_to_u64(value: &JsBigInt) -> u64;
+
+	#[js_sys(js_embed = "bigint.high")]
+	fn bigint_high_i64(value: &JsBigInt) -> i64;
+
+	#[js_sys(js_embed = "bigint.high")]
+	fn bigint_high_u64(value: &JsBigInt) -> u64;
+
+	#[js_sys(js_embed = "bigint.fits")]
+	fn bigint_fits(value: &JsBigInt, bits: u32, signed: bool) -> bool;
+
+	#[js_sys(js_embed = "bigint.add")]
+	fn bigint_add(a: &JsBigInt, b: &JsBigInt) -> JsBigInt;
+
+	#[js_sys(js_embed = "bigint.sub")]
+	fn bigint_sub(a: &JsBigInt, b: &JsBigInt) -> JsBigInt;
+
+	#[js_sys(js_embed = "bigint.mul")]
+	fn bigint_mul(a: &JsBigInt, b: &JsBigInt) -> JsBigInt;
+
+	#[js_sys(js_embed = "bigint.div")]
+	fn bigint_div(a: &JsBigInt, b: &JsBigInt) -> JsBigInt;
+
+	#[js_sys(js_embed = "bigint.rem")]
+	fn bigint_rem(a: &JsBigInt, b: &JsBigInt) -> JsBigInt;
+
+	#[js_sys(js_embed = "bigint.neg")]
+	fn bigint_neg(value: &JsBigInt) -> JsBigInt;
+
+	#[js_sys(js_embed = "bigint.compare")]
+	fn bigint_compare(a: &JsBigInt, b: &JsBigInt) -> i32;
+
+	#[js_sys(method, js_name = "toString")]
+	fn js_to_string(self: &JsBigInt) -> JsString;
+}
@@ -0,0 +1,56 @@
+use core::fmt::{self, Debug, Formatter};
+
+use js_sys_macro::js_sys;
+
+impl JsBoolean {
+	/// Returns the primitive value of this boolean.
+	#[must_use]
+	pub fn value_of(&self) -> bool {
+		boolean_value(self)
+	}
+}
+
+impl From<bool> for JsBoolean {
+	fn from(value: bool) -> Self {
+		boolean_from(value)
+	}
+}
+
+impl From<JsBoolean> for bool {
+	fn from(value: JsBoolean) -> Self {
+		value.value_of()
+	}
+}
+
+impl From<&JsBoolean> for bool {
+	fn from(value: &JsBoolean) -> Self {
+		value.value_of()
+	}
+}
+
+impl PartialEq<bool> for JsBoolean {
+	fn eq(&self, other: &bool) -> bool {
+		self.value_of() == *other
+	}
+}
+
+impl Debug for JsBoolean {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		Debug::fmt(&self.value_of(), f)
+	}
+}
+
+#[js_sys(js_sys = crate)]
+extern "C" {
+	#[js_sys(
+		is_type_of = "(value) => typeof value === 'boolean'",
+		ts_type = "boolean"
+	)]
+	pub type JsBoolean;
+
+	#[js_sys(js_embed = "identity")]
+	fn boolean_from(value: bool) -> JsBoolean;
+
+	#[js_sys(js_embed = "identity")]
+	fn boolean_value(value: &JsBoolean) -> bool;
+}
@@ -0,0 +1,91 @@
+use core::mem::ManuallyDrop;
+
+use crate::JsValue;
+use crate::hazard::{ExportInput, ExportOutput};
+use crate::r#macro::{self, JsType};
+use crate::panic::panic;
+
+/// Conversions between [`JsValue`] and types declared with `#[js_sys]`.
+///
+/// The type check defaults to `instanceof` with the name of the type and can
+/// be customized with `#[js_sys(instanceof = "...")]` or
+/// `#[js_sys(is_type_of = "...")]`.
+pub trait JsCast: JsType + AsRef<JsValue> + Into<JsValue> {
+	/// Returns `true` if `value` is of this type.
+	fn is_type_of(value: &JsValue) -> bool;
+
+	/// Returns `true` if this value is of type `T`.
+	fn is_instance_of<T: JsCast>(&self) -> bool {
+		T::is_type_of(self.as_ref())
+	}
+
+	/// Casts this value to `T` if it is of that type, otherwise returns it
+	/// unchanged.
+	///
+	/// # Errors
+	///
+	/// If this value isn't of type `T`.
+	fn dyn_into<T: JsCast>(self) -> Result<T, Self> {
+		if self.is_instance_of::<T>() {
+			Ok(self.unchecked_into())
+		} else {
+			Err(self)
+		}
+	}
+
+	/// Casts a reference of this value to `T` if it is of that type.
+	fn dyn_ref<T: JsCast>(&self) -> Option<&T> {
+		self.is_instance_of::<T>().then(|| self.unchecked_ref())
+	}
+
+	/// Casts this value to `T` without checking its type.
+	fn unchecked_into<T: JsCast>(self) -> T {
+		r#macro::cast(self)
+	}
+
+	/// Casts a reference of this value to `T` without checking its type.
+	fn unchecked_ref<T: JsCast>(&self) -> &T {
+		r#macro::cast_ref(self)
+	}
+}
+
+// SAFETY: JS values are inserted into the `externref` table, the export takes
+// ownership of the slot.
+unsafe impl<T: JsCast> ExportInput for T {
+	const TS_TYPE: &str = T::TS_TYPE;
+	const JS_CONV: &str = concat!(
+		"((value) => {\n",
+		"\t\tconst index = this.#instance.exports['js_sys.externref.next']()\n",
+		"\t\tthis.#importObject.js_sys['externref.table'].set(index, value)\n",
+		"\t\treturn index\n",
+		"\t})",
+	);
+
+	type Type = i32;
+	type Owned = Self;
+
+	fn from_raw(raw: Self::Type) -> Self::Owned {
+		JsValue::new(raw)
+			.dyn_into()
+			.unwrap_or_else(|_| panic("found value of the wrong type passed to an export"))
+	}
+}
+
+// SAFETY: Ownership of the `externref` table slot is passed to JS, which
+// releases it after reading the value.
+unsafe impl<T: JsCast> ExportOutput for T {
+	const TS_TYPE: &str = T::TS_TYPE;
+	const JS_CONV: &str = concat!(
+		"((index) => {\n",
+		"\t\tconst value = this.#importObject.js_sys['externref.table'].get(index)\n",
+		"\t\tthis.#instance.exports['js_sys.externref.release'](index)\n",
+		"\t\treturn value\n",
+		"\t})",
+	);
+
+	type Type = i32;
+
+	fn into_raw(self) -> Self::Type {
+		ManuallyDrop::new(self.into()).index
+	}
+}
@@ -0,0 +1,311 @@
+use alloc::boxed::Box;
+use core::cell::{OnceCell, UnsafeCell};
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+
+use js_sys_macro::js_sys;
+
+use crate::hazard::Input;
+use crate::r#macro;
+use crate::{JsCast, JsValue};
+
+/// The type-erased closure called by the `js_sys.closure.call` trampoline.
+/// Arguments and the return value are `externref` table slots.
+///
+/// Recursive calls are rejected by JS instead of a `RefCell`, a borrow guard
+/// would never be released if a JS exception unwinds through the call.
+type Erased = UnsafeCell<Box<dyn FnMut(i32, i32) -> i32>>;
+
+/// A Rust closure that can be passed to JS as a function.
+///
+/// `T` is the closure type, e.g. `dyn FnMut(JsValue) -> JsString`. Arguments
+/// must implement [`JsCast`], the return type [`ClosureReturn`].
+///
+/// The JS function is only created the first time the closure is passed to
+/// JS. Dropping the [`Closure`] invalidates the JS function, calling it
+/// afterwards throws a JS exception.
+pub struct Closure<T: ?Sized> {
+	data: *mut Erased,
+	arity: u32,
+	once: bool,
+	function: OnceCell<JsValue>,
+	_type: PhantomData<Box<T>>,
+}
+
+impl<T: ?Sized> Closure<T> {
+	#[must_use]
+	pub fn new<F: IntoClosure<T>>(f: F) -> Self {
+		Self {
+			data: Box::into_raw(Box::new(UnsafeCell::new(f.erase()))),
+			arity: F::ARITY,
+			once: F::ONCE,
+			function: OnceCell::new(),
+			_type: PhantomData,
+		}
+	}
+
+	/// Creates a JS function that can only be called once. The closure is
+	/// freed after the first call, if it is never called it is leaked.
+	#[must_use]
+	pub fn once<F: IntoClosure<T>>(f: F) -> JsValue {
+		let closure = ManuallyDrop::new(Self::new(f));
+		closure_new(closure.data as usize, closure.arity, 1)
+	}
+
+	fn function(&self) -> &JsValue {
+		self.function
+			.get_or_init(|| closure_new(self.data as usize, self.arity, self.once.into()))
+	}
+}
+
+impl<T: ?Sized> AsRef<JsValue> for Closure<T> {
+	fn as_ref(&self) -> &JsValue {
+		self.function()
+	}
+}
+
+impl<T: ?Sized> Drop for Closure<T> {
+	fn drop(&mut self) {
+		// When the JS function is currently being called, JS frees the closure
+		// after the call returns.
+		let free = self.function.get().is_none_or(closure_drop);
+
+		if free {
+			// SAFETY: JS won't call the closure anymore.
+			drop(unsafe { Box::from_raw(self.data) });
+		}
+	}
+}
+
+// SAFETY: Passes the `externref` table slot of the JS function.
+unsafe impl<T: ?Sized> Input for &Closure<T> {
+	const IMPORT_FUNC: &'static str = <&JsValue as Input>::IMPORT_FUNC;
+	const IMPORT_TYPE: &'static str = <&JsValue as Input>::IMPORT_TYPE;
+	const TYPE: &'static str = <&JsValue as Input>::TYPE;
+	const CONV: &'static str = <&JsValue as Input>::CONV;
+
+	type Type = i32;
+
+	fn into_raw(self) -> Self::Type {
+		self.function().index
+	}
+}
+
+/// Closures that can be turned into a [`Closure<T>`].
+pub trait IntoClosure<T: ?Sized>: 'static {
+	#[doc(hidden)]
+	const ARITY: u32;
+	#[doc(hidden)]
+	const ONCE: bool;
+
+	#[doc(hidden)]
+	fn erase(self) -> Box<dyn FnMut(i32, i32) -> i32>;
+}
+
+/// Types that can be returned from a [`Closure`] to JS.
+pub trait ClosureReturn {
+	#[doc(hidden)]
+	fn into_index(self) -> i32;
+}
+
+impl ClosureReturn for () {
+	fn into_index(self) -> i32 {
+		0
+	}
+}
+
+impl<T: JsCast> ClosureReturn for T {
+	fn into_index(self) -> i32 {
+		ManuallyDrop::new(self.into()).index
+	}
+}
+
+fn argument<A: JsCast>(index: i32) -> A {
+	r#macro::cast(JsValue::new(index))
+}
+
+impl<F, R> IntoClosure<dyn FnMut() -> R> for F
+where
+	F: 'static + FnMut() -> R,
+	R: ClosureReturn,
+{
+	const ARITY: u32 = 0;
+	const ONCE: bool = false;
+
+	fn erase(mut self) -> Box<dyn FnMut(i32, i32) -> i32> {
+		Box::new(move |_, _| self().into_index())
+	}
+}
+
+impl<F, A, R> IntoClosure<dyn FnMut(A) -> R> for F
+where
+	F: 'static + FnMut(A) -> R,
+	A: JsCast,
+	R: ClosureReturn,
+{
+	const ARITY: u32 = 1;
+	const ONCE: bool = false;
+
+	fn erase(mut self) -> Box<dyn FnMut(i32, i32) -> i32> {
+		Box::new(move |a, _| self(argument(a)).into_index())
+	}
+}
+
+impl<F, A, B, R> IntoClosure<dyn FnMut(A, B) -> R> for F
+where
+	F: 'static + FnMut(A, B) -> R,
+	A: JsCast,
+	B: JsCast,
+	R: ClosureReturn,
+{
+	const ARITY: u32 = 2;
+	const ONCE: bool = false;
+
+	fn erase(mut self) -> Box<dyn FnMut(i32, i32) -> i32> {
+		Box::new(move |a, b| self(argument(a), argument(b)).into_index())
+	}
+}
+
+impl<F, R> IntoClosure<dyn FnOnce() -> R> for F
+where
+	F: 'static + FnOnce() -> R,
+	R: ClosureReturn,
+{
+	const ARITY: u32 = 0;
+	const ONCE: bool = true;
+
+	fn erase(self) -> Box<dyn FnMut(i32, i32) -> i32> {
+		let mut f = Some(self);
+		Box::new(move |_, _| once(&mut f)().into_index())
+	}
+}
+
+impl<F, A, R> IntoClosure<dyn FnOnce(A) -> R> for F
+where
+	F: 'static + FnOnce(A) -> R,
+	A: JsCast,
+	R: ClosureReturn,
+{
+	const ARITY: u32 = 1;
+	const ONCE: bool = true;
+
+	fn erase(self) -> Box<dyn FnMut(i32, i32) -> i32> {
+		let mut f = Some(self);
+		Box::new(move |a, _| once(&mut f)(argument(a)).into_index())
+	}
+}
+
+impl<F, A, B, R> IntoClosure<dyn FnOnce(A, B) -> R> for F
+where
+	F: 'static + FnOnce(A, B) -> R,
+	A: JsCast,
+	B: JsCast,
+	R: ClosureReturn,
+{
+	const ARITY: u32 = 2;
+	const ONCE: bool = true;
+
+	fn erase(self) -> Box<dyn FnMut(i32, i32) -> i32> {
+		let mut f = Some(self);
+		Box::new(move |a, b| once(&mut f)(argument(a), argument(b)).into_index())
+	}
+}
+
+fn once<F>(f: &mut Option<F>) -> F {
+	f.take()
+		.unwrap_or_else(|| crate::panic("`FnOnce` closure called twice"))
+}
+
+#[unsafe(export_name = "js_sys.closure.call")]
+extern "C" fn call(data: usize, a: i32, b: i32) -> i32 {
+	// SAFETY: JS only calls closures that weren't freed yet and never while
+	// they are already being called.
+	let closure = unsafe { &mut *(*(data as *const Erased)).get() };
+	closure(a, b)
+}
+
+#[unsafe(export_name = "js_sys.closure.free")]
+extern "C" fn free(data: usize) {
+	// SAFETY: JS only frees closures that were dropped or called once and aren't
+	// being called anymore.
+	drop(unsafe { Box::from_raw(data as *mut Erased) });
+}
+
+js_bindgen::embed_js!(name = "closure.states", "new WeakMap()");
+
+js_bindgen::embed_js!(
+	name = "closure.new",
+	js_embed = "closure.states",
+	"(data, arity, once) => {{",
+	"	const table = this.#importObject.js_sys['externref.table']",
+	"	const state = {{ dropped: false, calling: false }}",
+	"	const closure = (...args) => {{",
+	"		if (state.dropped) {{",
+	"			throw new Error('closure called after being dropped')",
+	"		}}",
+	"",
+	"		if (state.calling) {{",
+	"			throw new Error('closure called recursively')",
+	"		}}",
+	"",
+	"		const exports = this.#instance.exports",
+	"		const indices = [0, 0]",
+	"",
+	"		for (let i = 0; i < arity; i++) {{",
+	"			indices[i] = exports['js_sys.externref.next']()",
+	"			table.set(indices[i], args[i])",
+	"		}}",
+	"",
+	"		let index",
+	"		state.calling = true",
+	"",
+	"		try {{",
+	"			index = exports['js_sys.closure.call'](data, indices[0], indices[1])",
+	"		}} finally {{",
+	"			state.calling = false",
+	"",
+	"			if (once) {{",
+	"				state.dropped = true",
+	"			}}",
+	"",
+	"			if (state.dropped) {{",
+	"				exports['js_sys.closure.free'](data)",
+	"			}}",
+	"		}}",
+	"",
+	"		if (index === 0) {{",
+	"			return undefined",
+	"		}}",
+	"",
+	"		const value = table.get(index)",
+	"		exports['js_sys.externref.release'](index)",
+	"		return value",
+	"	}}",
+	"	this.#jsEmbed.js_sys['closure.states'].set(closure, state)",
+	"	return closure",
+	"}}",
+);
+
+js_bindgen::embed_js!(
+	name = "closure.drop",
+	js_embed = "closure.states",
+	"(closure) => {{",
+	"	const state = this.#jsEmbed.js_sys['closure.states'].get(closure)",
+	"",
+	"	if (state.dropped) {{",
+	"		return false",
+	"	}}",
+	"",
+	"	state.dropped = true",
+	"	return !state.calling",
+	"}}",
+);
+
+#[js_sys(js_sys = crate)]
+extern "C" {
+	#[js_sys(js_embed = "closure.new")]
+	fn closure_new(data: usize, arity: u32, once: u32) -> JsValue;
+
+	#[js_sys(js_embed = "closure.drop")]
+	fn closure_drop(closure: &JsValue) -> bool;
+}
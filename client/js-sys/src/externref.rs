@@ -1,16 +1,17 @@
 use alloc::vec::Vec;
 use core::cell::RefCell;
 
+use crate::JsValue;
 use crate::panic::panic;
 
 macro_rules! thread_local {
     ($($vis:vis static $name:ident: $ty:ty = $value:expr;)*) => {
         #[cfg_attr(target_feature = "atomics", thread_local)]
-        $($vis static $name: LocalKey<$ty> = LocalKey($value);)*
+        $($vis static $name: $crate::externref::LocalKey<$ty> = $crate::externref::LocalKey($value);)*
     };
 }
 
-pub(crate) struct LocalKey<T>(T);
+pub(crate) struct LocalKey<T>(pub(crate) T);
 
 // SAFETY: Multi-threading is not possible without `atomics`.
 #[cfg(not(target_feature = "atomics"))]
@@ -32,9 +33,10 @@ impl<T> LocalKey<T> {
 js_bindgen::unsafe_embed_asm!(
 	".import_module js_sys.externref.table, js_sys",
 	".import_name js_sys.externref.table, externref.table",
-	".tabletype js_sys.externref.table, externref, 1",
+	".tabletype js_sys.externref.table, externref, 2",
 	"",
 	".functype js_sys.externref.next () -> (i32)",
+	".functype js_sys.externref.release (i32) -> ()",
 	"",
 	".globl js_sys.externref.grow",
 	"js_sys.externref.grow:",
@@ -62,6 +64,15 @@ js_bindgen::unsafe_embed_asm!(
 	"	table.get js_sys.externref.table",
 	"	end_function",
 	"",
+	".globl js_sys.externref.take",
+	"js_sys.externref.take:",
+	"	.functype js_sys.externref.take (i32) -> (externref)",
+	"	local.get 0",
+	"	table.get js_sys.externref.table",
+	"	local.get 0",
+	"	call js_sys.externref.release",
+	"	end_function",
+	"",
 	".globl js_sys.externref.remove",
 	"js_sys.externref.remove:",
 	"	.functype js_sys.externref.remove (i32) -> ()",
@@ -73,9 +84,14 @@ js_bindgen::unsafe_embed_asm!(
 
 js_bindgen::import_js!(
 	name = "externref.table",
-	"new WebAssembly.Table({{ initial: 1, element: 'externref' }})",
+	"(() => {{",
+	"	const table = new WebAssembly.Table({{ initial: 2, element: 'externref' }})",
+	"	table.set(1, null)",
+	"	return table",
+	"}})()",
 );
 
+#[cfg(target_family = "wasm")]
 unsafe extern "C" {
 	#[link_name = "js_sys.externref.grow"]
 	fn grow(size: i32) -> i32;
@@ -83,6 +99,16 @@ unsafe extern "C" {
 	fn remove(index: i32);
 }
 
+#[cfg(not(target_family = "wasm"))]
+unsafe fn grow(_: i32) -> i32 {
+	crate::r#macro::unsupported("js_sys.externref.grow")
+}
+
+#[cfg(not(target_family = "wasm"))]
+unsafe fn remove(_: i32) {
+	crate::r#macro::unsupported("js_sys.externref.remove")
+}
+
 thread_local! {
 	pub(crate) static EXTERNREF_TABLE: RefCell<Slab> = RefCell::new(Slab::new());
 }
@@ -119,3 +145,10 @@ impl Slab {
 extern "C" fn next() -> i32 {
 	EXTERNREF_TABLE.with(|slab| slab.try_borrow_mut().unwrap().next())
 }
+
+/// Releases a slot handed to JS by a `#[js_export]` function or taken by
+/// `js_sys.externref.take`.
+#[unsafe(export_name = "js_sys.externref.release")]
+extern "C" fn release(index: i32) {
+	drop(JsValue::new(index));
+}
@@ -0,0 +1,254 @@
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::rc::Rc;
+use core::cell::{Cell, OnceCell, RefCell};
+use core::future::Future;
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use core::pin::Pin;
+use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+use js_sys_macro::js_sys;
+
+use crate::{Closure, JsCast, JsPromise, JsValue};
+
+/// A [`Future`] resolving with the result of a [`JsPromise`].
+pub struct JsFuture<T = JsValue> {
+	state: Rc<RefCell<State>>,
+	_type: PhantomData<T>,
+}
+
+type Callback = Closure<dyn FnMut(JsValue)>;
+
+struct State {
+	result: Option<Result<JsValue, JsValue>>,
+	waker: Option<Waker>,
+	/// Kept alive until the promise settles, even if the [`JsFuture`] is dropped
+	/// earlier.
+	callbacks: Option<[Callback; 2]>,
+}
+
+impl<T> From<JsPromise<T>> for JsFuture<T> {
+	fn from(promise: JsPromise<T>) -> Self {
+		let state = Rc::new(RefCell::new(State {
+			result: None,
+			waker: None,
+			callbacks: None,
+		}));
+
+		let settle = |ok: bool| {
+			let state = Rc::clone(&state);
+
+			move |value: JsValue| {
+				let mut state = state.borrow_mut();
+				state.result = Some(if ok { Ok(value) } else { Err(value) });
+				let callbacks = state.callbacks.take();
+				let waker = state.waker.take();
+				drop(state);
+				drop(callbacks);
+
+				if let Some(waker) = waker {
+					waker.wake();
+				}
+			}
+		};
+
+		let resolve = Callback::new(settle(true));
+		let reject = Callback::new(settle(false));
+		promise_then(promise.as_ref(), resolve.as_ref(), reject.as_ref());
+		state.borrow_mut().callbacks = Some([resolve, reject]);
+
+		Self {
+			state,
+			_type: PhantomData,
+		}
+	}
+}
+
+impl<T: JsCast> Future for JsFuture<T> {
+	type Output = Result<T, JsValue>;
+
+	fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		let mut state = self.state.borrow_mut();
+
+		if let Some(result) = state.result.take() {
+			Poll::Ready(result.map(JsCast::unchecked_into))
+		} else {
+			state.waker = Some(cx.waker().clone());
+			Poll::Pending
+		}
+	}
+}
+
+/// Runs `future` to completion on the current thread. Tasks are polled from
+/// the JS microtask queue.
+pub fn spawn_local<F>(future: F)
+where
+	F: 'static + Future<Output = ()>,
+{
+	let task = Rc::new(Task {
+		future: Cell::new(Some(Box::pin(future))),
+		queued: Cell::new(false),
+	});
+	Task::wake(&task);
+}
+
+/// Converts `future` into a [`JsPromise`] settled with its result, e.g. to
+/// return it from a `#[js_export]` function.
+pub fn future_to_promise<F, T>(future: F) -> JsPromise<T>
+where
+	F: 'static + Future<Output = Result<T, JsValue>>,
+	T: JsCast,
+{
+	let executor =
+		Closure::<dyn FnOnce(JsValue, JsValue)>::once(move |resolve: JsValue, reject: JsValue| {
+			spawn_local(async move {
+				match future.await {
+					Ok(value) => function_call(&resolve, value.as_ref()),
+					Err(error) => function_call(&reject, &error),
+				}
+			});
+		});
+
+	promise_new(&executor).unchecked_into()
+}
+
+struct Task {
+	/// Taken while being polled. If polling throws a JS exception the future is
+	/// never put back and later wake-ups are ignored.
+	future: Cell<Option<Pin<Box<dyn Future<Output = ()>>>>>,
+	queued: Cell<bool>,
+}
+
+impl Task {
+	fn wake(self: &Rc<Self>) {
+		if self.queued.replace(true) {
+			return;
+		}
+
+		QUEUE.with(|queue| {
+			queue.tasks.borrow_mut().push_back(Rc::clone(self));
+
+			if !queue.scheduled.replace(true) {
+				executor_schedule(queue.run.get_or_init(|| Closure::new(run)).as_ref());
+			}
+		});
+	}
+
+	fn poll(self: &Rc<Self>) {
+		self.queued.set(false);
+
+		if let Some(mut future) = self.future.take() {
+			let waker = waker(Rc::clone(self));
+
+			if future
+				.as_mut()
+				.poll(&mut Context::from_waker(&waker))
+				.is_pending()
+			{
+				self.future.set(Some(future));
+			}
+		}
+	}
+}
+
+struct Queue {
+	tasks: RefCell<VecDeque<Rc<Task>>>,
+	scheduled: Cell<bool>,
+	run: OnceCell<Closure<dyn FnMut()>>,
+}
+
+thread_local! {
+	static QUEUE: Queue = Queue {
+		tasks: RefCell::new(VecDeque::new()),
+		scheduled: Cell::new(false),
+		run: OnceCell::new(),
+	};
+}
+
+/// Polls all queued tasks, called from the JS microtask queue.
+///
+/// `scheduled` is reset before polling, because a task throwing a JS exception
+/// skips everything after it. `executor.schedule` then calls `run()` again for
+/// the remaining tasks.
+fn run() {
+	QUEUE.with(|queue| queue.scheduled.set(false));
+
+	while let Some(task) = QUEUE.with(|queue| queue.tasks.borrow_mut().pop_front()) {
+		task.poll();
+	}
+}
+
+const VTABLE: RawWakerVTable = RawWakerVTable::new(
+	|task| {
+		// SAFETY: `task` was created by `Rc::into_raw()` in `waker()`.
+		unsafe { Rc::increment_strong_count(task.cast::<Task>()) };
+		RawWaker::new(task, &VTABLE)
+	},
+	|task| {
+		// SAFETY: `task` was created by `Rc::into_raw()` in `waker()`.
+		Task::wake(&unsafe { Rc::from_raw(task.cast::<Task>()) });
+	},
+	|task| {
+		// SAFETY: `task` was created by `Rc::into_raw()` in `waker()`.
+		Task::wake(&ManuallyDrop::new(unsafe {
+			Rc::from_raw(task.cast::<Task>())
+		}));
+	},
+	|task| {
+		// SAFETY: `task` was created by `Rc::into_raw()` in `waker()`.
+		drop(unsafe { Rc::from_raw(task.cast::<Task>()) });
+	},
+);
+
+fn waker(task: Rc<Task>) -> Waker {
+	// SAFETY: The vtable upholds the `RawWaker` contract. Tasks are only polled
+	// on the thread that spawned them.
+	unsafe { Waker::from_raw(RawWaker::new(Rc::into_raw(task).cast(), &VTABLE)) }
+}
+
+js_bindgen::embed_js!(
+	name = "executor.schedule",
+	"(run) => {{",
+	"	const schedule = () => queueMicrotask(() => {{",
+	"		try {{",
+	"			run()",
+	"		}} catch (error) {{",
+	"			schedule()",
+	"			throw error",
+	"		}}",
+	"	}})",
+	"	schedule()",
+	"}}",
+);
+
+js_bindgen::embed_js!(
+	name = "promise.then",
+	"(promise, resolve, reject) => {{",
+	"	promise.then(resolve, reject)",
+	"}}",
+);
+
+js_bindgen::embed_js!(name = "promise.new", "(executor) => new Promise(executor)",);
+
+js_bindgen::embed_js!(
+	name = "function.call",
+	"(f, value) => {{",
+	"	f(value)",
+	"}}",
+);
+
+#[js_sys(js_sys = crate)]
+extern "C" {
+	#[js_sys(js_embed = "executor.schedule")]
+	fn executor_schedule(run: &JsValue);
+
+	#[js_sys(js_embed = "promise.then")]
+	fn promise_then(promise: &JsValue, resolve: &JsValue, reject: &JsValue);
+
+	#[js_sys(js_embed = "promise.new")]
+	fn promise_new(executor: &JsValue) -> JsPromise;
+
+	#[js_sys(js_embed = "function.call")]
+	fn function_call(function: &JsValue, value: &JsValue);
+}
@@ -1,3 +1,12 @@
+use core::borrow::Borrow;
+
+/// Parameters of functions imported with `#[js_sys]`.
+///
+/// `JS_CONV` is a parenthesized JS function expression, e.g.
+/// `((value) => value >>> 0)`, or empty if no conversion is needed. It is
+/// called with the value received from Wasm and returns the value passed to the
+/// JS function.
+///
 /// # Safety
 ///
 /// This directly interacts with the assembly generator and therefor all
@@ -14,6 +23,11 @@ pub unsafe trait Input {
 	fn into_raw(self) -> Self::Type;
 }
 
+/// Return types of functions imported with `#[js_sys]`.
+///
+/// `JS_CONV` is called with the return value of the JS function and returns the
+/// value passed to Wasm, see [`Input`].
+///
 /// # Safety
 ///
 /// This directly interacts with the assembly generator and therefor all
@@ -23,8 +37,56 @@ pub unsafe trait Output {
 	const IMPORT_TYPE: &str;
 	const TYPE: &str;
 	const CONV: &str = "";
+	const JS_CONV: &str = "";
 
 	type Type;
 
 	fn from_raw(raw: Self::Type) -> Self;
+
+	/// Releases the placeholder returned by a `#[js_sys(catch)]` import that
+	/// threw, see [`catch_return()`](crate::r#macro::catch_return).
+	fn discard(raw: Self::Type)
+	where
+		Self: Sized,
+	{
+		drop(Self::from_raw(raw));
+	}
+}
+
+/// Parameters of functions exported with `#[js_export]`.
+///
+/// `JS_CONV` is a JS function expression called with the JS value, returning
+/// the value passed to the Wasm export.
+///
+/// # Safety
+///
+/// `Type` must be a Wasm value type and `JS_CONV` has to return a valid value
+/// for it.
+pub unsafe trait ExportInput {
+	const TS_TYPE: &str;
+	const JS_CONV: &str = "";
+
+	type Type;
+	/// The owned value references are borrowed from.
+	type Owned: Borrow<Self>;
+
+	fn from_raw(raw: Self::Type) -> Self::Owned;
+}
+
+/// Return types of functions exported with `#[js_export]`.
+///
+/// `JS_CONV` is a JS function expression called with the return value of the
+/// Wasm export, returning the value passed to JS.
+///
+/// # Safety
+///
+/// `Type` must be a Wasm value type and `JS_CONV` has to accept any value of
+/// it.
+pub unsafe trait ExportOutput {
+	const TS_TYPE: &str;
+	const JS_CONV: &str = "";
+
+	type Type;
+
+	fn into_raw(self) -> Self::Type;
 }
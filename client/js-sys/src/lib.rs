@@ -5,29 +5,64 @@
 extern crate alloc;
 
 mod array;
+mod bigint;
+mod boolean;
+mod cast;
+mod closure;
+#[macro_use]
 mod externref;
+mod future;
 pub mod hazard;
 #[doc(hidden)]
 pub mod r#macro;
+mod number;
 mod numeric;
+mod object;
 mod panic;
+mod promise;
+pub mod reflect;
 mod string;
+mod symbol;
+#[cfg(all(test, not(target_family = "wasm")))]
+mod tests;
+mod typed_array;
 mod util;
 
 use core::marker::PhantomData;
 
 pub use js_bindgen;
-pub use js_sys_macro::js_sys;
+pub use js_sys_macro::{js_export, js_sys};
 
 pub use crate::array::JsArray;
+pub use crate::bigint::{JsBigInt, TryFromBigIntError};
+pub use crate::boolean::JsBoolean;
+pub use crate::cast::JsCast;
+pub use crate::closure::{Closure, ClosureReturn, IntoClosure};
 use crate::externref::EXTERNREF_TABLE;
+pub use crate::future::{JsFuture, future_to_promise, spawn_local};
 use crate::hazard::{Input, Output};
+use crate::r#macro::JsType;
+pub use crate::number::JsNumber;
+pub use crate::object::{JsObject, PropertyKey};
 pub use crate::panic::{UnwrapThrowExt, panic};
+pub use crate::promise::JsPromise;
 pub use crate::string::JsString;
+pub use crate::symbol::JsSymbol;
+pub use crate::typed_array::{
+	JsBigInt64Array, JsBigUint64Array, JsFloat32Array, JsFloat64Array, JsInt8Array, JsInt16Array,
+	JsInt32Array, JsUint8Array, JsUint16Array, JsUint32Array,
+};
 
-#[cfg(not(target_feature = "reference-types"))]
+#[cfg(all(target_family = "wasm", not(target_feature = "reference-types")))]
 compile_error!("`js-sys` requires the `reference-types` target feature");
 
+// This library doesn't exist. `js-bindgen-ld` removes it, any other linker
+// fails with "unable to find library". Must match `POISON_LIBRARY` in
+// `js-bindgen-ld-shared`.
+#[cfg(target_family = "wasm")]
+#[link(name = "js-bindgen requires `js-bindgen-ld` as the linker")]
+unsafe extern "C" {}
+
 #[repr(transparent)]
 pub struct JsValue {
 	index: i32,
@@ -35,7 +70,10 @@ pub struct JsValue {
 }
 
 impl JsValue {
+	// Slot 0 of the `externref` table holds `undefined` and slot 1 holds
+	// `null`, both are never removed.
 	pub const UNDEFINED: Self = Self::new(0);
+	pub const NULL: Self = Self::new(1);
 
 	const fn new(index: i32) -> Self {
 		Self {
@@ -43,16 +81,76 @@ impl JsValue {
 			_local: PhantomData,
 		}
 	}
+
+	/// Returns `true` if this value is `null`.
+	#[must_use]
+	pub fn is_null(&self) -> bool {
+		js_bindgen::embed_js!(name = "value.isNull", "(value) => value === null");
+
+		value_is_null(self)
+	}
+
+	/// Returns `true` if this value is `undefined`.
+	#[must_use]
+	pub fn is_undefined(&self) -> bool {
+		js_bindgen::embed_js!(name = "value.isUndefined", "(value) => value === undefined",);
+
+		value_is_undefined(self)
+	}
+
+	/// Returns `true` if this value is truthy in JS.
+	#[must_use]
+	pub fn is_truthy(&self) -> bool {
+		js_bindgen::embed_js!(name = "value.isTruthy", "(value) => !!value");
+
+		value_is_truthy(self)
+	}
+
+	/// Returns the result of the JS `typeof` operator on this value.
+	#[must_use]
+	pub fn js_typeof(&self) -> JsString {
+		js_bindgen::embed_js!(name = "value.typeof", "(value) => typeof value");
+
+		value_typeof(self)
+	}
+
+	/// Returns the value of this number, or [`None`] if this isn't a number.
+	#[must_use]
+	pub fn as_f64(&self) -> Option<f64> {
+		self.dyn_ref().map(JsNumber::value_of)
+	}
+
+	/// Returns the value of this boolean, or [`None`] if this isn't a boolean.
+	#[must_use]
+	pub fn as_bool(&self) -> Option<bool> {
+		self.dyn_ref().map(JsBoolean::value_of)
+	}
 }
 
 impl Drop for JsValue {
 	fn drop(&mut self) {
-		if self.index > 0 {
+		// Skip `UNDEFINED` and `NULL`.
+		if self.index > 1 {
 			EXTERNREF_TABLE.with(|table| table.try_borrow_mut().unwrap().remove(self.index));
 		}
 	}
 }
 
+// SAFETY: `JsValue` trivially wraps itself.
+unsafe impl JsType for JsValue {}
+
+impl JsCast for JsValue {
+	fn is_type_of(_: &JsValue) -> bool {
+		true
+	}
+}
+
+impl AsRef<Self> for JsValue {
+	fn as_ref(&self) -> &Self {
+		self
+	}
+}
+
 // SAFETY: Implementation for all `JsValue`s.
 unsafe impl Input for &JsValue {
 	const IMPORT_FUNC: &'static str = ".functype js_sys.externref.get (i32) -> (externref)";
@@ -80,3 +178,20 @@ unsafe impl Output for JsValue {
 		Self::new(raw)
 	}
 }
+
+js_bindgen::embed_js!(name = "identity", "(value) => value");
+
+#[js_sys(js_sys = crate)]
+extern "C" {
+	#[js_sys(js_embed = "value.isNull")]
+	fn value_is_null(value: &JsValue) -> bool;
+
+	#[js_sys(js_embed = "value.isUndefined")]
+	fn value_is_undefined(value: &JsValue) -> bool;
+
+	#[js_sys(js_embed = "value.isTruthy")]
+	fn value_is_truthy(value: &JsValue) -> bool;
+
+	#[js_sys(js_embed = "value.typeof")]
+	fn value_typeof(value: &JsValue) -> JsString;
+}
@@ -1,3 +1,12 @@
+use core::mem::ManuallyDrop;
+use core::ptr;
+
+use js_sys_macro::js_sys;
+
+use crate::JsValue;
+use crate::hazard::Output;
+use crate::util::PtrLength;
+
 #[must_use]
 pub const fn select<const L: usize>(
 	a: &'static str,
@@ -18,3 +27,130 @@ pub const fn select<const L: usize>(
 
 	if any_conversions { b } else { a }
 }
+
+/// Implemented for every type declared with `#[js_sys]`.
+///
+/// # Safety
+///
+/// Must only be implemented on `#[repr(transparent)]` wrappers around
+/// [`JsValue`].
+pub unsafe trait JsType {
+	/// The TypeScript type, set with `#[js_sys(ts_type = "...")]`.
+	const TS_TYPE: &str = "unknown";
+}
+
+/// Casts a reference between two types declared with `#[js_sys]`.
+#[must_use]
+pub fn cast_ref<T: JsType, U: JsType>(value: &T) -> &U {
+	// SAFETY: both types are transparent wrappers around `JsValue`.
+	unsafe { &*ptr::from_ref(value).cast::<U>() }
+}
+
+/// Called by `#[js_sys]` functions on targets other than Wasm.
+#[track_caller]
+pub fn unsupported(name: &str) -> ! {
+	panic!("`{name}` is only supported on Wasm targets")
+}
+
+/// Casts between two types declared with `#[js_sys]`.
+#[must_use]
+pub fn cast<T: JsType, U: JsType>(value: T) -> U {
+	let value = ManuallyDrop::new(value);
+	// SAFETY: both types are transparent wrappers around `JsValue`, ownership
+	// moves from `value` to the returned value.
+	unsafe { ptr::read(ptr::from_ref::<T>(&value).cast::<U>()) }
+}
+
+/// Returns `true` if `value` is an instance of the class found at the
+/// `globalThis` path `class`, e.g. `"WebAssembly.Memory"`. Returns `false` if
+/// the path doesn't resolve to a class.
+#[must_use]
+pub fn instanceof(value: &JsValue, class: &str) -> bool {
+	js_bindgen::embed_js!(
+		name = "instanceof",
+		js_embed = "string.decode",
+		"(value, ptr, len) => {{",
+		"	const path = this.#jsEmbed.js_sys['string.decode'](ptr, len)",
+		"	const ctor = path.split('.').reduce((object, key) => object?.[key], globalThis)",
+		"	return typeof ctor === 'function' && value instanceof ctor",
+		"}}",
+	);
+
+	js_instanceof(
+		value,
+		class.as_ptr(),
+		PtrLength::new(class.as_ptr(), class.len()),
+	)
+}
+
+/// Returns the exception thrown by the last call to a `#[js_sys(catch)]`
+/// import, `raw` is the return value of that call. If an exception was thrown,
+/// `raw` is the placeholder returned by [`catch_return()`] and is discarded
+/// without conversion.
+pub fn catch<T: Output>(raw: T::Type) -> Result<T, JsValue> {
+	if exception_thrown() {
+		T::discard(raw);
+		Err(exception_take())
+	} else {
+		Ok(T::from_raw(raw))
+	}
+}
+
+/// [`catch()`] for `#[js_sys(catch)]` imports without a return value.
+pub fn catch_unit() -> Result<(), JsValue> {
+	if exception_thrown() {
+		Err(exception_take())
+	} else {
+		Ok(())
+	}
+}
+
+/// Returns the JS value returned by a `#[js_sys(catch)]` import when an
+/// exception was thrown. It has to be accepted by the Wasm import type, e.g.
+/// `i64` doesn't accept `undefined`.
+#[must_use]
+pub const fn catch_return(import_type: &str) -> &'static str {
+	match import_type.as_bytes() {
+		b"i64" => "0n",
+		b"externref" => "undefined",
+		_ => "0",
+	}
+}
+
+js_bindgen::embed_js!(
+	name = "exception",
+	"{{",
+	"	thrown: false,",
+	"	error: undefined,",
+	"}}",
+);
+
+js_bindgen::embed_js!(
+	name = "exception.thrown",
+	js_embed = "exception",
+	"() => this.#jsEmbed.js_sys['exception'].thrown",
+);
+
+js_bindgen::embed_js!(
+	name = "exception.take",
+	js_embed = "exception",
+	"() => {{",
+	"	const exception = this.#jsEmbed.js_sys['exception']",
+	"	const error = exception.error",
+	"	exception.thrown = false",
+	"	exception.error = undefined",
+	"	return error",
+	"}}",
+);
+
+#[js_sys(js_sys = crate)]
+extern "C" {
+	#[js_sys(js_embed = "exception.thrown")]
+	fn exception_thrown() -> bool;
+
+	#[js_sys(js_embed = "exception.take")]
+	fn exception_take() -> JsValue;
+
+	#[js_sys(js_embed = "instanceof")]
+	fn js_instanceof(value: &JsValue, class: *const u8, len: PtrLength) -> bool;
+}
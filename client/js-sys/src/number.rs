@@ -0,0 +1,104 @@
+use core::fmt::{self, Debug, Display, Formatter};
+
+use js_sys_macro::js_sys;
+
+use crate::{JsString, JsValue};
+
+impl JsNumber {
+	/// Parses `text` like JS `Number.parseFloat()`, returning `NaN` if no
+	/// number could be parsed.
+	#[must_use]
+	pub fn parse_float(text: &str) -> Self {
+		number_parse_float(&JsString::from_str(text))
+	}
+
+	/// Returns the primitive value of this number.
+	#[must_use]
+	pub fn value_of(&self) -> f64 {
+		number_value(self)
+	}
+
+	/// Returns `true` if this number is `NaN`.
+	#[must_use]
+	pub fn is_nan(&self) -> bool {
+		number_is_nan(self)
+	}
+
+	/// Formats this number with `digits` digits after the decimal point.
+	///
+	/// # Errors
+	///
+	/// If `digits` is greater than 100, with the JS `RangeError`.
+	pub fn to_fixed(&self, digits: u8) -> Result<JsString, JsValue> {
+		self.js_to_fixed(digits)
+	}
+}
+
+macro_rules! from {
+	($($ty:ty),*) => {$(
+		impl From<$ty> for JsNumber {
+			fn from(value: $ty) -> Self {
+				number_from(value.into())
+			}
+		}
+	)*};
+}
+
+from!(i8, i16, i32, u8, u16, u32, f32, f64);
+
+impl From<JsNumber> for f64 {
+	fn from(value: JsNumber) -> Self {
+		value.value_of()
+	}
+}
+
+impl From<&JsNumber> for f64 {
+	fn from(value: &JsNumber) -> Self {
+		value.value_of()
+	}
+}
+
+impl PartialEq<f64> for JsNumber {
+	fn eq(&self, other: &f64) -> bool {
+		self.value_of() == *other
+	}
+}
+
+impl Display for JsNumber {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		Display::fmt(&self.js_to_string(), f)
+	}
+}
+
+impl Debug for JsNumber {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		Display::fmt(&self.js_to_string(), f)
+	}
+}
+
+#[js_sys(js_sys = crate)]
+extern "C" {
+	#[js_sys(
+		is_type_of = "(value) => typeof value === 'number'",
+		ts_type = "number"
+	)]
+	pub type JsNumber;
+
+	#[js_sys(js_embed = "identity")]
+	fn number_from(value: f64) -> JsNumber;
+
+	#[js_sys(js_embed = "identity")]
+	fn number_value(value: &JsNumber) -> f64;
+
+	#[js_sys(js_name = "Number.parseFloat")]
+	fn number_parse_float(text: &JsString) -> JsNumber;
+
+	#[js_sys(js_name = "Number.isNaN")]
+	fn number_is_nan(value: &JsNumber) -> bool;
+
+	#[js_sys(method, js_name = "toFixed", catch)]
+	fn js_to_fixed(self: &JsNumber, digits: u8) -> Result<JsString, JsValue>;
+
+	#[js_sys(method, js_name = "toString")]
+	fn js_to_string(self: &JsNumber) -> JsString;
+}
@@ -1,10 +1,118 @@
-use crate::hazard::Input;
+use alloc::format;
+use core::fmt::Display;
+
+use crate::hazard::{ExportInput, ExportOutput, Input, Output};
+use crate::panic::panic;
+
+// SAFETY: Implementation.
+unsafe impl Input for bool {
+	const IMPORT_TYPE: &str = "i32";
+	const TYPE: &str = "i32";
+	const JS_CONV: &str = "((value) => !!value)";
+
+	type Type = i32;
+
+	fn into_raw(self) -> Self::Type {
+		self.into()
+	}
+}
+
+// SAFETY: JS values are converted to `0` or `1`.
+unsafe impl Output for bool {
+	const IMPORT_TYPE: &str = "i32";
+	const TYPE: &str = "i32";
+	const JS_CONV: &str = "((value) => !!value)";
+
+	type Type = i32;
+
+	fn from_raw(raw: Self::Type) -> Self {
+		raw != 0
+	}
+}
+
+/// JS conversion rejecting values that aren't integers in range. Wasm imports
+/// would otherwise wrap them with `ToInt32`.
+macro_rules! range_conv {
+	($ty:ty: $min:literal..=$max:literal) => {
+		concat!(
+			"((value) => {\n",
+			"\t\tif (Number.isInteger(value) && value >= ",
+			stringify!($min),
+			" && value <= ",
+			stringify!($max),
+			") {\n",
+			"\t\t\treturn value\n",
+			"\t\t}\n",
+			"\t\tthrow new RangeError('found `' + String(value) + '` out of range for `",
+			stringify!($ty),
+			"`')\n",
+			"\t})",
+		)
+	};
+}
+
+macro_rules! small_int {
+	($($ty:ty: $min:literal..=$max:literal),*) => {$(
+		// SAFETY: Sign- or zero-extended to `i32`, which JS receives unchanged.
+		unsafe impl Input for $ty {
+			const IMPORT_TYPE: &str = "i32";
+			const TYPE: &str = "i32";
+
+			type Type = i32;
+
+			fn into_raw(self) -> Self::Type {
+				self.into()
+			}
+		}
+
+		// SAFETY: JS values that aren't integers in range are rejected before the
+		// Wasm import truncates them to `i32`.
+		unsafe impl Output for $ty {
+			const IMPORT_TYPE: &str = "i32";
+			const TYPE: &str = "i32";
+			const JS_CONV: &str = range_conv!($ty: $min..=$max);
+
+			type Type = i32;
+
+			fn from_raw(raw: Self::Type) -> Self {
+				Self::try_from(raw).unwrap_or_else(|_| out_of_range(raw, stringify!($ty)))
+			}
+		}
+	)*};
+}
+
+small_int!(i8: -128..=127, u8: 0..=255, i16: -32768..=32767, u16: 0..=65535);
+
+// SAFETY: Implementation.
+unsafe impl Input for i32 {
+	const IMPORT_TYPE: &str = "i32";
+	const TYPE: &str = "i32";
+
+	type Type = Self;
+
+	fn into_raw(self) -> Self::Type {
+		self
+	}
+}
+
+// SAFETY: JS values that aren't integers in range are rejected.
+unsafe impl Output for i32 {
+	const IMPORT_TYPE: &str = "i32";
+	const TYPE: &str = "i32";
+	const JS_CONV: &str = range_conv!(i32: -2147483648..=2147483647);
+
+	type Type = Self;
+
+	fn from_raw(raw: Self::Type) -> Self {
+		raw
+	}
+}
 
 // SAFETY: Implementation.
 unsafe impl Input for u32 {
 	const IMPORT_TYPE: &str = "i32";
 	const TYPE: &str = "i32";
-	const JS_CONV: &str = " >>>= 0";
+	const JS_CONV: &str = "((value) => value >>> 0)";
 
 	type Type = Self;
 
@@ -13,18 +121,161 @@ unsafe impl Input for u32 {
 	}
 }
 
+// SAFETY: JS values that aren't integers in range are rejected, the Wasm import
+// keeps the bits of any `u32`.
+unsafe impl Output for u32 {
+	const IMPORT_TYPE: &str = "i32";
+	const TYPE: &str = "i32";
+	const JS_CONV: &str = range_conv!(u32: 0..=4294967295);
+
+	type Type = Self;
+
+	fn from_raw(raw: Self::Type) -> Self {
+		raw
+	}
+}
+
+// SAFETY: JS receives a `BigInt`.
+unsafe impl Input for i64 {
+	const IMPORT_TYPE: &str = "i64";
+	const TYPE: &str = "i64";
+
+	type Type = Self;
+
+	fn into_raw(self) -> Self::Type {
+		self
+	}
+}
+
+// SAFETY: JS `BigInt`s are truncated to `i64` by the Wasm import.
+unsafe impl Output for i64 {
+	const IMPORT_TYPE: &str = "i64";
+	const TYPE: &str = "i64";
+
+	type Type = Self;
+
+	fn from_raw(raw: Self::Type) -> Self {
+		raw
+	}
+}
+
+// SAFETY: JS receives a signed `BigInt`, which is converted to unsigned.
+unsafe impl Input for u64 {
+	const IMPORT_TYPE: &str = "i64";
+	const TYPE: &str = "i64";
+	const JS_CONV: &str = "((value) => BigInt.asUintN(64, value))";
+
+	type Type = Self;
+
+	fn into_raw(self) -> Self::Type {
+		self
+	}
+}
+
+// SAFETY: JS `BigInt`s are truncated to `i64` by the Wasm import, which keeps
+// the bits of any `u64`.
+unsafe impl Output for u64 {
+	const IMPORT_TYPE: &str = "i64";
+	const TYPE: &str = "i64";
+
+	type Type = Self;
+
+	fn from_raw(raw: Self::Type) -> Self {
+		raw
+	}
+}
+
+// SAFETY: Implementation.
+unsafe impl Input for isize {
+	#[cfg(not(target_arch = "wasm64"))]
+	const IMPORT_TYPE: &str = "i32";
+	#[cfg(target_arch = "wasm64")]
+	const IMPORT_TYPE: &str = "f64";
+	#[cfg(not(target_arch = "wasm64"))]
+	const TYPE: &str = "i32";
+	#[cfg(target_arch = "wasm64")]
+	const TYPE: &str = "f64";
+
+	#[cfg(not(target_arch = "wasm64"))]
+	type Type = Self;
+	#[cfg(target_arch = "wasm64")]
+	type Type = f64;
+
+	#[cfg(not(target_arch = "wasm64"))]
+	fn into_raw(self) -> Self::Type {
+		self
+	}
+
+	#[cfg(target_arch = "wasm64")]
+	fn into_raw(self) -> Self::Type {
+		debug_assert!(
+			self.unsigned_abs() < 0x20000000000000,
+			"found `isize` bigger than `Number.MAX_SAFE_INTEGER`"
+		);
+		self as f64
+	}
+}
+
+// SAFETY: JS values that aren't integers in range are rejected, on 32-bit in JS
+// and on 64-bit in Rust.
+unsafe impl Output for isize {
+	#[cfg(not(target_arch = "wasm64"))]
+	const IMPORT_TYPE: &str = "i32";
+	#[cfg(target_arch = "wasm64")]
+	const IMPORT_TYPE: &str = "f64";
+	#[cfg(not(target_arch = "wasm64"))]
+	const TYPE: &str = "i32";
+	#[cfg(target_arch = "wasm64")]
+	const TYPE: &str = "f64";
+	#[cfg(not(target_arch = "wasm64"))]
+	const JS_CONV: &str = range_conv!(isize: -2147483648..=2147483647);
+
+	#[cfg(not(target_arch = "wasm64"))]
+	type Type = Self;
+	#[cfg(target_arch = "wasm64")]
+	type Type = f64;
+
+	#[cfg(not(target_arch = "wasm64"))]
+	fn from_raw(raw: Self::Type) -> Self {
+		raw
+	}
+
+	#[cfg(target_arch = "wasm64")]
+	fn from_raw(raw: Self::Type) -> Self {
+		let value = raw as Self;
+
+		if value as f64 == raw {
+			value
+		} else {
+			out_of_range(raw, "isize")
+		}
+	}
+}
+
 // SAFETY: Implementation.
 unsafe impl Input for usize {
-	#[cfg(target_arch = "wasm32")]
+	#[cfg(not(target_arch = "wasm64"))]
 	const IMPORT_TYPE: &str = "i32";
 	#[cfg(target_arch = "wasm64")]
 	const IMPORT_TYPE: &str = "i64";
-	#[cfg(target_arch = "wasm32")]
+	#[cfg(not(target_arch = "wasm64"))]
 	const TYPE: &str = "i32";
 	#[cfg(target_arch = "wasm64")]
 	const TYPE: &str = "i64";
-	#[cfg(target_arch = "wasm32")]
-	const JS_CONV: &str = " >>>= 0";
+	#[cfg(not(target_arch = "wasm64"))]
+	const JS_CONV: &str = "((value) => value >>> 0)";
+
+	type Type = Self;
+
+	fn into_raw(self) -> Self::Type {
+		self
+	}
+}
+
+// SAFETY: Implementation.
+unsafe impl Input for f32 {
+	const IMPORT_TYPE: &str = "f32";
+	const TYPE: &str = "f32";
 
 	type Type = Self;
 
@@ -33,6 +284,18 @@ unsafe impl Input for usize {
 	}
 }
 
+// SAFETY: JS numbers are rounded to `f32` by the Wasm import.
+unsafe impl Output for f32 {
+	const IMPORT_TYPE: &str = "f32";
+	const TYPE: &str = "f32";
+
+	type Type = Self;
+
+	fn from_raw(raw: Self::Type) -> Self {
+		raw
+	}
+}
+
 // SAFETY: Implementation.
 unsafe impl Input for f64 {
 	const IMPORT_TYPE: &str = "f64";
@@ -45,25 +308,72 @@ unsafe impl Input for f64 {
 	}
 }
 
+// SAFETY: Implementation.
+unsafe impl Output for f64 {
+	const IMPORT_TYPE: &str = "f64";
+	const TYPE: &str = "f64";
+
+	type Type = Self;
+
+	fn from_raw(raw: Self::Type) -> Self {
+		raw
+	}
+}
+
+// SAFETY: Code points are passed as `i32` and converted to a JS string.
+unsafe impl Input for char {
+	const IMPORT_TYPE: &str = "i32";
+	const TYPE: &str = "i32";
+	const JS_CONV: &str = "((value) => String.fromCodePoint(value))";
+
+	type Type = u32;
+
+	fn into_raw(self) -> Self::Type {
+		self.into()
+	}
+}
+
+// SAFETY: JS strings that aren't a single valid code point are rejected, the
+// code point is passed as `i32`.
+unsafe impl Output for char {
+	const IMPORT_TYPE: &str = "i32";
+	const TYPE: &str = "i32";
+	const JS_CONV: &str = concat!(
+		"((value) => {\n",
+		"\t\tconst codePoint = value.codePointAt(0)\n",
+		"\t\tif (codePoint !== undefined && String.fromCodePoint(codePoint) === value && (codePoint < 0xD800 || codePoint > 0xDFFF)) {\n",
+		"\t\t\treturn codePoint\n",
+		"\t\t}\n",
+		"\t\tthrow new RangeError('found `' + value + '` which is not a single `char`')\n",
+		"\t})",
+	);
+
+	type Type = u32;
+
+	fn from_raw(raw: Self::Type) -> Self {
+		Self::from_u32(raw).unwrap_or_else(|| panic("found invalid code point for `char`"))
+	}
+}
+
 // SAFETY: Implementation.
 unsafe impl<T> Input for *const T {
-	#[cfg(target_arch = "wasm32")]
+	#[cfg(not(target_arch = "wasm64"))]
 	const IMPORT_TYPE: &str = "i32";
 	#[cfg(target_arch = "wasm64")]
 	const IMPORT_TYPE: &str = "f64";
-	#[cfg(target_arch = "wasm32")]
+	#[cfg(not(target_arch = "wasm64"))]
 	const TYPE: &str = "i32";
 	#[cfg(target_arch = "wasm64")]
 	const TYPE: &str = "f64";
-	#[cfg(target_arch = "wasm32")]
-	const JS_CONV: &str = " >>>= 0";
+	#[cfg(not(target_arch = "wasm64"))]
+	const JS_CONV: &str = "((value) => value >>> 0)";
 
-	#[cfg(target_arch = "wasm32")]
+	#[cfg(not(target_arch = "wasm64"))]
 	type Type = Self;
 	#[cfg(target_arch = "wasm64")]
 	type Type = f64;
 
-	#[cfg(target_arch = "wasm32")]
+	#[cfg(not(target_arch = "wasm64"))]
 	fn into_raw(self) -> Self::Type {
 		self
 	}
@@ -78,3 +388,170 @@ unsafe impl<T> Input for *const T {
 		addr as f64
 	}
 }
+
+// SAFETY: JS numbers are truncated to `i32` by the Wasm import on 32-bit, on
+// 64-bit values that aren't valid addresses are rejected.
+unsafe impl<T> Output for *const T {
+	#[cfg(not(target_arch = "wasm64"))]
+	const IMPORT_TYPE: &str = "i32";
+	#[cfg(target_arch = "wasm64")]
+	const IMPORT_TYPE: &str = "f64";
+	#[cfg(not(target_arch = "wasm64"))]
+	const TYPE: &str = "i32";
+	#[cfg(target_arch = "wasm64")]
+	const TYPE: &str = "f64";
+
+	#[cfg(not(target_arch = "wasm64"))]
+	type Type = Self;
+	#[cfg(target_arch = "wasm64")]
+	type Type = f64;
+
+	#[cfg(not(target_arch = "wasm64"))]
+	fn from_raw(raw: Self::Type) -> Self {
+		raw
+	}
+
+	#[cfg(target_arch = "wasm64")]
+	fn from_raw(raw: Self::Type) -> Self {
+		let addr = raw as usize;
+
+		if addr as f64 == raw {
+			core::ptr::with_exposed_provenance(addr)
+		} else {
+			out_of_range(raw, "pointer")
+		}
+	}
+}
+
+// SAFETY: Delegated to already implemented types.
+unsafe impl<T> Input for *mut T {
+	const IMPORT_TYPE: &str = <*const T as Input>::IMPORT_TYPE;
+	const TYPE: &str = <*const T as Input>::TYPE;
+	const JS_CONV: &str = <*const T as Input>::JS_CONV;
+
+	type Type = <*const T as Input>::Type;
+
+	fn into_raw(self) -> Self::Type {
+		self.cast_const().into_raw()
+	}
+}
+
+// SAFETY: Delegated to already implemented types.
+unsafe impl<T> Output for *mut T {
+	const IMPORT_TYPE: &str = <*const T as Output>::IMPORT_TYPE;
+	const TYPE: &str = <*const T as Output>::TYPE;
+
+	type Type = <*const T as Output>::Type;
+
+	fn from_raw(raw: Self::Type) -> Self {
+		<*const T>::from_raw(raw).cast_mut()
+	}
+}
+
+fn out_of_range(value: impl Display, ty: &str) -> ! {
+	panic(&format!("found `{value}` out of range for `{ty}`"))
+}
+
+/// Values pass Wasm exports the same way they pass imports, only in the other
+/// direction. So parameters convert like [`Output`] and return values like
+/// [`Input`].
+macro_rules! export {
+	($($ty:ty: $ts:literal),*) => {$(
+		// SAFETY: Delegated to `Output`.
+		unsafe impl ExportInput for $ty {
+			const TS_TYPE: &str = $ts;
+			const JS_CONV: &str = <Self as Output>::JS_CONV;
+
+			type Type = <Self as Output>::Type;
+			type Owned = Self;
+
+			fn from_raw(raw: Self::Type) -> Self::Owned {
+				<Self as Output>::from_raw(raw)
+			}
+		}
+
+		// SAFETY: Delegated to `Input`.
+		unsafe impl ExportOutput for $ty {
+			const TS_TYPE: &str = $ts;
+			const JS_CONV: &str = <Self as Input>::JS_CONV;
+
+			type Type = <Self as Input>::Type;
+
+			fn into_raw(self) -> Self::Type {
+				<Self as Input>::into_raw(self)
+			}
+		}
+	)*};
+}
+
+export!(
+	bool: "boolean",
+	i8: "number",
+	u8: "number",
+	i16: "number",
+	u16: "number",
+	i32: "number",
+	u32: "number",
+	i64: "bigint",
+	u64: "bigint",
+	isize: "number",
+	f32: "number",
+	f64: "number",
+	char: "string"
+);
+
+// SAFETY: JS values that aren't integers in range are rejected, on 32-bit in JS
+// and on 64-bit in Rust.
+unsafe impl ExportInput for usize {
+	const TS_TYPE: &str = "number";
+	#[cfg(not(target_arch = "wasm64"))]
+	const JS_CONV: &str = range_conv!(usize: 0..=4294967295);
+
+	#[cfg(not(target_arch = "wasm64"))]
+	type Type = Self;
+	#[cfg(target_arch = "wasm64")]
+	type Type = f64;
+	type Owned = Self;
+
+	#[cfg(not(target_arch = "wasm64"))]
+	fn from_raw(raw: Self::Type) -> Self::Owned {
+		raw
+	}
+
+	#[cfg(target_arch = "wasm64")]
+	fn from_raw(raw: Self::Type) -> Self::Owned {
+		let value = raw as Self;
+
+		if value as f64 == raw {
+			value
+		} else {
+			out_of_range(raw, "usize")
+		}
+	}
+}
+
+// SAFETY: Implementation.
+unsafe impl ExportOutput for usize {
+	const TS_TYPE: &str = "number";
+	#[cfg(not(target_arch = "wasm64"))]
+	const JS_CONV: &str = "((value) => value >>> 0)";
+
+	#[cfg(not(target_arch = "wasm64"))]
+	type Type = Self;
+	#[cfg(target_arch = "wasm64")]
+	type Type = f64;
+
+	#[cfg(not(target_arch = "wasm64"))]
+	fn into_raw(self) -> Self::Type {
+		self
+	}
+
+	#[cfg(target_arch = "wasm64")]
+	fn into_raw(self) -> Self::Type {
+		debug_assert!(
+			self < 0x20000000000000,
+			"found `usize` bigger than `Number.MAX_SAFE_INTEGER`"
+		);
+		self as f64
+	}
+}
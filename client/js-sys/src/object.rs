@@ -0,0 +1,130 @@
+use js_sys_macro::js_sys;
+
+use crate::{JsArray, JsString, JsValue, reflect};
+
+/// A property key accepted by [`JsObject`], either a [`prim@str`] or any JS
+/// value.
+pub trait PropertyKey {
+	#[doc(hidden)]
+	fn with_key<R>(self, f: impl FnOnce(&JsValue) -> R) -> R;
+}
+
+impl PropertyKey for &str {
+	fn with_key<R>(self, f: impl FnOnce(&JsValue) -> R) -> R {
+		f(&JsString::from_str(self))
+	}
+}
+
+impl<T: AsRef<JsValue>> PropertyKey for &T {
+	fn with_key<R>(self, f: impl FnOnce(&JsValue) -> R) -> R {
+		f(self.as_ref())
+	}
+}
+
+impl JsObject {
+	/// Creates a new empty object, equivalent to JS `{}`.
+	#[must_use]
+	pub fn new() -> Self {
+		object_new()
+	}
+
+	/// Returns the value of the property `key`, `undefined` if it doesn't
+	/// exist.
+	pub fn get(&self, key: impl PropertyKey) -> JsValue {
+		key.with_key(|key| reflect::get(self, key))
+	}
+
+	/// Sets the property `key` to `value`. Returns `false` if the property
+	/// couldn't be set, e.g. because this object is frozen.
+	pub fn set(&self, key: impl PropertyKey, value: &JsValue) -> bool {
+		key.with_key(|key| reflect::set(self, key, value))
+	}
+
+	/// Returns `true` if this object or its prototype chain has the property
+	/// `key`.
+	pub fn has(&self, key: impl PropertyKey) -> bool {
+		key.with_key(|key| reflect::has(self, key))
+	}
+
+	/// Deletes the property `key`. Returns `false` if the property couldn't be
+	/// deleted.
+	pub fn delete(&self, key: impl PropertyKey) -> bool {
+		key.with_key(|key| reflect::delete_property(self, key))
+	}
+
+	/// Defines the property `key` with a property descriptor like JS
+	/// `Object.defineProperty()`. Returns `false` if the property couldn't be
+	/// defined.
+	pub fn define_property(&self, key: impl PropertyKey, descriptor: &Self) -> bool {
+		key.with_key(|key| reflect::define_property(self, key, descriptor))
+	}
+
+	/// Returns the names of the own enumerable string properties.
+	#[must_use]
+	pub fn keys(&self) -> JsArray<JsString> {
+		object_keys(self)
+	}
+
+	/// Returns the values of the own enumerable string properties.
+	#[must_use]
+	pub fn values(&self) -> JsArray {
+		object_values(self)
+	}
+
+	/// Returns `[key, value]` pairs of the own enumerable string properties.
+	#[must_use]
+	pub fn entries(&self) -> JsArray<JsArray> {
+		object_entries(self)
+	}
+
+	/// Copies all own enumerable properties of `source` into this object.
+	pub fn assign(&self, source: &Self) {
+		object_assign(self, source);
+	}
+
+	/// Freezes this object, preventing any further changes to its properties.
+	pub fn freeze(&self) {
+		object_freeze(self);
+	}
+
+	#[must_use]
+	pub fn is_frozen(&self) -> bool {
+		object_is_frozen(self)
+	}
+}
+
+impl Default for JsObject {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[js_sys(js_sys = crate)]
+extern "C" {
+	#[js_sys(
+		is_type_of = "(value) => (typeof value === 'object' && value !== null) || typeof value === 'function'",
+		ts_type = "object"
+	)]
+	pub type JsObject;
+
+	#[js_sys(js_name = "Object")]
+	fn object_new() -> JsObject;
+
+	#[js_sys(js_name = "Object.keys")]
+	fn object_keys(object: &JsObject) -> JsArray<JsString>;
+
+	#[js_sys(js_name = "Object.values")]
+	fn object_values(object: &JsObject) -> JsArray;
+
+	#[js_sys(js_name = "Object.entries")]
+	fn object_entries(object: &JsObject) -> JsArray<JsArray>;
+
+	#[js_sys(js_name = "Object.assign")]
+	fn object_assign(target: &JsObject, source: &JsObject);
+
+	#[js_sys(js_name = "Object.freeze")]
+	fn object_freeze(object: &JsObject);
+
+	#[js_sys(js_name = "Object.isFrozen")]
+	fn object_is_frozen(object: &JsObject) -> bool;
+}
@@ -1,11 +1,20 @@
-#[cfg(not(debug_assertions))]
 use alloc::format;
-#[cfg(all(not(debug_assertions), target_arch = "wasm32"))]
+#[cfg(target_arch = "wasm32")]
 use core::arch::wasm32 as wasm;
-#[cfg(all(not(debug_assertions), target_arch = "wasm64"))]
+#[cfg(target_arch = "wasm64")]
 use core::arch::wasm64 as wasm;
 use core::fmt::Debug;
+#[cfg(target_family = "wasm")]
+use core::panic::Location;
 
+#[cfg(target_family = "wasm")]
+use js_sys_macro::js_sys;
+
+#[cfg(target_family = "wasm")]
+use crate::util::PtrLength;
+
+/// Like [`Option::expect()`] and [`Result::expect()`] but throws a JS
+/// exception with [`panic()`] instead of panicking.
 pub trait UnwrapThrowExt<T> {
 	#[track_caller]
 	fn expect_throw(self, message: &str) -> T;
@@ -14,18 +23,6 @@ pub trait UnwrapThrowExt<T> {
 	fn unwrap_throw(self) -> T;
 }
 
-#[cfg(debug_assertions)]
-impl<T> UnwrapThrowExt<T> for Option<T> {
-	fn expect_throw(self, message: &str) -> T {
-		self.expect(message)
-	}
-
-	fn unwrap_throw(self) -> T {
-		self.unwrap()
-	}
-}
-
-#[cfg(not(debug_assertions))]
 impl<T> UnwrapThrowExt<T> for Option<T> {
 	fn expect_throw(self, message: &str) -> T {
 		match self {
@@ -42,18 +39,6 @@ impl<T> UnwrapThrowExt<T> for Option<T> {
 	}
 }
 
-#[cfg(debug_assertions)]
-impl<T, E: Debug> UnwrapThrowExt<T> for Result<T, E> {
-	fn expect_throw(self, message: &str) -> T {
-		self.expect(message)
-	}
-
-	fn unwrap_throw(self) -> T {
-		self.unwrap()
-	}
-}
-
-#[cfg(not(debug_assertions))]
 impl<T, E: Debug> UnwrapThrowExt<T> for Result<T, E> {
 	fn expect_throw(self, message: &str) -> T {
 		match self {
@@ -66,22 +51,60 @@ impl<T, E: Debug> UnwrapThrowExt<T> for Result<T, E> {
 		match self {
 			Ok(value) => value,
 			Err(error) => panic(&format!(
-				"called `Result::unwrap()` on an `Err` value: {error:?}"
+				"called `Result::unwrap_throw()` on an `Err` value: {error:?}"
 			)),
 		}
 	}
 }
 
+/// Throws a JS `Error` with `message` and the location of the caller, without
+/// going through the Rust panic machinery.
+///
+/// On other targets this is a regular panic.
 #[track_caller]
-#[cfg(debug_assertions)]
 pub fn panic(message: &str) -> ! {
-	// TODO: actually throw.
-	panic!("{message}");
+	#[cfg(not(target_family = "wasm"))]
+	core::panic!("{message}");
+
+	#[cfg(target_family = "wasm")]
+	{
+		js_bindgen::embed_js!(
+			name = "panic",
+			js_embed = "string.decode",
+			"(ptr, len, filePtr, fileLen, line, column) => {{",
+			"	const message = this.#jsEmbed.js_sys['string.decode'](ptr, len)",
+			"	const file = this.#jsEmbed.js_sys['string.decode'](filePtr, fileLen)",
+			"	throw new Error(`panicked at ${{file}}:${{line}}:${{column}}:\\n${{message}}`)",
+			"}}",
+		);
+
+		let location = Location::caller();
+		let file = location.file();
+
+		js_panic(
+			message.as_ptr(),
+			PtrLength::new(message.as_ptr(), message.len()),
+			file.as_ptr(),
+			PtrLength::new(file.as_ptr(), file.len()),
+			location.line(),
+			location.column(),
+		);
+
+		// `js_panic()` always throws, trap in case it somehow returns.
+		wasm::unreachable()
+	}
 }
 
-#[track_caller]
-#[cfg(not(debug_assertions))]
-pub fn panic(_: &str) -> ! {
-	// TODO: print message.
-	wasm::unreachable();
+#[cfg(target_family = "wasm")]
+#[js_sys(js_sys = crate)]
+extern "C" {
+	#[js_sys(js_embed = "panic")]
+	fn js_panic(
+		message: *const u8,
+		len: PtrLength,
+		file: *const u8,
+		file_len: PtrLength,
+		line: u32,
+		column: u32,
+	);
 }
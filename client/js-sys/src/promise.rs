@@ -0,0 +1,51 @@
+use core::future::IntoFuture;
+use core::marker::PhantomData;
+
+use js_sys_macro::js_sys;
+
+use crate::{JsCast, JsFuture, JsValue};
+
+impl JsPromise {
+	/// Returns a promise resolved with `value`.
+	#[must_use]
+	pub fn resolve(value: &JsValue) -> Self {
+		promise_resolve(value)
+	}
+
+	/// Returns a promise rejected with `error`.
+	#[must_use]
+	pub fn reject(error: &JsValue) -> Self {
+		promise_reject(error)
+	}
+}
+
+impl<T> JsPromise<T> {
+	#[must_use]
+	pub fn as_any(self) -> JsPromise {
+		JsPromise {
+			value: self.value,
+			_type: PhantomData,
+		}
+	}
+}
+
+impl<T: JsCast> IntoFuture for JsPromise<T> {
+	type Output = Result<T, JsValue>;
+	type IntoFuture = JsFuture<T>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		JsFuture::from(self)
+	}
+}
+
+#[js_sys(js_sys = crate)]
+extern "C" {
+	#[js_sys(instanceof = "Promise", ts_type = "Promise<unknown>")]
+	pub type JsPromise<T = JsValue>;
+
+	#[js_sys(js_name = "Promise.resolve")]
+	fn promise_resolve(value: &JsValue) -> JsPromise;
+
+	#[js_sys(js_name = "Promise.reject")]
+	fn promise_reject(error: &JsValue) -> JsPromise;
+}
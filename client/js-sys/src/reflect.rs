@@ -0,0 +1,29 @@
+//! Bindings to the JS `Reflect` namespace.
+
+use js_sys_macro::js_sys;
+
+use crate::{JsArray, JsObject, JsValue};
+
+#[js_sys(js_sys = crate, namespace = "Reflect")]
+extern "C" {
+	#[js_sys(catch)]
+	pub fn apply(target: &JsValue, this: &JsValue, args: &JsArray) -> Result<JsValue, JsValue>;
+
+	#[js_sys(catch)]
+	pub fn construct(target: &JsValue, args: &JsArray) -> Result<JsValue, JsValue>;
+
+	#[js_sys(js_name = "defineProperty")]
+	pub fn define_property(target: &JsObject, key: &JsValue, descriptor: &JsObject) -> bool;
+
+	#[js_sys(js_name = "deleteProperty")]
+	pub fn delete_property(target: &JsObject, key: &JsValue) -> bool;
+
+	pub fn get(target: &JsObject, key: &JsValue) -> JsValue;
+
+	#[js_sys(js_name = "getPrototypeOf")]
+	pub fn get_prototype_of(target: &JsObject) -> JsValue;
+
+	pub fn has(target: &JsObject, key: &JsValue) -> bool;
+
+	pub fn set(target: &JsObject, key: &JsValue, value: &JsValue) -> bool;
+}
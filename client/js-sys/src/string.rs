@@ -1,5 +1,11 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Display, Formatter};
+
 use js_sys_macro::js_sys;
 
+use crate::hazard::ExportInput;
 use crate::util::PtrLength;
 
 impl JsString {
@@ -30,12 +36,189 @@ impl JsString {
 			PtrLength::new(string.as_ptr(), string.len()),
 		)
 	}
+
+	/// Copies this string into a Rust [`String`]. Lone surrogates are replaced
+	/// with `U+FFFD`.
+	#[must_use]
+	pub fn as_string(&self) -> String {
+		js_bindgen::embed_js!(
+			name = "string.encode",
+			"(string, ptr, len) => {{",
+			"	const encoder = new TextEncoder()",
+			#[cfg(not(target_feature = "atomics"))]
+			"	const view = new Uint8Array(this.#memory.buffer, ptr, len)",
+			#[cfg(not(target_feature = "atomics"))]
+			"	return encoder.encodeInto(string, view).written",
+			#[cfg(target_feature = "atomics")]
+			"	const bytes = encoder.encode(string)",
+			#[cfg(target_feature = "atomics")]
+			"	new Uint8Array(this.#memory.buffer, ptr, len).set(bytes)",
+			#[cfg(target_feature = "atomics")]
+			"	return bytes.length",
+			"}}",
+		);
+
+		// Every UTF-16 code unit takes at most 3 bytes in UTF-8.
+		let capacity = self.len() * 3;
+		let mut bytes = Vec::<u8>::with_capacity(capacity);
+		let ptr = bytes.as_mut_ptr().cast_const();
+		let len = string_encode(self, ptr, PtrLength::new(ptr, capacity));
+
+		// SAFETY: JS wrote `len` bytes of valid UTF-8.
+		unsafe {
+			bytes.set_len(len as usize);
+			String::from_utf8_unchecked(bytes)
+		}
+	}
+
+	/// Returns the length of this string in UTF-16 code units.
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.length() as usize
+	}
+
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Returns a new string with `other` appended.
+	#[must_use]
+	pub fn concat(&self, other: &Self) -> Self {
+		self.js_concat(other)
+	}
+
+	/// Returns the part of this string between `start` and `end` in UTF-16 code
+	/// units. Indices past the end are clamped to [`len()`](Self::len).
+	#[must_use]
+	pub fn slice(&self, start: usize, end: usize) -> Self {
+		self.js_slice(
+			u32::try_from(start).unwrap_or(u32::MAX),
+			u32::try_from(end).unwrap_or(u32::MAX),
+		)
+	}
+
+	/// Returns the index of the first occurrence of `search` in UTF-16 code
+	/// units.
+	#[must_use]
+	pub fn index_of(&self, search: &Self) -> Option<usize> {
+		// JS returns `-1` if `search` wasn't found.
+		usize::try_from(self.js_index_of(search)).ok()
+	}
+}
+
+impl Display for JsString {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.as_string())
+	}
+}
+
+impl Debug for JsString {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		Debug::fmt(&self.as_string(), f)
+	}
+}
+
+impl PartialEq<str> for JsString {
+	fn eq(&self, other: &str) -> bool {
+		// Compare in JS, converting `self` to Rust would replace lone surrogates.
+		js_bindgen::embed_js!(
+			name = "string.eq",
+			js_embed = "string.decode",
+			"(string, ptr, len) => string === this.#jsEmbed.js_sys['string.decode'](ptr, len)",
+		);
+
+		string_eq(
+			self,
+			other.as_ptr(),
+			PtrLength::new(other.as_ptr(), other.len()),
+		)
+	}
+}
+
+impl PartialEq<&str> for JsString {
+	fn eq(&self, other: &&str) -> bool {
+		self == *other
+	}
 }
 
 #[js_sys(js_sys = crate)]
 extern "C" {
+	#[js_sys(
+		is_type_of = "(value) => typeof value === 'string'",
+		ts_type = "string"
+	)]
 	pub type JsString;
 
 	#[js_sys(js_embed = "string.decode")]
 	fn string_decode(array: *const u8, len: PtrLength) -> JsString;
+
+	#[js_sys(js_embed = "string.encode")]
+	fn string_encode(string: &JsString, array: *const u8, len: PtrLength) -> u32;
+
+	#[js_sys(js_embed = "string.eq")]
+	fn string_eq(string: &JsString, array: *const u8, len: PtrLength) -> bool;
+
+	#[js_sys(getter)]
+	fn length(self: &JsString) -> u32;
+
+	#[js_sys(method, js_name = "concat")]
+	fn js_concat(self: &JsString, other: &JsString) -> JsString;
+
+	#[js_sys(method, js_name = "slice")]
+	fn js_slice(self: &JsString, start: u32, end: u32) -> JsString;
+
+	#[js_sys(method, js_name = "indexOf")]
+	fn js_index_of(self: &JsString, search: &JsString) -> i32;
+}
+
+// SAFETY: The string is allocated by `js_sys.string.new` and filled with valid
+// UTF-8 by `TextEncoder`.
+#[expect(clippy::use_self, reason = "`&Self` would be less clear")]
+unsafe impl ExportInput for str {
+	const TS_TYPE: &str = "string";
+	#[cfg(not(target_arch = "wasm64"))]
+	const JS_CONV: &str = concat!(
+		"((string) => {\n",
+		"\t\tconst bytes = new TextEncoder().encode(string)\n",
+		"\t\tconst raw = this.#instance.exports['js_sys.string.new'](bytes.length)\n",
+		"\t\tconst ptr = this.#instance.exports['js_sys.string.ptr'](raw) >>> 0\n",
+		"\t\tnew Uint8Array(this.#memory.buffer, ptr, bytes.length).set(bytes)\n",
+		"\t\treturn raw\n",
+		"\t})",
+	);
+	#[cfg(target_arch = "wasm64")]
+	const JS_CONV: &str = concat!(
+		"((string) => {\n",
+		"\t\tconst bytes = new TextEncoder().encode(string)\n",
+		"\t\tconst raw = this.#instance.exports['js_sys.string.new'](BigInt(bytes.length))\n",
+		"\t\tconst ptr = Number(this.#instance.exports['js_sys.string.ptr'](raw))\n",
+		"\t\tnew Uint8Array(this.#memory.buffer, ptr, bytes.length).set(bytes)\n",
+		"\t\treturn raw\n",
+		"\t})",
+	);
+
+	type Type = *mut String;
+	type Owned = String;
+
+	#[expect(
+		clippy::not_unsafe_ptr_arg_deref,
+		reason = "only called by `#[js_export]` with pointers received from JS"
+	)]
+	fn from_raw(raw: Self::Type) -> Self::Owned {
+		// SAFETY: `raw` was returned by `string_new()`, which hands over ownership.
+		*unsafe { Box::from_raw(raw) }
+	}
+}
+
+/// Allocates a string of `len` bytes for JS to write into.
+#[unsafe(export_name = "js_sys.string.new")]
+extern "C" fn string_new(len: usize) -> *mut String {
+	Box::into_raw(Box::new("\0".repeat(len)))
+}
+
+#[unsafe(export_name = "js_sys.string.ptr")]
+extern "C" fn string_ptr(string: *mut String) -> *mut u8 {
+	// SAFETY: Only called from JS with strings returned by `string_new()`.
+	unsafe { (*string).as_mut_ptr() }
 }
@@ -0,0 +1,100 @@
+use core::fmt::{self, Debug, Formatter};
+
+use js_sys_macro::js_sys;
+
+use crate::{JsCast, JsString, JsValue};
+
+impl JsSymbol {
+	/// Creates a new unique symbol with the given description.
+	#[must_use]
+	pub fn new(description: &str) -> Self {
+		symbol_new(&JsString::from_str(description))
+	}
+
+	/// Returns the symbol registered under `key` in the global symbol
+	/// registry, creating it if it doesn't exist yet. Equivalent to JS
+	/// `Symbol.for()`.
+	#[must_use]
+	pub fn for_key(key: &str) -> Self {
+		symbol_for(&JsString::from_str(key))
+	}
+
+	/// Returns the description of this symbol, if any.
+	#[must_use]
+	pub fn description(&self) -> Option<JsString> {
+		self.js_description().dyn_into().ok()
+	}
+}
+
+macro_rules! well_known {
+	($($name:ident => $js:literal;)*) => {
+		impl JsSymbol {$(
+			#[doc = concat!("Returns the well-known `Symbol.", $js, "`.")]
+			#[must_use]
+			pub fn $name() -> Self {
+				symbol_well_known(&JsString::from_str($js))
+			}
+		)*}
+	};
+}
+
+well_known!(
+	async_iterator => "asyncIterator";
+	has_instance => "hasInstance";
+	is_concat_spreadable => "isConcatSpreadable";
+	iterator => "iterator";
+	r#match => "match";
+	match_all => "matchAll";
+	replace => "replace";
+	search => "search";
+	species => "species";
+	split => "split";
+	to_primitive => "toPrimitive";
+	to_string_tag => "toStringTag";
+	unscopables => "unscopables";
+);
+
+impl PartialEq for JsSymbol {
+	fn eq(&self, other: &Self) -> bool {
+		symbol_equals(self, other)
+	}
+}
+
+impl Eq for JsSymbol {}
+
+impl Debug for JsSymbol {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		Debug::fmt(&self.js_to_string(), f)
+	}
+}
+
+js_bindgen::embed_js!(name = "symbol.wellKnown", "(name) => Symbol[name]");
+
+js_bindgen::embed_js!(name = "symbol.equals", "(a, b) => a === b");
+
+#[js_sys(js_sys = crate)]
+extern "C" {
+	#[js_sys(
+		is_type_of = "(value) => typeof value === 'symbol'",
+		ts_type = "symbol"
+	)]
+	pub type JsSymbol;
+
+	#[js_sys(js_name = "Symbol")]
+	fn symbol_new(description: &JsString) -> JsSymbol;
+
+	#[js_sys(js_name = "Symbol.for")]
+	fn symbol_for(key: &JsString) -> JsSymbol;
+
+	#[js_sys(js_embed = "symbol.wellKnown")]
+	fn symbol_well_known(name: &JsString) -> JsSymbol;
+
+	#[js_sys(js_embed = "symbol.equals")]
+	fn symbol_equals(a: &JsSymbol, b: &JsSymbol) -> bool;
+
+	#[js_sys(getter = "description")]
+	fn js_description(self: &JsSymbol) -> JsValue;
+
+	#[js_sys(method, js_name = "toString")]
+	fn js_to_string(self: &JsSymbol) -> JsString;
+}
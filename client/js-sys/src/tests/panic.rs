@@ -0,0 +1,19 @@
+use crate::UnwrapThrowExt;
+
+#[test]
+#[should_panic(expected = "kaboom")]
+fn panic() {
+	crate::panic("kaboom");
+}
+
+#[test]
+#[should_panic(expected = "kaboom: 42")]
+fn expect_throw() {
+	Err::<(), _>(42).expect_throw("kaboom");
+}
+
+#[test]
+#[should_panic(expected = "called `Option::unwrap_throw()` on a `None` value")]
+fn unwrap_throw() {
+	None::<()>.unwrap_throw();
+}
@@ -0,0 +1,240 @@
+use alloc::vec::Vec;
+
+use js_sys_macro::js_sys;
+
+use crate::util::PtrLength;
+use crate::{JsArray, JsCast, JsValue, r#macro};
+
+macro_rules! typed_array {
+	($($name:ident: $ty:ident, $class:tt;)*) => {
+		/// Index into `typedArray.kinds`, which is generated from the same list.
+		#[expect(clippy::enum_variant_names, reason = "named after the types")]
+		enum Kind {
+			$($name,)*
+		}
+
+		// Each entry holds the class, the `DataView` accessor suffix and whether the
+		// class shares the byte order of Wasm memory, which is always little-endian.
+		js_bindgen::embed_js!(
+			name = "typedArray.kinds",
+			"(() => {{",
+			"	const buffer = new ArrayBuffer(2)",
+			"	new DataView(buffer).setInt16(0, 256, true)",
+			"	const littleEndian = new Int16Array(buffer)[0] === 256",
+			"",
+			"	return [{}].map((Class) => [",
+			"		Class,",
+			"		Class.name.slice(0, -'Array'.length),",
+			"		Class.BYTES_PER_ELEMENT === 1 || littleEndian,",
+			"	])",
+			"}})()",
+			interpolate concat!($($class, ", ",)*),
+		);
+
+		$(typed_array!(@impl $name: $ty, $class);)*
+	};
+	(@impl $name:ident: $ty:ident, $class:tt) => {
+		impl $name {
+			/// Creates a view into Wasm memory without copying `slice`.
+			///
+			/// On big-endian hosts the elements are copied instead, because Wasm
+			/// memory is always little-endian. With the `atomics` target feature
+			/// the view is backed by a `SharedArrayBuffer`, which some JS APIs
+			/// reject.
+			///
+			/// # Safety
+			///
+			/// The view must not be used after Wasm memory grows or `slice` is
+			/// mutated or dropped. JS must not write to the view.
+			#[must_use]
+			pub unsafe fn view(slice: &[$ty]) -> Self {
+				typed_array_view(
+					Kind::$name as u32,
+					slice.as_ptr().cast(),
+					PtrLength::new(slice.as_ptr(), slice.len()),
+				)
+				.unchecked_into()
+			}
+
+			/// Returns the number of elements in this array.
+			#[must_use]
+			pub fn len(&self) -> usize {
+				self.length() as usize
+			}
+
+			#[must_use]
+			pub fn is_empty(&self) -> bool {
+				self.len() == 0
+			}
+
+			/// Copies the elements of this array into `dst`.
+			///
+			/// # Panics
+			///
+			/// If the length of `dst` doesn't match the length of this array.
+			pub fn copy_to(&self, dst: &mut [$ty]) {
+				assert_eq!(self.len(), dst.len(), "found mismatching lengths");
+				typed_array_write(
+					Kind::$name as u32,
+					self.as_ref(),
+					dst.as_mut_ptr().cast_const().cast(),
+					PtrLength::new(dst.as_ptr(), dst.len()),
+				);
+			}
+
+			/// Copies the elements of `src` into this array.
+			///
+			/// # Panics
+			///
+			/// If the length of `src` doesn't match the length of this array.
+			pub fn copy_from(&self, src: &[$ty]) {
+				assert_eq!(self.len(), src.len(), "found mismatching lengths");
+				// SAFETY: The view is only used until `set()` returns.
+				self.js_set(&unsafe { Self::view(src) });
+			}
+
+			/// Copies the elements of this array into a new [`Vec`].
+			#[must_use]
+			pub fn to_vec(&self) -> Vec<$ty> {
+				let mut vec = alloc::vec![<$ty>::default(); self.len()];
+				self.copy_to(&mut vec);
+				vec
+			}
+		}
+
+		impl From<&[$ty]> for $name {
+			fn from(value: &[$ty]) -> Self {
+				// SAFETY: The view is only used until `slice()` returns.
+				unsafe { Self::view(value) }.js_slice()
+			}
+		}
+
+		impl From<&[$ty]> for JsArray<$ty> {
+			fn from(value: &[$ty]) -> Self {
+				// SAFETY: The view is only used until `Array.from()` returns.
+				r#macro::cast(array_from(unsafe { $name::view(value) }.as_ref()))
+			}
+		}
+
+		impl JsArray<$ty> {
+			/// Returns the element at `index`, or [`None`] if it is out of bounds.
+			#[must_use]
+			pub fn get(&self, index: usize) -> Option<$ty> {
+				(index < self.len()).then(|| {
+					#[expect(
+						clippy::cast_possible_truncation,
+						reason = "JS arrays are limited to `u32` indices"
+					)]
+					self.element(index as u32)
+				})
+			}
+
+			/// Returns an iterator over the elements of this array.
+			#[must_use]
+			pub fn iter(&self) -> impl DoubleEndedIterator<Item = $ty> + ExactSizeIterator {
+				#[expect(
+					clippy::cast_possible_truncation,
+					reason = "JS arrays are limited to `u32` indices"
+				)]
+				(0..self.len()).map(|index| self.element(index as u32))
+			}
+
+			/// Copies the elements of this array into a new [`Vec`].
+			#[must_use]
+			pub fn to_vec(&self) -> Vec<$ty> {
+				self.iter().collect()
+			}
+
+			fn element(&self, index: u32) -> $ty {
+				// Declared locally because every element type needs its own import, the
+				// namespace keeps the import names apart.
+				#[js_sys(js_sys = crate, namespace = $class)]
+				extern "C" {
+					#[js_sys(js_embed = "array.get")]
+					fn array_get(array: &JsArray, index: u32) -> $ty;
+				}
+
+				array_get(r#macro::cast_ref(self), index)
+			}
+		}
+
+		#[js_sys(js_sys = crate)]
+		extern "C" {
+			#[js_sys(instanceof = $class, ts_type = $class)]
+			pub type $name;
+
+			#[js_sys(getter)]
+			fn length(self: &$name) -> u32;
+
+			#[js_sys(method, js_name = "slice")]
+			fn js_slice(self: &$name) -> $name;
+
+			#[js_sys(method, js_name = "set")]
+			fn js_set(self: &$name, source: &$name);
+		}
+	};
+}
+
+typed_array!(
+	JsInt8Array: i8, "Int8Array";
+	JsUint8Array: u8, "Uint8Array";
+	JsInt16Array: i16, "Int16Array";
+	JsUint16Array: u16, "Uint16Array";
+	JsInt32Array: i32, "Int32Array";
+	JsUint32Array: u32, "Uint32Array";
+	JsBigInt64Array: i64, "BigInt64Array";
+	JsBigUint64Array: u64, "BigUint64Array";
+	JsFloat32Array: f32, "Float32Array";
+	JsFloat64Array: f64, "Float64Array";
+);
+
+js_bindgen::embed_js!(
+	name = "typedArray.view",
+	js_embed = "typedArray.kinds",
+	"(kind, ptr, len) => {{",
+	"	const [Class, name, native] = this.#jsEmbed.js_sys['typedArray.kinds'][kind]",
+	"",
+	"	if (native) {{",
+	"		return new Class(this.#memory.buffer, ptr, len)",
+	"	}}",
+	"",
+	"	const size = Class.BYTES_PER_ELEMENT",
+	"	const view = new DataView(this.#memory.buffer, ptr, len * size)",
+	"	const array = new Class(len)",
+	"	for (let i = 0; i < len; i++) {{",
+	"		array[i] = view[`get${{name}}`](i * size, true)",
+	"	}}",
+	"	return array",
+	"}}",
+);
+
+js_bindgen::embed_js!(
+	name = "typedArray.write",
+	js_embed = "typedArray.kinds",
+	"(kind, array, ptr, len) => {{",
+	"	const [Class, name, native] = this.#jsEmbed.js_sys['typedArray.kinds'][kind]",
+	"",
+	"	if (native) {{",
+	"		new Class(this.#memory.buffer, ptr, len).set(array)",
+	"		return",
+	"	}}",
+	"",
+	"	const size = Class.BYTES_PER_ELEMENT",
+	"	const view = new DataView(this.#memory.buffer, ptr, len * size)",
+	"	for (let i = 0; i < len; i++) {{",
+	"		view[`set${{name}}`](i * size, array[i], true)",
+	"	}}",
+	"}}",
+);
+
+#[js_sys(js_sys = crate)]
+extern "C" {
+	#[js_sys(js_embed = "typedArray.view")]
+	fn typed_array_view(kind: u32, ptr: *const u8, len: PtrLength) -> JsValue;
+
+	#[js_sys(js_embed = "typedArray.write")]
+	fn typed_array_write(kind: u32, array: &JsValue, ptr: *const u8, len: PtrLength);
+
+	#[js_sys(js_name = "Array.from")]
+	fn array_from(value: &JsValue) -> JsArray;
+}
@@ -1,14 +1,14 @@
 use crate::hazard::Input;
 
 pub(crate) struct PtrLength(
-	#[cfg(target_arch = "wasm32")] usize,
+	#[cfg(not(target_arch = "wasm64"))] usize,
 	#[cfg(target_arch = "wasm64")] f64,
 );
 
 impl PtrLength {
 	pub(crate) fn new<T>(
 		#[cfg_attr(
-			target_arch = "wasm32",
+			not(target_arch = "wasm64"),
 			expect(unused_variables, reason = "32-bit is unchecked")
 		)]
 		ptr: *const T,
@@ -33,7 +33,7 @@ unsafe impl Input for PtrLength {
 	const TYPE: &str = Self::Type::TYPE;
 	const JS_CONV: &str = Self::Type::JS_CONV;
 
-	#[cfg(target_arch = "wasm32")]
+	#[cfg(not(target_arch = "wasm64"))]
 	type Type = usize;
 	#[cfg(target_arch = "wasm64")]
 	type Type = f64;
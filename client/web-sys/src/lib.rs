@@ -28,8 +28,17 @@ pub mod console {
 
 #[cfg(test)]
 mod tests {
+	extern crate alloc;
+
+	use alloc::format;
+	use alloc::rc::Rc;
+	use core::cell::{Cell, RefCell};
+
 	use js_bindgen_test::test;
-	use js_sys::{JsArray, JsString};
+	use js_sys::{
+		Closure, JsArray, JsBigInt, JsBoolean, JsCast, JsInt32Array, JsNumber, JsObject, JsPromise,
+		JsString, JsSymbol, JsUint8Array, JsValue, UnwrapThrowExt, reflect,
+	};
 
 	use super::console;
 
@@ -39,12 +48,385 @@ mod tests {
 		console::log(&value);
 	}
 
+	#[test]
+	fn test_string() {
+		let value = JsString::from_str("hello 🌍");
+		assert_eq!(value, "hello 🌍");
+		assert_eq!(value.len(), 8);
+		assert_ne!(value, "hello");
+		assert_ne!(value, "hello 🌎");
+
+		let world = JsString::from_str("world");
+		assert_eq!(value.slice(0, 6).concat(&world), "hello world");
+		assert_eq!(value.index_of(&JsString::from_str("🌍")), Some(6));
+		assert_eq!(value.index_of(&world), None);
+		assert_eq!(format!("{value:?}"), "\"hello 🌍\"");
+
+		// Lone surrogates must not compare equal to their replacement character.
+		assert_ne!(from_char_code(0xD800), "\u{FFFD}");
+		assert_eq!(from_char_code(0xFFFD), "\u{FFFD}");
+	}
+
+	#[js_sys::js_sys]
+	extern "C" {
+		#[js_sys(js_name = "Math.max")]
+		fn max(a: i8, b: u16) -> i16;
+
+		#[js_sys(js_name = "Math.max", catch)]
+		fn try_max(a: f64, b: f64) -> Result<i8, JsValue>;
+
+		#[js_sys(js_name = "Math.max", catch)]
+		fn try_max_i32(a: f64, b: f64) -> Result<i32, JsValue>;
+
+		#[js_sys(js_name = "Math.max", catch)]
+		fn try_max_u32(a: f64, b: f64) -> Result<u32, JsValue>;
+
+		#[js_sys(js_name = "Boolean")]
+		fn boolean(value: bool) -> bool;
+
+		#[js_sys(js_name = "String")]
+		fn string_to_bool(value: &JsString) -> bool;
+
+		#[js_sys(js_name = "String")]
+		fn string(value: char) -> char;
+
+		#[js_sys(js_name = "String", catch)]
+		fn try_string(value: &JsString) -> Result<char, JsValue>;
+
+		#[js_sys(js_name = "String.fromCharCode")]
+		fn from_char_code(code: u16) -> JsString;
+
+		#[js_sys(js_name = "BigInt")]
+		fn bigint_u64(value: u64) -> u64;
+
+		#[js_sys(js_name = "BigInt")]
+		fn bigint_i128(value: i128) -> i128;
+
+		#[js_sys(js_name = "BigInt", catch)]
+		fn try_bigint_u64(value: f64) -> Result<u64, JsValue>;
+
+		#[js_sys(js_name = "BigInt", catch)]
+		fn try_bigint_i128(value: f64) -> Result<i128, JsValue>;
+
+		type Map;
+
+		#[js_sys(constructor)]
+		fn new() -> Map;
+
+		#[js_sys(instanceof = "HTMLElement")]
+		type HtmlElement;
+
+		#[js_sys(js_name = "document.createElement", catch)]
+		fn create_element(tag: &JsString) -> Result<JsValue, JsValue>;
+
+		#[js_sys(instanceof = "Missing.Class")]
+		type Missing;
+	}
+
+	#[test]
+	fn test_primitives() {
+		assert_eq!(max(-1, 300), 300);
+		assert!(boolean(true));
+		assert!(!boolean(false));
+		assert!(string_to_bool(&JsString::from_str("yes")));
+		assert!(!string_to_bool(&JsString::from_str("")));
+		assert_eq!(string('🌍'), '🌍');
+
+		assert!(matches!(try_max(-1., 5.), Ok(5)));
+		assert!(try_max(1.5, 0.).is_err());
+		assert!(try_max(128., 0.).is_err());
+		assert!(matches!(try_max_i32(-1., i32::MAX.into()), Ok(i32::MAX)));
+		assert!(try_max_i32(f64::from(i32::MAX) + 1., 0.).is_err());
+		assert!(matches!(try_max_u32(-1., u32::MAX.into()), Ok(u32::MAX)));
+		assert!(try_max_u32(-1., -2.).is_err());
+		assert!(try_max_u32(0.5, 0.).is_err());
+
+		assert!(matches!(try_string(&JsString::from_str("🌍")), Ok('🌍')));
+		assert!(try_string(&JsString::from_str("ab")).is_err());
+		assert!(try_string(&JsString::from_str("")).is_err());
+		assert!(try_string(&from_char_code(0xD800)).is_err());
+	}
+
+	#[test]
+	fn test_instanceof() {
+		let map = JsValue::from(Map::new());
+		assert!(map.is_instance_of::<Map>());
+		assert!(map.dyn_ref::<Map>().is_some());
+		assert!(!map.is_instance_of::<JsPromise>());
+		assert!(!map.is_instance_of::<Missing>());
+
+		// Only exists in browsers, elsewhere the check fails without throwing.
+		assert!(!map.is_instance_of::<HtmlElement>());
+		if let Ok(element) = create_element(&JsString::from_str("div")) {
+			assert!(element.is_instance_of::<HtmlElement>());
+		}
+	}
+
+	#[test]
+	fn test_bigint() {
+		assert_eq!(bigint_u64(u64::MAX), u64::MAX);
+		assert_eq!(bigint_i128(i128::MIN), i128::MIN);
+
+		let max = JsBigInt::from(u64::MAX);
+		let one = JsBigInt::from(1_u8);
+		let sum = &max + &one;
+		assert!(sum > max);
+		assert_eq!(u128::try_from(&sum), Ok(1 << 64));
+		assert!(u64::try_from(&sum).is_err());
+		assert_eq!(i128::try_from(-sum), Ok(-(1 << 64)));
+		assert_eq!(
+			JsBigInt::from(i128::MAX) % JsBigInt::from(10),
+			JsBigInt::from(7)
+		);
+	}
+
+	#[test]
+	fn test_catch() {
+		for error in [try_bigint_u64(1.5).err(), try_bigint_i128(1.5).err()] {
+			let Some(Ok(error)) = error.map(JsValue::dyn_into::<JsObject>) else {
+				panic!("expected an error object");
+			};
+			assert!(
+				error
+					.get("name")
+					.dyn_into::<JsString>()
+					.is_ok_and(|name| name == "RangeError")
+			);
+		}
+
+		assert!(matches!(try_bigint_u64(2.), Ok(2)));
+		assert!(matches!(try_bigint_i128(-2.), Ok(-2)));
+	}
+
+	#[test]
+	fn test_value() {
+		assert!(JsValue::NULL.is_null());
+		assert!(JsValue::UNDEFINED.is_undefined());
+		assert!(!JsValue::NULL.is_truthy());
+		assert_eq!(JsValue::NULL.js_typeof(), "object");
+
+		let number = JsValue::from(JsNumber::from(1.5));
+		assert_eq!(number.as_f64(), Some(1.5));
+		assert_eq!(number.as_bool(), None);
+		assert_eq!(JsValue::from(JsBoolean::from(true)).as_bool(), Some(true));
+	}
+
+	#[test]
+	fn test_number() {
+		let value = JsNumber::parse_float("3.14159abc");
+		let Ok(fixed) = value.to_fixed(2) else {
+			panic!("`toFixed()` threw");
+		};
+		assert_eq!(fixed, "3.14");
+		assert!(value.to_fixed(101).is_err());
+		assert!(JsNumber::parse_float("abc").is_nan());
+	}
+
+	#[test]
+	fn test_symbol() {
+		assert_eq!(JsSymbol::for_key("key"), JsSymbol::for_key("key"));
+		assert_ne!(JsSymbol::new("key"), JsSymbol::new("key"));
+		assert_eq!(
+			JsSymbol::iterator().description().unwrap(),
+			"Symbol.iterator"
+		);
+		assert_eq!(format!("{:?}", JsSymbol::new("key")), "\"Symbol(key)\"");
+	}
+
 	#[test]
 	fn test_array() {
-		let value = JsArray::from([42, 43].as_slice());
+		let value = JsArray::from([42_i32, 43].as_slice());
+		console::log(&value);
+		assert_eq!(value.len(), 2);
+		assert_eq!(value.to_vec(), [42, 43]);
+		assert_eq!(value.get(2), None);
+		assert_eq!(JsArray::from([u32::MAX].as_slice()).get(0), Some(u32::MAX));
+		assert_eq!(
+			JsArray::from([-1.5_f32, 2.].as_slice()).iter().next_back(),
+			Some(2.)
+		);
+		assert_eq!(JsArray::from([u64::MAX].as_slice()).to_vec(), [u64::MAX]);
+
+		let array: JsArray<JsString> = ["a", "b"].into_iter().map(JsString::from_str).collect();
+		array.push(&JsString::from_str("c"));
+		assert_eq!(array.get(2).unwrap(), "c");
+		assert!(array.get(3).is_none());
+		assert_eq!(array.to_vec().len(), 3);
+	}
+
+	#[test]
+	fn test_typed_array() {
+		let mut data = [1, -2, i32::MAX];
+		// SAFETY: The view isn't used after `data` is mutated.
+		let view = unsafe { JsInt32Array::view(&data) };
+		assert_eq!(view.to_vec(), data);
+
+		let copy = JsInt32Array::from(data.as_slice());
+		copy.copy_from(&[0; 3]);
+		copy.copy_to(&mut data);
+		assert_eq!(data, [0; 3]);
+
+		let bytes = JsUint8Array::from([1, 2, 3].as_slice());
+		assert_eq!(bytes.to_vec(), [1, 2, 3]);
+		assert_eq!(JsArray::from([u64::MAX].as_slice()).len(), 1);
+	}
+
+	#[test]
+	fn test_object() {
+		let options = JsObject::new();
+		assert!(options.set("method", &JsString::from_str("POST")));
+		assert!(options.has("method"));
+		assert_eq!(options.keys().to_vec(), ["method"]);
+
+		let defaults = JsObject::new();
+		defaults.set("mode", &JsValue::NULL);
+		options.assign(&defaults);
+		assert!(options.get("mode").is_null());
+		assert!(options.delete("mode"));
+		assert!(options.get("mode").is_undefined());
+
+		options.freeze();
+		assert!(options.is_frozen());
+		assert!(!options.set("method", &JsValue::NULL));
+	}
+
+	#[test]
+	fn test_reflect() {
+		let object = JsObject::new().get("constructor");
+		let Ok(value) = reflect::apply(&object, &JsValue::UNDEFINED, &JsArray::new()) else {
+			panic!("`Object()` threw");
+		};
+		assert!(value.is_instance_of::<JsObject>());
+
+		let Ok(value) = reflect::construct(&object, &JsArray::new()) else {
+			panic!("`new Object()` threw");
+		};
+		assert!(reflect::get_prototype_of(&value.unchecked_into()).is_truthy());
+		assert!(reflect::apply(&JsValue::NULL, &JsValue::UNDEFINED, &JsArray::new()).is_err());
+	}
+
+	fn call(function: &JsValue) -> Result<JsValue, JsValue> {
+		reflect::apply(function, &JsValue::UNDEFINED, &JsArray::new())
+	}
+
+	#[test]
+	fn test_closure() {
+		let mut count = 0;
+		let closure = Closure::<dyn FnMut() -> JsNumber>::new(move || {
+			count += 1;
+
+			if count == 2 {
+				js_sys::panic("kaboom");
+			}
+
+			JsNumber::from(count)
+		});
+		assert_eq!(
+			call(closure.as_ref()).ok().and_then(|value| value.as_f64()),
+			Some(1.)
+		);
+		// A JS exception thrown through the closure doesn't poison it.
+		assert!(call(closure.as_ref()).is_err());
+		assert_eq!(
+			call(closure.as_ref()).ok().and_then(|value| value.as_f64()),
+			Some(3.)
+		);
+
+		let holder = JsObject::new();
+		holder.set("function", closure.as_ref());
+		drop(closure);
+		assert!(call(&holder.get("function")).is_err());
+	}
+
+	#[test]
+	fn test_closure_once() {
+		let value = Rc::new(());
+		let captured = Rc::clone(&value);
+		let function = Closure::<dyn FnOnce()>::once(move || drop(captured));
+		assert!(call(&function).is_ok());
+		assert_eq!(Rc::strong_count(&value), 1);
+		assert!(call(&function).is_err());
+	}
+
+	#[test]
+	fn test_closure_drop_while_called() {
+		let slot = Rc::new(RefCell::new(None::<Closure<dyn FnMut()>>));
+		let captured = Rc::clone(&slot);
+		let closure = Closure::new(move || drop(captured.borrow_mut().take()));
+		let holder = JsObject::new();
+		holder.set("function", closure.as_ref());
+		*slot.borrow_mut() = Some(closure);
+
+		let function = holder.get("function");
+		assert!(call(&function).is_ok());
+		// The closure, including its captured `Rc`, is freed after the call.
+		assert_eq!(Rc::strong_count(&slot), 1);
+		assert!(call(&function).is_err());
+	}
+
+	#[test]
+	fn test_unwrap_throw() {
+		let closure = Closure::<dyn FnMut()>::new(|| None::<()>.unwrap_throw());
+		let line = line!() - 1;
+		let Err(error) = call(closure.as_ref()) else {
+			panic!("expected an exception");
+		};
+		let Ok(error) = error.dyn_into::<JsObject>() else {
+			panic!("expected an error object");
+		};
+		let Ok(message) = error.get("message").dyn_into::<JsString>() else {
+			panic!("expected an error message");
+		};
+		let message = format!("{message}");
+
+		assert!(message.starts_with(&format!("panicked at {}:{line}:", file!())));
+		assert!(message.ends_with(":\ncalled `Option::unwrap_throw()` on a `None` value"));
+	}
+
+	#[test]
+	async fn test_async() {
+		let promise = JsPromise::resolve(&JsString::from_str("hello world"));
+		let Ok(value) = promise.await else {
+			panic!("promise rejected");
+		};
 		console::log(&value);
 	}
 
+	#[test]
+	async fn test_spawn_local() {
+		let done = Rc::new(Cell::new(false));
+		let captured = Rc::clone(&done);
+		js_sys::spawn_local(async move {
+			let _ = JsPromise::resolve(&JsValue::NULL).await;
+			captured.set(true);
+		});
+
+		let promise = js_sys::future_to_promise(async { Ok(JsString::from_str("hello")) });
+		let Ok(value) = promise.await else {
+			panic!("promise rejected");
+		};
+		assert_eq!(value, "hello");
+
+		let promise = js_sys::future_to_promise::<_, JsValue>(async { Err(JsValue::NULL) });
+		assert!(promise.await.is_err_and(|error| error.is_null()));
+		assert!(done.get());
+	}
+
+	#[test]
+	#[should_panic(expected = "kaboom")]
+	async fn test_async_should_panic() {
+		let promise = JsPromise::reject(&JsString::from_str("kaboom"));
+		assert!(promise.await.is_ok(), "kaboom");
+	}
+
+	// Sorts after `test_async_should_panic()`, the executor must keep running
+	// after a task panicked.
+	#[test]
+	async fn test_async_survives_panic() {
+		let promise = JsPromise::resolve(&JsString::from_str("hello world"));
+		assert!(promise.await.is_ok());
+	}
+
 	#[test]
 	#[ignore = "hah, it works"]
 	fn test_ignore() {
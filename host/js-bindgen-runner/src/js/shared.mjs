@@ -76,23 +76,51 @@ export async function runTests(module, report) {
         }
         interceptFlag = true;
         try {
-            testFn();
+            const index = testFn();
+            // Async tests return the `externref` table slot of their promise.
+            if (index !== undefined) {
+                const table = jsBindgen.importObject.js_sys["externref.table"];
+                const promise = table.get(index);
+                instance.exports["js_sys.externref.release"](index);
+                await settle(promise, testData.timeout);
+            }
             result = { success: true };
         }
         catch (error) {
-            result = { success: false, stack: error.stack };
+            if (error instanceof TimeoutError) {
+                result = { success: false, stack: error.message, timedOut: true };
+            }
+            else if (error instanceof UncaughtError) {
+                result = {
+                    success: false,
+                    stack: `note: uncaught exception while awaiting the test\n${error.stack}`,
+                };
+            }
+            else {
+                result = { success: false, stack: error.stack };
+            }
         }
         interceptFlag = false;
         if (test.shouldPanic) {
+            if (!result.success && result.timedOut) {
+                report(0 /* Stream.Stdout */, [failedText, newLineText]);
+                let stdout = interceptStore.join("");
+                if (stdout.length !== 0) {
+                    stdout += "\n";
+                }
+                failures.push({ name: test.name, error: stdout + result.stack });
+                continue;
+            }
             if (result.success) {
                 report(0 /* Stream.Stdout */, [failedText, newLineText]);
                 let stdout = interceptStore.join("");
                 failures.push({ name: test.name, error: stdout + "note: test did not panic as expected" });
                 continue;
             }
-            if (typeof test.shouldPanic == "string" &&
-                typeof panicPayload !== "undefined" &&
-                !panicPayload.includes(test.shouldPanic)) {
+            // Exceptions that aren't Rust panics, e.g. from `js_sys::panic()`, are
+            // matched by their stack, so unrelated errors don't pass silently.
+            const panicText = panicPayload ?? result.stack;
+            if (typeof test.shouldPanic == "string" && !panicText.includes(test.shouldPanic)) {
                 report(0 /* Stream.Stdout */, [failedText, newLineText]);
                 let stdout = interceptStore.join("");
                 if (stdout.length !== 0) {
@@ -106,7 +134,7 @@ export async function runTests(module, report) {
                         result.stack +
                         "\n" +
                         "note: panic did not contain expected string\n" +
-                        `      panic message: "${panicPayload}"\n` +
+                        `      panic message: "${panicText}"\n` +
                         ` expected substring: "${test.shouldPanic}"`,
                 });
                 continue;
@@ -155,3 +183,45 @@ export async function runTests(module, report) {
     ]);
     return success;
 }
+class TimeoutError extends Error {
+}
+/** An exception that reached the global error handler while awaiting a test. */
+class UncaughtError extends Error {
+    constructor(error) {
+        super("uncaught exception");
+        this.stack = error?.stack ?? String(error);
+    }
+}
+/**
+ * Waits for the promise of an async test. Panics inside the Rust executor
+ * surface as uncaught exceptions from the microtask queue, so they are caught
+ * here as well. The listener is only installed while awaiting the test, and
+ * caught exceptions are reported as an `UncaughtError`.
+ */
+function settle(promise, timeout) {
+    let timer;
+    let removeListener;
+    return new Promise((resolve, reject) => {
+        timer = setTimeout(() => reject(new TimeoutError(`test timed out after ${timeout}s`)), timeout * 1000);
+        removeListener = onUncaughtError((error) => reject(new UncaughtError(error)));
+        promise.then(resolve, reject);
+    }).finally(() => {
+        clearTimeout(timer);
+        removeListener?.();
+    });
+}
+function onUncaughtError(listener) {
+    const process = globalThis.process;
+    if (typeof process?.on === "function") {
+        process.on("uncaughtException", listener);
+        return () => process.off("uncaughtException", listener);
+    }
+    else {
+        const errorListener = (event) => {
+            event.preventDefault();
+            listener(event.error);
+        };
+        addEventListener("error", errorListener);
+        return () => removeEventListener("error", errorListener);
+    }
+}
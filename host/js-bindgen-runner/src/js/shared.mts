@@ -93,8 +93,8 @@ export async function runTests(
 			continue
 		}
 
-		const testFn = instance.exports[test.importName] as () => void
-		let result: { success: true } | { success: false; stack: string }
+		const testFn = instance.exports[test.importName] as () => number | undefined
+		let result: { success: true } | { success: false; stack: string; timedOut?: true }
 
 		if (test.shouldPanic) {
 			report(Stream.Stdout, [
@@ -107,15 +107,45 @@ export async function runTests(
 		interceptFlag = true
 
 		try {
-			testFn()
+			const index = testFn()
+
+			// Async tests return the `externref` table slot of their promise.
+			if (index !== undefined) {
+				const table = jsBindgen.importObject.js_sys["externref.table"] as WebAssembly.Table
+				const promise = table.get(index) as Promise<void>
+				;(instance.exports["js_sys.externref.release"] as (index: number) => void)(index)
+				await settle(promise, testData.timeout)
+			}
+
 			result = { success: true }
 		} catch (error) {
-			result = { success: false, stack: (error as Error).stack as string }
+			if (error instanceof TimeoutError) {
+				result = { success: false, stack: error.message, timedOut: true }
+			} else if (error instanceof UncaughtError) {
+				result = {
+					success: false,
+					stack: `note: uncaught exception while awaiting the test\n${error.stack}`,
+				}
+			} else {
+				result = { success: false, stack: (error as Error).stack as string }
+			}
 		}
 
 		interceptFlag = false
 
 		if (test.shouldPanic) {
+			if (!result.success && result.timedOut) {
+				report(Stream.Stdout, [failedText, newLineText])
+				let stdout = interceptStore.join("")
+
+				if (stdout.length !== 0) {
+					stdout += "\n"
+				}
+
+				failures.push({ name: test.name, error: stdout + result.stack })
+				continue
+			}
+
 			if (result.success) {
 				report(Stream.Stdout, [failedText, newLineText])
 				let stdout = interceptStore.join("")
@@ -124,11 +154,11 @@ export async function runTests(
 				continue
 			}
 
-			if (
-				typeof test.shouldPanic == "string" &&
-				typeof panicPayload !== "undefined" &&
-				!(panicPayload as string).includes(test.shouldPanic)
-			) {
+			// Exceptions that aren't Rust panics, e.g. from `js_sys::panic()`, are
+			// matched by their stack, so unrelated errors don't pass silently.
+			const panicText: string = panicPayload ?? result.stack
+
+			if (typeof test.shouldPanic == "string" && !panicText.includes(test.shouldPanic)) {
 				report(Stream.Stdout, [failedText, newLineText])
 				let stdout = interceptStore.join("")
 
@@ -145,7 +175,7 @@ export async function runTests(
 						result.stack +
 						"\n" +
 						"note: panic did not contain expected string\n" +
-						`      panic message: "${panicPayload}"\n` +
+						`      panic message: "${panicText}"\n` +
 						` expected substring: "${test.shouldPanic}"`,
 				})
 				continue
@@ -203,3 +233,53 @@ export async function runTests(
 
 	return success
 }
+
+class TimeoutError extends Error {}
+
+/** An exception that reached the global error handler while awaiting a test. */
+class UncaughtError extends Error {
+	constructor(error: unknown) {
+		super("uncaught exception")
+		this.stack = (error as Error | undefined)?.stack ?? String(error)
+	}
+}
+
+/**
+ * Waits for the promise of an async test. Panics inside the Rust executor
+ * surface as uncaught exceptions from the microtask queue, so they are caught
+ * here as well. The listener is only installed while awaiting the test, and
+ * caught exceptions are reported as an `UncaughtError`.
+ */
+function settle(promise: Promise<void>, timeout: number): Promise<void> {
+	let timer: ReturnType<typeof setTimeout> | undefined
+	let removeListener: (() => void) | undefined
+
+	return new Promise<void>((resolve, reject) => {
+		timer = setTimeout(
+			() => reject(new TimeoutError(`test timed out after ${timeout}s`)),
+			timeout * 1000
+		)
+		removeListener = onUncaughtError((error) => reject(new UncaughtError(error)))
+		promise.then(resolve, reject)
+	}).finally(() => {
+		clearTimeout(timer)
+		removeListener?.()
+	})
+}
+
+function onUncaughtError(listener: (error: unknown) => void): () => void {
+	const process = (globalThis as any).process
+
+	if (typeof process?.on === "function") {
+		process.on("uncaughtException", listener)
+		return () => process.off("uncaughtException", listener)
+	} else {
+		const errorListener = (event: ErrorEvent) => {
+			event.preventDefault()
+			listener(event.error)
+		}
+
+		addEventListener("error", errorListener)
+		return () => removeEventListener("error", errorListener)
+	}
+}
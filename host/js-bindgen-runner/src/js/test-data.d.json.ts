@@ -1,6 +1,7 @@
 type TestData = {
 	worker: WorkerKind
 	noCapture: boolean
+	timeout: number
 	filteredCount: number
 	tests: TestEntry[]
 }
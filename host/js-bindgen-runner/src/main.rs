@@ -13,6 +13,7 @@ use std::{env, fs, iter, str};
 use anyhow::{Context, Result, bail};
 use clap::{Parser, ValueEnum};
 use fantoccini::ClientBuilder;
+use js_bindgen_ld_shared::JsBindgenTestSectionParser;
 use js_bindgen_shared::ReadFile;
 use serde::{Serialize, Serializer};
 use tokio::runtime::Runtime;
@@ -22,6 +23,9 @@ use wasmparser::{Parser as WasmParser, Payload};
 use crate::server::{HttpServer, Status};
 use crate::web_driver::WebDriver;
 
+/// Default timeout of async tests in seconds.
+const DEFAULT_TIMEOUT: u64 = 60;
+
 const NODE_JS_JS: &str = include_str!("js/node-js.mjs");
 const SHARED_JS: &str = include_str!("js/shared.mjs");
 const SHARED_TERMINAL_JS: &str = include_str!("js/shared-terminal.mjs");
@@ -126,8 +130,16 @@ fn main() -> Result<()> {
 
 	// The JS file has the same name, just a different file extension.
 	let imports_path = wasm_path.with_extension("mjs");
+	let timeout = match env::var("JBG_TEST_TIMEOUT") {
+		Ok(timeout) => timeout
+			.parse()
+			.context("unable to parse `JBG_TEST_TIMEOUT`")?,
+		Err(VarError::NotPresent) => DEFAULT_TIMEOUT,
+		Err(VarError::NotUnicode(_)) => bail!("unable to parse `JBG_TEST_TIMEOUT`"),
+	};
 	let test_data = TestData {
 		no_capture: args.no_capture,
+		timeout,
 		filtered_count,
 		tests,
 	};
@@ -176,6 +188,8 @@ impl TestArgs {
 #[serde(rename_all = "camelCase")]
 struct TestData {
 	no_capture: bool,
+	/// Timeout of async tests in seconds.
+	timeout: u64,
 	filtered_count: usize,
 	tests: Vec<TestEntry>,
 }
@@ -209,16 +223,9 @@ impl TestEntry {
 			if let Payload::CustomSection(section) = payload?
 				&& section.name() == "js_bindgen.test"
 			{
-				let mut data = section.data();
-
-				while !data.is_empty() {
-					let len = u32::from_le_bytes(
-						data.split_off(..4)
-							.context("invalid test encoding")?
-							.try_into()?,
-					) as usize;
-					let mut data = data.split_off(..len).context("invalid test encoding")?;
+				js_bindgen_ld_shared::check_custom_section_version(&section)?;
 
+				for mut data in JsBindgenTestSectionParser::new(&section) {
 					let ignore = TestAttr::parse(&mut data)?;
 					let should_panic = TestAttr::parse(&mut data)?;
 					let import_name = str::from_utf8(data)?;
@@ -44,9 +44,6 @@ fn test_internal(attr: TokenStream, item: TokenStream) -> Result<TokenStream, To
 
 	let (item, attrs) = strip_test_attributes(item)?;
 	let (ident, is_async) = find_test_ident(&item)?;
-	if is_async {
-		return Err(compile_error(ident.span(), "async tests are not supported"));
-	}
 
 	let mut output = TokenStream::new();
 	output.extend(item);
@@ -75,15 +72,28 @@ fn test_internal(attr: TokenStream, item: TokenStream) -> Result<TokenStream, To
 	let section = custom_section("js_bindgen.test", &data);
 	output.extend(section);
 
-	let wrapper = format!(
-		r#"const _: () = {{
-    		#[unsafe(export_name = ::core::concat!(::core::module_path!(), "::", ::core::stringify!({ident})))]
-    		extern "C" fn jbg_test() {{
-				js_bindgen_test::set_panic_hook();
-				{ident}();
-			}}
-		}};"#
-	);
+	// Async tests return the promise of the test to JS.
+	let wrapper = if is_async {
+		format!(
+			r#"const _: () = {{
+				#[unsafe(export_name = ::core::concat!(::core::module_path!(), "::", ::core::stringify!({ident})))]
+				extern "C" fn jbg_test() -> ::core::primitive::i32 {{
+					js_bindgen_test::set_panic_hook();
+					js_bindgen_test::run_async({ident}())
+				}}
+			}};"#
+		)
+	} else {
+		format!(
+			r#"const _: () = {{
+				#[unsafe(export_name = ::core::concat!(::core::module_path!(), "::", ::core::stringify!({ident})))]
+				extern "C" fn jbg_test() {{
+					js_bindgen_test::set_panic_hook();
+					{ident}();
+				}}
+			}};"#
+		)
+	};
 	output.extend(wrapper.parse::<TokenStream>().unwrap());
 
 	Ok(output)
@@ -135,6 +135,42 @@ fn import_js_internal(input: TokenStream) -> Result<TokenStream, TokenStream> {
 	Ok(output)
 }
 
+#[proc_macro]
+pub fn export_js(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	export_js_internal(input.into())
+		.unwrap_or_else(|e| e)
+		.into()
+}
+
+fn export_js_internal(input: TokenStream) -> Result<TokenStream, TokenStream> {
+	let mut input = input.into_iter().peekable();
+
+	let name = expect_meta_name_value(&mut input, "name")?;
+
+	let mut data = Vec::new();
+	parse_string_arguments(&mut input, Span::mixed_site(), &mut data)?;
+	let output = custom_section(&format!("js_bindgen.export.{name}"), &data);
+
+	Ok(output)
+}
+
+#[proc_macro]
+pub fn type_ts(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	type_ts_internal(input.into()).unwrap_or_else(|e| e).into()
+}
+
+fn type_ts_internal(input: TokenStream) -> Result<TokenStream, TokenStream> {
+	let mut input = input.into_iter().peekable();
+
+	let name = expect_meta_name_value(&mut input, "name")?;
+
+	let mut data = Vec::new();
+	parse_string_arguments(&mut input, Span::mixed_site(), &mut data)?;
+	let output = custom_section(&format!("js_bindgen.type.{name}"), &data);
+
+	Ok(output)
+}
+
 fn parse_string_arguments(
 	mut stream: &mut Peekable<token_stream::IntoIter>,
 	mut previous_span: Span,
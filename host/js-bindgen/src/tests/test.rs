@@ -5,6 +5,7 @@ fn basic() {
 	super::test(
 		crate::embed_asm_internal(quote! { "foo", "bar" }),
 		quote! {
+			#[cfg(target_family = "wasm")]
 			const _: () = {
 				const ARR_0: [u8; 7] = *b"foo\nbar";
 
@@ -16,10 +17,10 @@ fn basic() {
 
 				const _: () = {
 					#[repr(C)]
-					struct Layout([u8; 4], [u8; 7]);
+					struct Layout([u8; 2], [u8; 4], [u8; 7]);
 
 					#[unsafe(link_section = "js_bindgen.assembly")]
-					static CUSTOM_SECTION: Layout = Layout(::core::primitive::u32::to_le_bytes(LEN), ARR_0);
+					static CUSTOM_SECTION: Layout = Layout(::core::primitive::u16::to_le_bytes(1), ::core::primitive::u32::to_le_bytes(LEN), ARR_0);
 				};
 			};
 		},
@@ -31,6 +32,7 @@ fn minimum() {
 	super::test(
 		crate::embed_asm_internal(quote! { "" }),
 		quote! {
+			#[cfg(target_family = "wasm")]
 			const _: () = {
 				const LEN: u32 = {
 					let mut len: usize = 0;
@@ -39,10 +41,10 @@ fn minimum() {
 
 				const _: () = {
 					#[repr(C)]
-					struct Layout([u8; 4]);
+					struct Layout([u8; 2], [u8; 4]);
 
 					#[unsafe(link_section = "js_bindgen.assembly")]
-					static CUSTOM_SECTION: Layout = Layout(::core::primitive::u32::to_le_bytes(LEN));
+					static CUSTOM_SECTION: Layout = Layout(::core::primitive::u16::to_le_bytes(1), ::core::primitive::u32::to_le_bytes(LEN));
 				};
 			};
 		},
@@ -54,6 +56,7 @@ fn no_newline() {
 	super::test(
 		crate::embed_asm_internal(quote! { "foo" }),
 		quote! {
+			#[cfg(target_family = "wasm")]
 			const _: () = {
 				const ARR_0: [u8; 3] = *b"foo";
 
@@ -65,10 +68,10 @@ fn no_newline() {
 
 				const _: () = {
 					#[repr(C)]
-					struct Layout([u8; 4], [u8; 3]);
+					struct Layout([u8; 2], [u8; 4], [u8; 3]);
 
 					#[unsafe(link_section = "js_bindgen.assembly")]
-					static CUSTOM_SECTION: Layout = Layout(::core::primitive::u32::to_le_bytes(LEN), ARR_0);
+					static CUSTOM_SECTION: Layout = Layout(::core::primitive::u16::to_le_bytes(1), ::core::primitive::u32::to_le_bytes(LEN), ARR_0);
 				};
 			};
 		},
@@ -89,6 +92,7 @@ fn merge_strings() {
 			"grault",
 		}),
 		quote! {
+			#[cfg(target_family = "wasm")]
 			const _: () = {
 				const ARR_0: [u8; 12] = *b"foo\nbar\nbaz\n";
 				#[cfg(test)]
@@ -107,6 +111,7 @@ fn merge_strings() {
 				const _: () = {
 					#[repr(C)]
 					struct Layout(
+						[u8; 2],
 						[u8; 4],
 						[u8; 12],
 						#[cfg(test)]
@@ -116,6 +121,7 @@ fn merge_strings() {
 
 					#[unsafe(link_section = "js_bindgen.assembly")]
 					static CUSTOM_SECTION: Layout = Layout(
+						::core::primitive::u16::to_le_bytes(1),
 						::core::primitive::u32::to_le_bytes(LEN),
 						ARR_0,
 						#[cfg(test)]
@@ -140,6 +146,7 @@ fn merge_edge_1() {
 			interpolate &Bar
 		}),
 		quote! {
+			#[cfg(target_family = "wasm")]
 			const _: () = {
 				const ARR_0: [u8; 1] = *b"\n";
 				#[cfg(test)]
@@ -163,6 +170,7 @@ fn merge_edge_1() {
 				const _: () = {
 					#[repr(C)]
 					struct Layout(
+						[u8; 2],
 						[u8; 4],
 						[u8; 1],
 						#[cfg(test)]
@@ -173,6 +181,7 @@ fn merge_edge_1() {
 
 					#[unsafe(link_section = "js_bindgen.assembly")]
 					static CUSTOM_SECTION: Layout = Layout(
+						::core::primitive::u16::to_le_bytes(1),
 						::core::primitive::u32::to_le_bytes(LEN),
 						ARR_0,
 						#[cfg(test)]
@@ -196,6 +205,7 @@ fn merge_edge_2() {
 			"",
 		}),
 		quote! {
+			#[cfg(target_family = "wasm")]
 			const _: () = {
 				#[cfg(test)]
 				const ARR_0: [u8; 1] = *b"\n";
@@ -209,10 +219,11 @@ fn merge_edge_2() {
 
 				const _: () = {
 					#[repr(C)]
-					struct Layout([u8; 4], #[cfg(test)] [u8; 1]);
+					struct Layout([u8; 2], [u8; 4], #[cfg(test)] [u8; 1]);
 
 					#[unsafe(link_section = "js_bindgen.assembly")]
 					static CUSTOM_SECTION: Layout = Layout(
+						::core::primitive::u16::to_le_bytes(1),
 						::core::primitive::u32::to_le_bytes(LEN),
 						#[cfg(test)]
 						ARR_0,
@@ -233,6 +244,7 @@ fn cfg() {
 		   "test3",
 		}),
 		quote! {
+			#[cfg(target_family = "wasm")]
 			const _: () = {
 				const ARR_0: [u8; 6] = *b"test1\n";
 				#[cfg(test)]
@@ -249,10 +261,11 @@ fn cfg() {
 
 				const _: () = {
 					#[repr(C)]
-					struct Layout([u8; 4], [u8; 6], #[cfg(test)] [u8; 6], [u8; 5]);
+					struct Layout([u8; 2], [u8; 4], [u8; 6], #[cfg(test)] [u8; 6], [u8; 5]);
 
 					#[unsafe(link_section = "js_bindgen.assembly")]
 					static CUSTOM_SECTION: Layout = Layout(
+						::core::primitive::u16::to_le_bytes(1),
 						::core::primitive::u32::to_le_bytes(LEN),
 						ARR_0,
 						#[cfg(test)]
@@ -270,6 +283,7 @@ fn escape() {
 	super::test(
 		crate::embed_asm_internal(quote! { "\n\t\"\\{{}}" }),
 		quote! {
+			#[cfg(target_family = "wasm")]
 			const _: () = {
 				const ARR_0: [u8; 6] = *b"\n\t\"\\{}";
 
@@ -281,10 +295,10 @@ fn escape() {
 
 				const _: () = {
 					#[repr(C)]
-					struct Layout([u8; 4], [u8; 6]);
+					struct Layout([u8; 2], [u8; 4], [u8; 6]);
 
 					#[unsafe(link_section = "js_bindgen.assembly")]
-					static CUSTOM_SECTION: Layout = Layout(::core::primitive::u32::to_le_bytes(LEN), ARR_0);
+					static CUSTOM_SECTION: Layout = Layout(::core::primitive::u16::to_le_bytes(1), ::core::primitive::u32::to_le_bytes(LEN), ARR_0);
 				};
 			};
 		},
@@ -296,6 +310,7 @@ fn interpolate() {
 	super::test(
 		crate::embed_asm_internal(quote! { "{}", interpolate "test" }),
 		quote! {
+			#[cfg(target_family = "wasm")]
 			const _: () = {
 				const VAL_0: &str = "test";
 				const LEN_0: usize = ::core::primitive::str::len(VAL_0);
@@ -310,10 +325,10 @@ fn interpolate() {
 
 				const _: () = {
 					#[repr(C)]
-					struct Layout([u8; 4], [u8; LEN_0]);
+					struct Layout([u8; 2], [u8; 4], [u8; LEN_0]);
 
 					#[unsafe(link_section = "js_bindgen.assembly")]
-					static CUSTOM_SECTION: Layout = Layout(::core::primitive::u32::to_le_bytes(LEN), ARR_0);
+					static CUSTOM_SECTION: Layout = Layout(::core::primitive::u16::to_le_bytes(1), ::core::primitive::u32::to_le_bytes(LEN), ARR_0);
 				};
 			};
 		},
@@ -330,6 +345,7 @@ fn interpolate_edge() {
 			interpolate <Foo<Bar::Baz> as Qux>::QUUX,
 		}),
 		quote! {
+			#[cfg(target_family = "wasm")]
 			const _: () = {
 				const VAL_0: &str = foo!();
 				const LEN_0: usize = ::core::primitive::str::len(VAL_0);
@@ -351,10 +367,10 @@ fn interpolate_edge() {
 
 				const _: () = {
 					#[repr(C)]
-					struct Layout([u8; 4], [u8; LEN_0], [u8; 1], [u8; LEN_2]);
+					struct Layout([u8; 2], [u8; 4], [u8; LEN_0], [u8; 1], [u8; LEN_2]);
 
 					#[unsafe(link_section = "js_bindgen.assembly")]
-					static CUSTOM_SECTION: Layout = Layout(::core::primitive::u32::to_le_bytes(LEN), ARR_0, ARR_1, ARR_2);
+					static CUSTOM_SECTION: Layout = Layout(::core::primitive::u16::to_le_bytes(1), ::core::primitive::u32::to_le_bytes(LEN), ARR_0, ARR_1, ARR_2);
 				};
 			};
 		},
@@ -369,6 +385,7 @@ fn import() {
 			"bar", "baz",
 		}),
 		quote! {
+			#[cfg(target_family = "wasm")]
 			const _: () = {
 				const ARR_0: [u8; 1] = *b"\0";
 				const ARR_1: [u8; 7] = *b"bar\nbaz";
@@ -382,10 +399,10 @@ fn import() {
 
 				const _: () = {
 					#[repr(C)]
-					struct Layout([u8; 4], [u8; 1], [u8; 7]);
+					struct Layout([u8; 2], [u8; 4], [u8; 1], [u8; 7]);
 
 					#[unsafe(link_section = "js_bindgen.import.test_crate.foo")]
-					static CUSTOM_SECTION: Layout = Layout(::core::primitive::u32::to_le_bytes(LEN), ARR_0, ARR_1);
+					static CUSTOM_SECTION: Layout = Layout(::core::primitive::u16::to_le_bytes(1), ::core::primitive::u32::to_le_bytes(LEN), ARR_0, ARR_1);
 				};
 			};
 		},
@@ -399,6 +416,7 @@ fn required_embed() {
 			name = "foo", required_embed = "bar", "",
 		}),
 		quote! {
+			#[cfg(target_family = "wasm")]
 			const _: () = {
 				const ARR_0: [u8; 6] = *b"\x02\x03\0bar";
 				const LEN: u32 = {
@@ -411,10 +429,10 @@ fn required_embed() {
 
 				const _: () = {
 					#[repr(C)]
-					struct Layout([u8; 4], [u8; 6]);
+					struct Layout([u8; 2], [u8; 4], [u8; 6]);
 
 					#[unsafe(link_section = "js_bindgen.import.test_crate.foo")]
-					static CUSTOM_SECTION: Layout = Layout(::core::primitive::u32::to_le_bytes(LEN), ARR_0);
+					static CUSTOM_SECTION: Layout = Layout(::core::primitive::u16::to_le_bytes(1), ::core::primitive::u32::to_le_bytes(LEN), ARR_0);
 				};
 			};
 		},
@@ -428,6 +446,7 @@ fn embed() {
 			name = "foo", "",
 		}),
 		quote! {
+			#[cfg(target_family = "wasm")]
 			const _: () = {
 				const ARR_0: [u8; 2] = *b"\0\0";
 				const LEN: u32 = {
@@ -438,10 +457,75 @@ fn embed() {
 
 				const _: () = {
 					#[repr(C)]
-					struct Layout([u8; 4], [u8; 2]);
+					struct Layout([u8; 2], [u8; 4], [u8; 2]);
 
 					#[unsafe(link_section = "js_bindgen.embed.test_crate.foo")]
 					static CUSTOM_SECTION: Layout = Layout(
+						::core::primitive::u16::to_le_bytes(1),
+						::core::primitive::u32::to_le_bytes(LEN),
+						ARR_0,
+					);
+				};
+			};
+		},
+	);
+}
+
+#[test]
+fn export() {
+	super::test(
+		crate::export_js_internal(quote! {
+			name = "foo", "bar",
+		}),
+		quote! {
+			#[cfg(target_family = "wasm")]
+			const _: () = {
+				const ARR_0: [u8; 3] = *b"bar";
+				const LEN: u32 = {
+					let mut len: usize = 0;
+					{ len += 3; }
+					len as u32
+				};
+
+				const _: () = {
+					#[repr(C)]
+					struct Layout([u8; 2], [u8; 4], [u8; 3]);
+
+					#[unsafe(link_section = "js_bindgen.export.foo")]
+					static CUSTOM_SECTION: Layout = Layout(
+						::core::primitive::u16::to_le_bytes(1),
+						::core::primitive::u32::to_le_bytes(LEN),
+						ARR_0,
+					);
+				};
+			};
+		},
+	);
+}
+
+#[test]
+fn r#type() {
+	super::test(
+		crate::type_ts_internal(quote! {
+			name = "foo", "(): void",
+		}),
+		quote! {
+			#[cfg(target_family = "wasm")]
+			const _: () = {
+				const ARR_0: [u8; 8] = *b"(): void";
+				const LEN: u32 = {
+					let mut len: usize = 0;
+					{ len += 8; }
+					len as u32
+				};
+
+				const _: () = {
+					#[repr(C)]
+					struct Layout([u8; 2], [u8; 4], [u8; 8]);
+
+					#[unsafe(link_section = "js_bindgen.type.foo")]
+					static CUSTOM_SECTION: Layout = Layout(
+						::core::primitive::u16::to_le_bytes(1),
 						::core::primitive::u32::to_le_bytes(LEN),
 						ARR_0,
 					);
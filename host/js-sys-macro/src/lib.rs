@@ -4,6 +4,7 @@ mod tests;
 use std::borrow::Cow;
 #[cfg(not(test))]
 use std::env;
+use std::fmt::Write;
 use std::iter;
 use std::iter::Peekable;
 use std::str::FromStr;
@@ -28,12 +29,46 @@ pub fn js_sys(
 		.into()
 }
 
+#[proc_macro_attribute]
+pub fn js_export(
+	attr: proc_macro::TokenStream,
+	original_item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+	let original_item: TokenStream = original_item.into();
+
+	js_export_internal(attr.into(), original_item.clone())
+		.unwrap_or_else(|mut e| {
+			e.extend(original_item);
+			e
+		})
+		.into()
+}
+
 enum JsFunction {
 	Global(String),
 	Embed(String),
 	Import,
 }
 
+enum JsMember {
+	Method,
+	Getter(Option<String>),
+	Setter(Option<String>),
+	Constructor,
+	StaticMethodOf(Vec<TokenTree>),
+}
+
+enum TypeCheck {
+	Instanceof(String),
+	IsTypeOf(String),
+}
+
+struct FnAttributes {
+	function: Option<JsFunction>,
+	member: Option<JsMember>,
+	catch: bool,
+}
+
 fn js_sys_internal(attr: TokenStream, item: TokenStream) -> Result<TokenStream, TokenStream> {
 	let mut attr = attr.into_iter().peekable();
 	let mut item = item.into_iter().peekable();
@@ -117,6 +152,11 @@ fn js_sys_internal(attr: TokenStream, item: TokenStream) -> Result<TokenStream,
 		let mut cfg = None;
 		let mut js_sys = false;
 		let mut js_function_attr = None;
+		let mut member = None;
+		let mut catch = false;
+		let mut extends = Vec::new();
+		let mut type_check = None;
+		let mut ts_type = None;
 
 		while let Some(TokenTree::Punct(p)) = items.peek() {
 			if p.as_char() == '#' {
@@ -164,7 +204,9 @@ fn js_sys_internal(attr: TokenStream, item: TokenStream) -> Result<TokenStream,
 							let TokenTree::Ident(ident) = token else {
 								return Err(compile_error(
 									token.span(),
-									"`js_name`, `js_embed` or `js_import`",
+									"expected `js_name`, `js_embed`, `js_import`, `catch`, `method`, \
+									 `getter`, `setter`, `constructor`, `static_method_of`, `extends`, \
+									 `instanceof`, `is_type_of` or `ts_type`",
 								));
 							};
 
@@ -270,10 +312,146 @@ fn js_sys_internal(attr: TokenStream, item: TokenStream) -> Result<TokenStream,
 
 									js_function_attr = Some(JsFunction::Import);
 								}
+								"catch" => {
+									let span = ident.span();
+									let _ = inner.next();
+
+									if catch {
+										return Err(compile_error(
+											span,
+											"found duplicate `catch` attributes",
+										));
+									}
+
+									if inner.peek().is_some() {
+										expect_punct(
+											&mut inner,
+											',',
+											span,
+											"a `,` after an attribute",
+											false,
+										)?;
+									}
+
+									catch = true;
+								}
+								"method" | "getter" | "setter" | "constructor"
+								| "static_method_of" => {
+									let Some(TokenTree::Ident(ident)) = inner.next() else {
+										unreachable!()
+									};
+									let span = ident.span();
+
+									if member.is_some() {
+										return Err(compile_error(
+											span,
+											"only one of `method`, `getter`, `setter`, \
+											 `constructor` or `static_method_of` can be set",
+										));
+									}
+
+									member = Some(match ident.to_string().as_str() {
+										"method" => JsMember::Method,
+										"getter" => JsMember::Getter(parse_optional_value(
+											&mut inner, span,
+										)?),
+										"setter" => JsMember::Setter(parse_optional_value(
+											&mut inner, span,
+										)?),
+										"constructor" => JsMember::Constructor,
+										"static_method_of" => {
+											let punct = expect_punct(
+												&mut inner,
+												'=',
+												span,
+												"`static_method_of = <type>`",
+												true,
+											)?;
+											let mut ty = Vec::new();
+											parse_ty_or_value(
+												&mut inner,
+												punct.span(),
+												"`static_method_of = <type>`",
+												&mut ty,
+											)?;
+											JsMember::StaticMethodOf(ty)
+										}
+										_ => unreachable!(),
+									});
+
+									if inner.peek().is_some() {
+										expect_punct(
+											&mut inner,
+											',',
+											span,
+											"a `,` after an attribute",
+											false,
+										)?;
+									}
+								}
+								"extends" => {
+									let span = ident.span();
+									let _ = inner.next();
+									let punct = expect_punct(
+										&mut inner,
+										'=',
+										span,
+										"`extends = <type>`",
+										true,
+									)?;
+									let mut parent = Vec::new();
+									parse_ty_or_value(
+										&mut inner,
+										punct.span(),
+										"`extends = <type>`",
+										&mut parent,
+									)?;
+									extends.push(parent);
+
+									if inner.peek().is_some() {
+										expect_punct(
+											&mut inner,
+											',',
+											span,
+											"a `,` after an attribute",
+											false,
+										)?;
+									}
+								}
+								"instanceof" | "is_type_of" => {
+									let (ident, string) = parse_meta_name_value(&mut inner)?;
+
+									if type_check.is_some() {
+										return Err(compile_error(
+											ident.span(),
+											"only one of `instanceof` or `is_type_of` can be set",
+										));
+									}
+
+									type_check = Some(if ident == "instanceof" {
+										TypeCheck::Instanceof(string)
+									} else {
+										TypeCheck::IsTypeOf(string)
+									});
+								}
+								"ts_type" => {
+									let (ident, string) = parse_meta_name_value(&mut inner)?;
+
+									if ts_type.is_some() {
+										return Err(compile_error(
+											ident.span(),
+											"found duplicate `ts_type` attributes",
+										));
+									}
+
+									ts_type = Some(string);
+								}
 								_ => {
 									return Err(compile_error(
 										ident.span(),
-										"expected `js_name` or `js_embed`",
+										"expected `js_name`, `js_embed`, `js_import`, `catch`, `method`, \
+										 `getter`, `setter`, `constructor`, `static_method_of`, `extends`, \
+										 `instanceof`, `is_type_of` or `ts_type`",
 									));
 								}
 							}
@@ -292,22 +470,40 @@ fn js_sys_internal(attr: TokenStream, item: TokenStream) -> Result<TokenStream,
 		let item = ExternItem::parse(&mut items)?;
 
 		match item {
-			ExternItem::Fn(extern_fn) => extern_fn.emit(
-				&mut output,
-				&js_sys_path,
-				namespace.as_deref(),
-				cfg,
-				js_function_attr.as_ref(),
-			),
-			ExternItem::Type(extern_type) => {
-				if js_function_attr.is_some() {
+			ExternItem::Fn(extern_fn) => {
+				if !extends.is_empty() || type_check.is_some() || ts_type.is_some() {
+					return Err(compile_error(
+						extern_fn.name.span(),
+						"`extends`, `instanceof`, `is_type_of` and `ts_type` are only supported on \
+						 types",
+					));
+				}
+
+				extern_fn.emit(
+					&mut output,
+					&js_sys_path,
+					namespace.as_deref(),
+					cfg,
+					FnAttributes {
+						function: js_function_attr,
+						member,
+						catch,
+					},
+				)?;
+			}
+			ExternItem::Type(mut extern_type) => {
+				if js_function_attr.is_some() || member.is_some() || catch {
 					return Err(compile_error(
 						extern_type.name.span(),
-						"types don't support any attributes",
+						"types only support the `extends`, `instanceof`, `is_type_of` and \
+						 `ts_type` attributes",
 					));
 				}
 
-				extern_type.emit(&mut output, &js_sys_path, cfg);
+				extern_type.extends.extend(extends);
+				extern_type.type_check = type_check;
+				extern_type.ts_type = ts_type;
+				extern_type.emit(&mut output, &js_sys_path, namespace.as_deref(), cfg)?;
 			}
 		}
 	}
@@ -315,134 +511,630 @@ fn js_sys_internal(attr: TokenStream, item: TokenStream) -> Result<TokenStream,
 	Ok(output)
 }
 
-enum ExternItem {
-	Fn(ExternFn),
-	Type(ExternType),
-}
-
-impl ExternItem {
-	fn parse(stream: &mut Peekable<token_stream::IntoIter>) -> Result<Self, TokenStream> {
-		let mut visibility = None;
-		let mut peek_next = stream.peek();
+fn js_export_internal(attr: TokenStream, item: TokenStream) -> Result<TokenStream, TokenStream> {
+	let mut attr = attr.into_iter().peekable();
 
-		if let Some(TokenTree::Ident(ident)) = peek_next
-			&& ident == "pub"
-		{
-			let Some(TokenTree::Ident(ident)) = stream.next() else {
-				unreachable!()
-			};
-			visibility = Some(ident);
-			peek_next = stream.peek();
-		}
+	let mut js_sys_path = None;
+	let mut js_name = None;
 
-		let Some(TokenTree::Ident(ident)) = peek_next else {
+	while let Some(token) = attr.peek() {
+		let TokenTree::Ident(ident) = token else {
 			return Err(compile_error(
-				peek_next.map_or(Span::mixed_site(), TokenTree::span),
-				"expected item",
+				token.span(),
+				"expected `js_sys` or `js_name`",
 			));
 		};
 
 		match ident.to_string().as_str() {
-			"fn" => {
-				let mut extern_fn = ExternFn::parse(stream)?;
-				extern_fn.visibility = visibility;
-				Ok(Self::Fn(extern_fn))
+			"js_sys" => {
+				let ident = parse_ident(&mut attr, Span::mixed_site(), "`js_sys = <path>`")?;
+
+				if js_sys_path.is_some() {
+					return Err(compile_error(
+						ident.span(),
+						"`js_sys` attribute already set",
+					));
+				}
+
+				let punct = expect_punct(&mut attr, '=', ident.span(), "`js_sys = <path>`", true)?;
+				let mut out = Vec::new();
+				parse_ty_or_value(&mut attr, punct.span(), "`js_sys = <path>`", &mut out)?;
+				js_sys_path = Some(out);
+
+				if attr.peek().is_some() {
+					expect_punct(&mut attr, ',', ident.span(), "`,` after attribute", false)?;
+				}
 			}
-			"type" => {
-				let mut extern_type = ExternType::parse(stream)?;
-				extern_type.visibility = visibility;
-				Ok(Self::Type(extern_type))
+			"js_name" => {
+				let (ident, string) = parse_meta_name_value(&mut attr)?;
+
+				if js_name.is_some() {
+					return Err(compile_error(
+						ident.span(),
+						"`js_name` attribute already set",
+					));
+				}
+
+				js_name = Some(string);
+			}
+			_ => {
+				return Err(compile_error(
+					ident.span(),
+					"expected `js_sys` or `js_name`",
+				));
 			}
-			_ => Err(compile_error(
-				ident.span(),
-				"expected extern function or type",
-			)),
 		}
 	}
-}
 
-struct ExternFn {
-	visibility: Option<Ident>,
-	r#fn: Ident,
-	name: Ident,
-	parms: Vec<Parameter>,
-	ret_ty: Option<([TokenTree; 2], SpanRange, Vec<TokenTree>)>,
-}
+	let js_sys_path =
+		js_sys_path.unwrap_or_else(|| path(iter::once("js_sys"), Span::mixed_site()).collect());
+	let js_sys = js_sys_path.as_slice();
+
+	let tokens: Vec<_> = item.clone().into_iter().collect();
+	let Some(fn_index) = tokens
+		.iter()
+		.position(|tok| matches!(tok, TokenTree::Ident(ident) if ident == "fn"))
+	else {
+		return Err(compile_error(Span::mixed_site(), "expected function item"));
+	};
 
-struct Parameter {
-	name: Ident,
-	name_string: String,
-	colon: Punct,
-	ty_span: SpanRange,
-	ty: Vec<TokenTree>,
-	comma: Option<Punct>,
-}
+	if let Some(tok) = tokens[..fn_index]
+		.iter()
+		.find(|tok| matches!(tok, TokenTree::Ident(ident) if ident == "async"))
+	{
+		return Err(compile_error(
+			tok.span(),
+			"`async` functions can't be exported",
+		));
+	}
 
-impl ExternFn {
-	fn parse(mut stream: &mut Peekable<token_stream::IntoIter>) -> Result<Self, TokenStream> {
-		let ident = parse_ident(&mut stream, Span::mixed_site(), "function item")?;
-		let ident_span = ident.span();
-		let ident_string = ident.to_string();
+	let mut stream = tokens[fn_index + 1..]
+		.iter()
+		.cloned()
+		.collect::<TokenStream>()
+		.into_iter()
+		.peekable();
+	let name = parse_ident(
+		&mut stream,
+		tokens[fn_index].span(),
+		"identifier after `fn`",
+	)?;
 
-		let (visibility, r#fn) = match ident_string.as_str() {
-			"pub" => (
-				Some(ident),
-				expect_ident(&mut stream, "fn", ident_span, "function item", false)?,
-			),
-			"fn" => (None, ident),
-			_ => return Err(compile_error(ident_span, "expected function item")),
-		};
+	let parms_group = expect_group(
+		&mut stream,
+		Delimiter::Parenthesis,
+		name.span(),
+		"paranthesis after function identifier, generic functions can't be exported",
+	)?;
+	let mut parms_stream = parms_group.stream().into_iter().peekable();
+	let mut parms = Vec::new();
 
-		let name = parse_ident(&mut stream, r#fn.span(), "identifier after `fn`")?;
+	while parms_stream.peek().is_some() {
+		let mut name = parse_ident(&mut parms_stream, parms_group.span(), "parameter name")?;
 
-		let parms_group = expect_group(
-			&mut stream,
-			Delimiter::Parenthesis,
+		if name == "mut" {
+			name = parse_ident(&mut parms_stream, name.span(), "parameter name")?;
+		}
+
+		if name == "self" {
+			return Err(compile_error(name.span(), "methods can't be exported"));
+		}
+
+		let colon = expect_punct(
+			&mut parms_stream,
+			':',
 			name.span(),
-			"paranthesis after function identifier",
+			"colon after parameter name",
+			false,
 		)?;
-		let parms_span = parms_group.span();
 
-		let mut parms = Vec::new();
-		let mut parms_stream = parms_group.stream().into_iter().peekable();
+		// References are stripped, the value is borrowed from the owned value
+		// inside the export, so their lifetime is irrelevant.
+		let reference = if let Some(TokenTree::Punct(p)) = parms_stream.peek()
+			&& p.as_char() == '&'
+		{
+			let ampersand = parms_stream.next().unwrap();
 
-		while parms_stream.peek().is_some() {
-			let name = parse_ident(&mut parms_stream, parms_group.span(), "parameter name")?;
-			let name_string = name.to_string();
-			let colon = expect_punct(
+			if let Some(TokenTree::Punct(p)) = parms_stream.peek()
+				&& p.as_char() == '\''
+			{
+				let quote = parms_stream.next().unwrap();
+				parse_ident(&mut parms_stream, quote.span(), "lifetime")?;
+			}
+
+			if let Some(TokenTree::Ident(ident)) = parms_stream.peek()
+				&& ident == "mut"
+			{
+				return Err(compile_error(
+					(ampersand.span(), ident.span()),
+					"mutable references can't be exported",
+				));
+			}
+
+			true
+		} else {
+			false
+		};
+
+		let mut ty = Vec::new();
+		let ty_span = parse_ty_or_value(&mut parms_stream, colon.span(), "a type", &mut ty)?;
+
+		if parms_stream.peek().is_some() {
+			expect_punct(
 				&mut parms_stream,
-				':',
+				',',
 				name.span(),
-				"colon after parameter name",
+				"`,` after parameter type",
 				false,
 			)?;
-			let mut ty = Vec::new();
-			let ty_span = parse_ty_or_value(&mut parms_stream, colon.span(), "a type", &mut ty)?;
-			let comma = if parms_stream.peek().is_some() {
-				Some(expect_punct(
-					&mut parms_stream,
-					',',
-					name.span(),
-					"`,` after parameter type",
-					false,
-				)?)
-			} else {
-				None
-			};
+		}
 
-			parms.push(Parameter {
-				name,
-				name_string,
-				colon,
-				ty_span,
-				ty,
-				comma,
-			});
+		let name_string = name.to_string();
+		let js_name = name_string
+			.strip_prefix("r#")
+			.unwrap_or(&name_string)
+			.to_owned();
+		// The local of the export is hygienic, so it can't shadow the exported
+		// function.
+		let local = if name_string.starts_with("r#") {
+			Ident::new_raw(&js_name, Span::mixed_site())
+		} else {
+			Ident::new(&js_name, Span::mixed_site())
+		};
+
+		parms.push(ExportParameter {
+			local,
+			js_name,
+			ty_span,
+			ty,
+			reference,
+		});
+	}
+
+	let mut rest: Vec<_> = stream.collect();
+
+	if !matches!(rest.pop(), Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace)
+	{
+		return Err(compile_error(parms_group.span(), "expected function body"));
+	}
+
+	let ret_ty = match rest.as_slice() {
+		[] => None,
+		[TokenTree::Punct(minus), TokenTree::Punct(greater), ty @ ..]
+			if minus.as_char() == '-' && greater.as_char() == '>' && !ty.is_empty() =>
+		{
+			if let Some(tok) = ty
+				.iter()
+				.find(|tok| matches!(tok, TokenTree::Ident(ident) if ident == "where"))
+			{
+				return Err(compile_error(
+					tok.span(),
+					"generic functions can't be exported",
+				));
+			}
+
+			let span = SpanRange::from((ty[0].span(), ty[ty.len() - 1].span()));
+			Some((span, ty.to_vec()))
 		}
+		[tok, ..] => {
+			return Err(compile_error(
+				tok.span(),
+				"expected return type or function body",
+			));
+		}
+	};
 
-		let punct = parse_punct(&mut stream, parms_span, "`;` or a return type")?;
+	#[cfg(not(test))]
+	let package = env::var("CARGO_CRATE_NAME").expect("`CARGO_CRATE_NAME` not found");
+	#[cfg(test)]
+	let package = String::from("test_crate");
+	let name_string = name.to_string();
+	let name_string = name_string.strip_prefix("r#").unwrap_or(&name_string);
+	let js_name = js_name.unwrap_or_else(|| name_string.to_owned());
+	let export_name = format!("{package}.{js_name}");
+	let span = name.span();
+
+	let js_parms = parms
+		.iter()
+		.map(|p| p.js_name.as_str())
+		.collect::<Vec<_>>()
+		.join(", ");
+	let js_call = format!("this.#instance.exports['{export_name}']({js_parms})");
+	let mut js_fmt = format!("({js_parms}) => {{{{\n");
+	let mut js_args = Vec::new();
+	let mut ts_parms = Vec::new();
+	let mut ts_args = Vec::new();
+	let mut rust_parms = Vec::new();
+	let mut rust_conv = Vec::new();
+	let mut rust_args = Vec::new();
+
+	for ExportParameter {
+		local,
+		js_name,
+		ty_span,
+		ty,
+		reference,
+	} in &parms
+	{
+		let check_list = || {
+			js_sys_hazard(ty, js_sys, "ExportInput", "JS_CONV", *ty_span)
+				.chain(iter::once(Punct::new(',', Spacing::Alone).into()))
+				.collect::<TokenStream>()
+		};
 
-		let ret_ty = match punct.as_char() {
+		js_fmt.push_str("{}{}{}");
+		js_args.extend(select(
+			js_sys,
+			"",
+			iter::once(Literal::string(&format!("\t{js_name} = ")).into()),
+			check_list(),
+			*ty_span,
+		));
+		js_args.push(Ident::new("interpolate", ty_span.start).into());
+		js_args.extend(js_sys_hazard(
+			ty,
+			js_sys,
+			"ExportInput",
+			"JS_CONV",
+			*ty_span,
+		));
+		js_args.push(Punct::new(',', Spacing::Alone).into());
+		js_args.extend(select(
+			js_sys,
+			"",
+			iter::once(Literal::string(&format!("({js_name})\n")).into()),
+			check_list(),
+			*ty_span,
+		));
+
+		ts_parms.push(format!("{js_name}: {{}}"));
+		ts_args.push(Ident::new("interpolate", ty_span.start).into());
+		ts_args.extend(js_sys_hazard(
+			ty,
+			js_sys,
+			"ExportInput",
+			"TS_TYPE",
+			*ty_span,
+		));
+		ts_args.push(Punct::new(',', Spacing::Alone).into());
+
+		// `<local>: <ty as ExportInput>::Type,`
+		rust_parms.extend([
+			TokenTree::from(local.clone()),
+			Punct::new(':', Spacing::Alone).into(),
+		]);
+		rust_parms.extend(js_sys_hazard(ty, js_sys, "ExportInput", "Type", *ty_span));
+		rust_parms.push(Punct::new(',', Spacing::Alone).into());
+
+		// `let <local> = <ty as ExportInput>::from_raw(<local>);`
+		rust_conv.extend([
+			TokenTree::from(Ident::new("let", ty_span.start)),
+			local.clone().into(),
+			Punct::new('=', Spacing::Alone).into(),
+		]);
+		rust_conv.extend(js_sys_hazard(
+			ty,
+			js_sys,
+			"ExportInput",
+			"from_raw",
+			*ty_span,
+		));
+		rust_conv.extend([
+			TokenTree::from(Group::new(
+				Delimiter::Parenthesis,
+				iter::once(TokenTree::from(local.clone())).collect(),
+			)),
+			Punct::new(';', Spacing::Alone).into(),
+		]);
+
+		// `::core::borrow::Borrow::borrow(&<local>),` or `<local>,`
+		if *reference {
+			rust_args.extend(path(["core", "borrow", "Borrow", "borrow"], *ty_span));
+			rust_args.push(
+				Group::new(
+					Delimiter::Parenthesis,
+					[
+						TokenTree::from(Punct::new('&', Spacing::Alone)),
+						local.clone().into(),
+					]
+					.into_iter()
+					.collect(),
+				)
+				.into(),
+			);
+		} else {
+			rust_args.push(local.clone().into());
+		}
+
+		rust_args.push(Punct::new(',', Spacing::Alone).into());
+	}
+
+	let mut rust_call = vec![
+		TokenTree::from(name.clone()),
+		Group::new(Delimiter::Parenthesis, rust_args.into_iter().collect()).into(),
+	];
+	let mut rust_ret = Vec::new();
+	let ts_ret;
+
+	if let Some((ret_span, ret_ty)) = &ret_ty {
+		write!(
+			js_fmt,
+			"\treturn {{}}{{}}{}{{}}\n}}}}",
+			escape_braces(&js_call)
+		)
+		.unwrap();
+		js_args.extend(
+			js_conv_ret(js_sys, "ExportOutput", ret_ty, *ret_span)
+				.into_iter()
+				.flatten(),
+		);
+
+		ts_args.push(Ident::new("interpolate", ret_span.start).into());
+		ts_args.extend(js_sys_hazard(
+			ret_ty,
+			js_sys,
+			"ExportOutput",
+			"TS_TYPE",
+			*ret_span,
+		));
+		ts_args.push(Punct::new(',', Spacing::Alone).into());
+		ts_ret = "{}";
+
+		// `-> <ty as ExportOutput>::Type`
+		rust_ret.extend([
+			TokenTree::from(Punct::new('-', Spacing::Joint)),
+			Punct::new('>', Spacing::Alone).into(),
+		]);
+		rust_ret.extend(js_sys_hazard(
+			ret_ty,
+			js_sys,
+			"ExportOutput",
+			"Type",
+			*ret_span,
+		));
+
+		// `<ty as ExportOutput>::into_raw(<call>)`
+		rust_call = js_sys_hazard(ret_ty, js_sys, "ExportOutput", "into_raw", *ret_span)
+			.chain(iter::once(
+				Group::new(Delimiter::Parenthesis, rust_call.into_iter().collect()).into(),
+			))
+			.collect();
+	} else {
+		write!(js_fmt, "\t{}\n}}}}", escape_braces(&js_call)).unwrap();
+		ts_ret = "void";
+		rust_call.push(Punct::new(';', Spacing::Alone).into());
+	}
+
+	let ts_fmt = format!("({}): {ts_ret}", ts_parms.join(", "));
+
+	// `<js_sys>::js_bindgen::<r#macro>!(name = "<js_name>", "<fmt>", <args>);`
+	let bindgen_macro = |r#macro: &'static str, fmt: String, args: Vec<TokenTree>| {
+		path_with_js_sys(js_sys, ["js_bindgen", r#macro], span)
+			.chain([
+				TokenTree::from(Punct::new('!', Spacing::Alone)),
+				Group::new(
+					Delimiter::Parenthesis,
+					[
+						TokenTree::from(Ident::new("name", span)),
+						Punct::new('=', Spacing::Alone).into(),
+						Literal::string(&js_name).into(),
+						Punct::new(',', Spacing::Alone).into(),
+						Literal::string(&fmt).into(),
+						Punct::new(',', Spacing::Alone).into(),
+					]
+					.into_iter()
+					.chain(args)
+					.collect(),
+				)
+				.into(),
+				Punct::new(';', Spacing::Alone).into(),
+			])
+			.collect::<Vec<_>>()
+	};
+
+	// The name of the export is different from the exported function, which it
+	// would shadow otherwise.
+	let export = Ident::new(&format!("__js_export_{name_string}"), Span::mixed_site());
+
+	// ```
+	// #[unsafe(export_name = "<package>.<js_name>")]
+	// extern "C" fn <export>(<parms>) -> <ret> { <conv> <call> }
+	// ```
+	let export_fn = [
+		TokenTree::from(Punct::new('#', Spacing::Alone)),
+		Group::new(
+			Delimiter::Bracket,
+			[
+				TokenTree::from(Ident::new("unsafe", span)),
+				Group::new(
+					Delimiter::Parenthesis,
+					[
+						TokenTree::from(Ident::new("export_name", span)),
+						Punct::new('=', Spacing::Alone).into(),
+						Literal::string(&export_name).into(),
+					]
+					.into_iter()
+					.collect(),
+				)
+				.into(),
+			]
+			.into_iter()
+			.collect(),
+		)
+		.into(),
+		Ident::new("extern", span).into(),
+		Literal::string("C").into(),
+		Ident::new("fn", span).into(),
+		export.into(),
+		Group::new(Delimiter::Parenthesis, rust_parms.into_iter().collect()).into(),
+	]
+	.into_iter()
+	.chain(rust_ret)
+	.chain(iter::once(
+		Group::new(
+			Delimiter::Brace,
+			rust_conv.into_iter().chain(rust_call).collect(),
+		)
+		.into(),
+	));
+
+	// `const _: () = { <export_js> <type_ts> <export_fn> };`
+	let block = bindgen_macro("export_js", js_fmt, js_args)
+		.into_iter()
+		.chain(bindgen_macro("type_ts", ts_fmt, ts_args))
+		.chain(export_fn)
+		.collect();
+
+	let mut output = item;
+	output.extend([
+		TokenTree::from(Ident::new("const", span)),
+		Ident::new("_", span).into(),
+		Punct::new(':', Spacing::Alone).into(),
+		Group::new(Delimiter::Parenthesis, TokenStream::new()).into(),
+		Punct::new('=', Spacing::Alone).into(),
+		Group::new(Delimiter::Brace, block).into(),
+		Punct::new(';', Spacing::Alone).into(),
+	]);
+
+	Ok(output)
+}
+
+struct ExportParameter {
+	local: Ident,
+	js_name: String,
+	ty_span: SpanRange,
+	ty: Vec<TokenTree>,
+	reference: bool,
+}
+
+enum ExternItem {
+	Fn(ExternFn),
+	Type(ExternType),
+}
+
+impl ExternItem {
+	fn parse(stream: &mut Peekable<token_stream::IntoIter>) -> Result<Self, TokenStream> {
+		let mut visibility = None;
+		let mut peek_next = stream.peek();
+
+		if let Some(TokenTree::Ident(ident)) = peek_next
+			&& ident == "pub"
+		{
+			let Some(TokenTree::Ident(ident)) = stream.next() else {
+				unreachable!()
+			};
+			visibility = Some(ident);
+			peek_next = stream.peek();
+		}
+
+		let Some(TokenTree::Ident(ident)) = peek_next else {
+			return Err(compile_error(
+				peek_next.map_or(Span::mixed_site(), TokenTree::span),
+				"expected item",
+			));
+		};
+
+		match ident.to_string().as_str() {
+			"fn" => {
+				let mut extern_fn = ExternFn::parse(stream)?;
+				extern_fn.visibility = visibility;
+				Ok(Self::Fn(extern_fn))
+			}
+			"type" => {
+				let mut extern_type = ExternType::parse(stream)?;
+				extern_type.visibility = visibility;
+				Ok(Self::Type(extern_type))
+			}
+			_ => Err(compile_error(
+				ident.span(),
+				"expected extern function or type",
+			)),
+		}
+	}
+}
+
+struct ExternFn {
+	visibility: Option<Ident>,
+	r#fn: Ident,
+	name: Ident,
+	parms: Vec<Parameter>,
+	ret_ty: Option<([TokenTree; 2], SpanRange, Vec<TokenTree>)>,
+}
+
+struct Parameter {
+	name: Ident,
+	name_string: String,
+	colon: Punct,
+	ty_span: SpanRange,
+	ty: Vec<TokenTree>,
+	comma: Option<Punct>,
+}
+
+impl ExternFn {
+	fn parse(mut stream: &mut Peekable<token_stream::IntoIter>) -> Result<Self, TokenStream> {
+		let ident = parse_ident(&mut stream, Span::mixed_site(), "function item")?;
+		let ident_span = ident.span();
+		let ident_string = ident.to_string();
+
+		let (visibility, r#fn) = match ident_string.as_str() {
+			"pub" => (
+				Some(ident),
+				expect_ident(&mut stream, "fn", ident_span, "function item", false)?,
+			),
+			"fn" => (None, ident),
+			_ => return Err(compile_error(ident_span, "expected function item")),
+		};
+
+		let name = parse_ident(&mut stream, r#fn.span(), "identifier after `fn`")?;
+
+		let parms_group = expect_group(
+			&mut stream,
+			Delimiter::Parenthesis,
+			name.span(),
+			"paranthesis after function identifier",
+		)?;
+		let parms_span = parms_group.span();
+
+		let mut parms = Vec::new();
+		let mut parms_stream = parms_group.stream().into_iter().peekable();
+
+		while parms_stream.peek().is_some() {
+			let name = parse_ident(&mut parms_stream, parms_group.span(), "parameter name")?;
+			let name_string = name.to_string();
+			let colon = expect_punct(
+				&mut parms_stream,
+				':',
+				name.span(),
+				"colon after parameter name",
+				false,
+			)?;
+			let mut ty = Vec::new();
+			let ty_span = parse_ty_or_value(&mut parms_stream, colon.span(), "a type", &mut ty)?;
+			let comma = if parms_stream.peek().is_some() {
+				Some(expect_punct(
+					&mut parms_stream,
+					',',
+					name.span(),
+					"`,` after parameter type",
+					false,
+				)?)
+			} else {
+				None
+			};
+
+			parms.push(Parameter {
+				name,
+				name_string,
+				colon,
+				ty_span,
+				ty,
+				comma,
+			});
+		}
+
+		let punct = parse_punct(&mut stream, parms_span, "`;` or a return type")?;
+
+		let ret_ty = match punct.as_char() {
 			';' => None,
 			'-' => {
 				let closing = expect_punct(
@@ -482,9 +1174,47 @@ impl ExternFn {
 		js_sys: &[TokenTree],
 		namespace: Option<&str>,
 		cfg: Option<[TokenTree; 2]>,
-		js_function_attr: Option<&JsFunction>,
-	) {
-		let import_name = self.name.to_string();
+		attributes: FnAttributes,
+	) -> Result<(), TokenStream> {
+		let FnAttributes {
+			function: js_function_attr,
+			member,
+			catch,
+		} = attributes;
+		let js_function_attr = js_function_attr.as_ref();
+
+		// With `catch` the import returns `T` of `Result<T, JsValue>`.
+		let ret_ty = if catch {
+			if let Some(JsFunction::Import) = js_function_attr {
+				return Err(compile_error(
+					self.name.span(),
+					"can't set `catch` and `js_import` at the same time",
+				));
+			}
+
+			let Some((_, span, ty)) = &self.ret_ty else {
+				return Err(compile_error(
+					self.name.span(),
+					"`catch` requires a `Result<T, JsValue>` return type",
+				));
+			};
+
+			parse_result_ok(ty, *span)?
+		} else {
+			self.ret_ty
+				.as_ref()
+				.map(|(_, span, ty)| (*span, ty.clone()))
+		};
+
+		let impl_ty = self.impl_ty(member.as_ref(), js_function_attr, ret_ty.as_ref())?;
+		let type_name = impl_ty.as_deref().map(type_name);
+
+		// Members are prefixed with their type to avoid collisions between types.
+		let import_name = if let Some(type_name) = &type_name {
+			format!("{type_name}.{}", self.name)
+		} else {
+			self.name.to_string()
+		};
 		let namespace_import_name = if let Some(namespace) = &namespace {
 			Cow::Owned(format!("{namespace}.{import_name}"))
 		} else {
@@ -496,7 +1226,7 @@ impl ExternFn {
 		#[cfg(test)]
 		let package = String::from("test_crate");
 		let import_parms: String = self.parms.iter().map(|_| "{},").collect();
-		let import_ret = self.ret_ty.as_ref().map(|_| "{}").unwrap_or_default();
+		let import_ret = ret_ty.as_ref().map(|_| "{}").unwrap_or_default();
 
 		let asm_import_name = format!("{package}.import.{namespace_import_name}");
 		let extern_name = format!("{package}.{namespace_import_name}");
@@ -525,9 +1255,8 @@ impl ExternFn {
 				.flat_map(|_| [Cow::Borrowed("{}"), Cow::Borrowed("")]),
 		)
 		.chain(
-			self.ret_ty
-				.as_ref()
-				.into_iter()
+			ret_ty
+				.iter()
 				.flat_map(|_| [Cow::Borrowed("{}"), Cow::Borrowed("")]),
 		)
 		.chain([
@@ -539,12 +1268,7 @@ impl ExternFn {
 		])
 		.chain(parms_input)
 		.chain(iter::once(Cow::Owned(format!("\tcall {asm_import_name}"))))
-		.chain(
-			self.ret_ty
-				.as_ref()
-				.into_iter()
-				.map(|_| Cow::Borrowed("\t{}")),
-		)
+		.chain(ret_ty.iter().map(|_| Cow::Borrowed("\t{}")))
 		.chain(iter::once(Cow::Borrowed("\tend_function")));
 
 		let interpolate = iter::once(TokenTree::from(Ident::new("interpolate", self.name.span())));
@@ -560,7 +1284,7 @@ impl ExternFn {
 				))
 				.chain(iter::once(Punct::new(',', Spacing::Alone).into()))
 		});
-		let out_import_ty_fmt = self.ret_ty.as_ref().into_iter().flat_map(|(_, span, ty)| {
+		let out_import_ty_fmt = ret_ty.iter().flat_map(|(span, ty)| {
 			interpolate
 				.clone()
 				.chain(js_sys_hazard(ty, js_sys, "Output", "IMPORT_TYPE", *span))
@@ -578,7 +1302,7 @@ impl ExternFn {
 				))
 				.chain(iter::once(Punct::new(',', Spacing::Alone).into()))
 		});
-		let out_import_func_fmt = self.ret_ty.as_ref().into_iter().flat_map(|(_, span, ty)| {
+		let out_import_func_fmt = ret_ty.iter().flat_map(|(span, ty)| {
 			interpolate
 				.clone()
 				.chain(js_sys_hazard(ty, js_sys, "Output", "IMPORT_FUNC", *span))
@@ -590,7 +1314,7 @@ impl ExternFn {
 				.chain(js_sys_hazard(ty, js_sys, "Input", "TYPE", name.span()))
 				.chain(iter::once(Punct::new(',', Spacing::Alone).into()))
 		});
-		let out_type_fmt = self.ret_ty.as_ref().into_iter().flat_map(|(_, span, ty)| {
+		let out_type_fmt = ret_ty.iter().flat_map(|(span, ty)| {
 			interpolate
 				.clone()
 				.chain(js_sys_hazard(ty, js_sys, "Output", "TYPE", *span))
@@ -602,7 +1326,7 @@ impl ExternFn {
 				.chain(js_sys_hazard(ty, js_sys, "Input", "CONV", *ty_span))
 				.chain(iter::once(Punct::new(',', Spacing::Alone).into()))
 		});
-		let out_conv_fmt = self.ret_ty.as_ref().into_iter().flat_map(|(_, span, ty)| {
+		let out_conv_fmt = ret_ty.iter().flat_map(|(span, ty)| {
 			interpolate
 				.clone()
 				.chain(js_sys_hazard(ty, js_sys, "Output", "CONV", *span))
@@ -635,40 +1359,112 @@ impl ExternFn {
 				Punct::new(';', Spacing::Alone).into(),
 			]);
 
-		let js_function_name = match &js_function_attr {
-			Some(JsFunction::Global(js_name)) => Cow::Owned(if let Some(namespace) = &namespace {
-				format!("globalThis.{namespace}.{js_name}")
+		let mut js_parms = String::new();
+
+		for Parameter { name_string, .. } in &self.parms {
+			if js_parms.is_empty() {
+				js_parms.push_str(name_string);
 			} else {
-				format!("globalThis.{js_name}")
-			}),
-			Some(JsFunction::Embed(js_name)) => {
-				Cow::Owned(format!("this.#jsEmbed.{package}['{js_name}']"))
+				js_parms.extend([", ", name_string]);
 			}
-			Some(JsFunction::Import) => Cow::Borrowed(namespace_import_name.as_ref()),
-			None => Cow::Owned(format!("globalThis.{namespace_import_name}")),
-		};
+		}
 
-		let js_function = match &js_function_attr {
-			Some(JsFunction::Global(_) | JsFunction::Embed(_)) | None => {
-				if self.parms.is_empty() {
-					vec![Literal::string(&js_function_name).into()]
+		let js_return = if ret_ty.is_some() { "return " } else { "" };
+
+		// Members always require a wrapper, so they can't be referenced directly.
+		let (js_function_name, js_call) = if let Some(member) = &member {
+			let fn_name = self.name.to_string();
+			let js_name = match js_function_attr {
+				Some(JsFunction::Global(js_name)) => js_name.as_str(),
+				_ => fn_name.as_str(),
+			};
+			let class = |class: &str| {
+				if let Some(namespace) = &namespace {
+					format!("globalThis.{namespace}.{class}")
 				} else {
-					let mut js_parms = String::new();
+					format!("globalThis.{class}")
+				}
+			};
+			let type_name = type_name.as_deref().unwrap_or_default();
 
-					for Parameter { name_string, .. } in &self.parms {
-						if js_parms.is_empty() {
-							js_parms.push_str(name_string);
+			let js_call = match member {
+				JsMember::Method => {
+					let args = js_parms.split_once(", ").map_or("", |(_, args)| args);
+					format!("self.{js_name}({args})")
+				}
+				JsMember::Getter(property) => {
+					format!("self.{}", property.as_deref().unwrap_or(js_name))
+				}
+				JsMember::Setter(property) => {
+					let property = property.as_deref().unwrap_or_else(|| {
+						if let Some(JsFunction::Global(js_name)) = js_function_attr {
+							js_name
 						} else {
-							js_parms.extend([", ", name_string]);
+							js_name.strip_prefix("set_").unwrap_or(js_name)
 						}
-					}
+					});
+					format!("self.{property} = {}", self.parms[1].name_string)
+				}
+				JsMember::Constructor => {
+					let class = class(
+						if let Some(JsFunction::Global(js_name)) = js_function_attr {
+							js_name
+						} else {
+							type_name
+						},
+					);
+					format!("new {class}({js_parms})")
+				}
+				JsMember::StaticMethodOf(_) => {
+					format!("{}.{js_name}({js_parms})", class(type_name))
+				}
+			};
+
+			(None, js_call)
+		} else {
+			let js_function_name = match &js_function_attr {
+				Some(JsFunction::Global(js_name)) => {
+					Cow::Owned(if let Some(namespace) = &namespace {
+						format!("globalThis.{namespace}.{js_name}")
+					} else {
+						format!("globalThis.{js_name}")
+					})
+				}
+				Some(JsFunction::Embed(js_name)) => {
+					Cow::Owned(format!("this.#jsEmbed.{package}['{js_name}']"))
+				}
+				Some(JsFunction::Import) => Cow::Borrowed(namespace_import_name.as_ref()),
+				None => Cow::Owned(format!("globalThis.{namespace_import_name}")),
+			};
+			let js_call = format!("{js_function_name}({js_parms})");
+
+			(Some(js_function_name), js_call)
+		};
 
-					let js_select_list = js_select_parms(js_sys, self.parms.iter());
+		let js_function = match (&js_function_attr, js_function_name) {
+			(Some(JsFunction::Import), _) => Vec::new(),
+			(_, Some(js_function_name)) if !catch => {
+				if self.parms.is_empty() && ret_ty.is_none() {
+					vec![Literal::string(&js_function_name).into()]
+				} else {
+					let js_select_list: TokenStream = js_select_parms(js_sys, self.parms.iter())
+						.into_iter()
+						.chain(
+							ret_ty
+								.iter()
+								.flat_map(|(span, ty)| js_select_ret(js_sys, "Output", ty, *span)),
+						)
+						.collect();
 
 					let parms_fmt: String = self.parms.iter().map(|_| "{}{}{}").collect();
+					let ret_fmt = if ret_ty.is_some() {
+						"{}{}{}{}{}{}"
+					} else {
+						"{}"
+					};
 
 					[
-						Literal::string(&format!("{{}}{parms_fmt}{{}}")).into(),
+						Literal::string(&format!("{{}}{parms_fmt}{ret_fmt}")).into(),
 						Punct::new(',', Spacing::Alone).into(),
 					]
 					.into_iter()
@@ -679,46 +1475,94 @@ impl ExternFn {
 						js_select_list.clone(),
 						self.name.span(),
 					))
-					.chain(self.parms.iter().flat_map(|p| {
+					.chain(js_conv_parms(js_sys, &self.parms))
+					.chain(if let Some((span, ty)) = &ret_ty {
+						let [conv, open, close] = js_conv_ret(js_sys, "Output", ty, *span);
+
 						select(
 							js_sys,
 							"",
-							iter::once(Literal::string(&format!("\t{}", p.name_string)).into()),
-							js_select_parms(js_sys, iter::once(p)),
-							p.ty_span,
+							iter::once(Literal::string("\treturn ").into()),
+							js_select_list.clone(),
+							self.name.span(),
 						)
+						.chain(conv)
+						.chain(open)
 						.chain(select(
 							js_sys,
 							"",
-							js_sys_hazard(&p.ty, js_sys, "Input", "JS_CONV", p.ty_span),
-							js_select_parms(js_sys, iter::once(p)),
-							p.ty_span,
+							iter::once(Literal::string(&js_call).into()),
+							js_select_list.clone(),
+							self.name.span(),
 						))
+						.chain(close)
 						.chain(select(
 							js_sys,
 							"",
-							iter::once(Literal::string("\n").into()),
-							js_select_parms(js_sys, iter::once(p)),
-							p.ty_span,
+							iter::once(Literal::string("\n}").into()),
+							js_select_list,
+							self.name.span(),
 						))
-					}))
-					.chain(select(
-						js_sys,
-						"",
-						iter::once(
-							Literal::string(&format!(
-								"\t{}{js_function_name}({js_parms})\n}}",
-								if self.ret_ty.is_some() { "return " } else { "" }
-							))
-							.into(),
-						),
-						js_select_list,
-						self.name.span(),
-					))
+						.collect::<Vec<_>>()
+					} else {
+						select(
+							js_sys,
+							"",
+							iter::once(Literal::string(&format!("\t{js_call}\n}}")).into()),
+							js_select_list,
+							self.name.span(),
+						)
+						.collect()
+					})
 					.collect()
 				}
 			}
-			Some(JsFunction::Import) => Vec::new(),
+			_ => {
+				let head = format!("({js_parms}) => {{\n");
+				let parms_fmt: String = self.parms.iter().map(|_| "{}{}{}").collect();
+				// Placeholders for the conversion of the return value.
+				let js_call = if ret_ty.is_some() {
+					format!("{js_return}{{}}{{}}{}{{}}", escape_braces(&js_call))
+				} else {
+					escape_braces(&js_call)
+				};
+				let body = if catch {
+					// The thrown value is picked up by `js_sys::r#macro::catch()`. The returned
+					// placeholder has to be accepted by the Wasm import.
+					let catch_return = if ret_ty.is_some() {
+						"\n\t\treturn {}"
+					} else {
+						""
+					};
+					format!(
+						"\ttry {{{{\n\t\t{js_call}\n\t}}}} catch (error) {{{{\n\t\tconst \
+						 exception = this.#jsEmbed.js_sys['exception']\n\t\texception.thrown = \
+						 true\n\t\texception.error = error{catch_return}\n\t}}}}\n}}}}"
+					)
+				} else {
+					format!("\t{js_call}\n}}}}")
+				};
+
+				[
+					Literal::string(&format!("{}{parms_fmt}{body}", escape_braces(&head))).into(),
+					Punct::new(',', Spacing::Alone).into(),
+				]
+				.into_iter()
+				.chain(js_conv_parms(js_sys, &self.parms))
+				.chain(
+					ret_ty
+						.iter()
+						.flat_map(|(span, ty)| js_conv_ret(js_sys, "Output", ty, *span))
+						.flatten(),
+				)
+				.chain(
+					ret_ty
+						.iter()
+						.filter(|_| catch)
+						.flat_map(|(span, ty)| js_catch_return(js_sys, ty, *span)),
+				)
+				.collect()
+			}
 		};
 
 		let import_js = path_with_js_sys(js_sys, ["js_bindgen", "import_js"], self.name.span())
@@ -762,8 +1606,15 @@ impl ExternFn {
 			|Parameter {
 			     name, ty_span, ty, ..
 			 }| {
+				// `self` is not allowed in foreign functions.
+				let name = if name == "self" {
+					Ident::new("this", name.span())
+				} else {
+					name.clone()
+				};
+
 				[
-					TokenTree::from(name.clone()),
+					TokenTree::from(name),
 					Punct::new(':', Spacing::Alone).into(),
 				]
 				.into_iter()
@@ -775,8 +1626,9 @@ impl ExternFn {
 		let rust_ty = self
 			.ret_ty
 			.as_ref()
+			.zip(ret_ty.as_ref())
 			.into_iter()
-			.flat_map(|(arrow, span, ty)| {
+			.flat_map(|((arrow, ..), (span, ty))| {
 				arrow
 					.iter()
 					.cloned()
@@ -823,60 +1675,224 @@ impl ExternFn {
 			])
 		});
 
-		let mut call = vec![
-			TokenTree::from(Ident::new("unsafe", self.name.span())),
-			Group::new(
+		let mut call = vec![
+			TokenTree::from(Ident::new("unsafe", self.name.span())),
+			Group::new(
+				Delimiter::Brace,
+				TokenStream::from_iter([
+					TokenTree::from(self.name.clone()),
+					Group::new(Delimiter::Parenthesis, call_parms.collect()).into(),
+				]),
+			)
+			.into(),
+		];
+
+		match (&ret_ty, catch) {
+			// `<js_sys>::r#macro::catch::<ty>(<call>)`
+			(Some((span, ty)), true) => {
+				call = path_with_js_sys(js_sys, ["r#macro", "catch"], *span)
+					.chain([
+						Punct::new(':', Spacing::Joint).into(),
+						Punct::new(':', Spacing::Alone).into(),
+						Punct::new('<', Spacing::Alone).into(),
+					])
+					.chain(ty.iter().cloned())
+					.chain([
+						Punct::new('>', Spacing::Alone).into(),
+						Group::new(Delimiter::Parenthesis, call.into_iter().collect()).into(),
+					])
+					.collect();
+			}
+			(Some((span, ty)), false) => {
+				call = js_sys_hazard(ty, js_sys, "Output", "from_raw", *span)
+					.chain(iter::once(
+						Group::new(Delimiter::Parenthesis, call.into_iter().collect()).into(),
+					))
+					.collect();
+			}
+			// `<call>; <js_sys>::r#macro::catch_unit()`
+			(None, true) => {
+				call.push(Punct::new(';', Spacing::Alone).into());
+				call.extend(path_with_js_sys(
+					js_sys,
+					["r#macro", "catch_unit"],
+					self.name.span(),
+				));
+				call.push(Group::new(Delimiter::Parenthesis, TokenStream::new()).into());
+			}
+			(None, false) => call.push(Punct::new(';', Spacing::Alone).into()),
+		}
+
+		// Other targets only panic, so they don't use any parameters.
+		// `<js_sys>::r#macro::unsupported("<name>")`
+		let stub = path_with_js_sys(js_sys, ["r#macro", "unsupported"], self.name.span())
+			.chain(iter::once(
+				Group::new(
+					Delimiter::Parenthesis,
+					iter::once(TokenTree::from(Literal::string(&self.name.to_string()))).collect(),
+				)
+				.into(),
+			))
+			.collect();
+		let stub_parms = self
+			.parms
+			.iter()
+			.flat_map(|p| {
+				let name = if p.name == "self" {
+					p.name.clone()
+				} else {
+					Ident::new("_", p.name.span())
+				};
+
+				[name.into(), p.colon.clone().into()]
+					.into_iter()
+					.chain(p.ty.iter().cloned())
+					.chain(p.comma.clone().map(TokenTree::from))
+			})
+			.collect();
+
+		let name_span = self.name.span();
+		let parms = self
+			.parms
+			.into_iter()
+			.flat_map(|p| {
+				[p.name.into(), p.colon.into()]
+					.into_iter()
+					.chain(p.ty)
+					.chain(p.comma.into_iter().map(TokenTree::from))
+			})
+			.collect();
+		let body = assembly
+			.chain(import_js)
+			.chain(import)
+			.chain(call)
+			.collect();
+		let ret_ty: Vec<_> = self
+			.ret_ty
+			.into_iter()
+			.flat_map(|(arrow, _, ty)| arrow.into_iter().chain(ty))
+			.collect();
+
+		// `#[cfg(<target>)] <vis> fn <name>(<parms>) -> <ret_ty> { <body> }`
+		let mut item = TokenStream::new();
+
+		for (target, parms, body) in [
+			(r#"#[cfg(target_family = "wasm")]"#, parms, body),
+			(r#"#[cfg(not(target_family = "wasm"))]"#, stub_parms, stub),
+		] {
+			// Members share the `cfg` on their `impl` block.
+			if impl_ty.is_none() {
+				item.extend(cfg.iter().flatten().cloned());
+			}
+
+			item.extend(TokenStream::from_str(target).unwrap());
+			item.extend(self.visibility.clone().map(TokenTree::from));
+			item.extend([
+				TokenTree::from(self.r#fn.clone()),
+				self.name.clone().into(),
+				Group::new(Delimiter::Parenthesis, parms).into(),
+			]);
+			item.extend(ret_ty.iter().cloned());
+			item.extend(iter::once(TokenTree::from(Group::new(
 				Delimiter::Brace,
-				TokenStream::from_iter([
-					TokenTree::from(Ident::new(&import_name, self.name.span())),
-					Group::new(Delimiter::Parenthesis, call_parms.collect()).into(),
-				]),
-			)
-			.into(),
-		];
+				body,
+			))));
+		}
 
-		if let Some((_, span, ty)) = &self.ret_ty {
-			call = js_sys_hazard(ty, js_sys, "Output", "from_raw", *span)
-				.chain(iter::once(
-					Group::new(Delimiter::Parenthesis, call.into_iter().collect()).into(),
-				))
-				.collect();
+		if let Some(impl_ty) = impl_ty {
+			output.extend(
+				cfg.into_iter()
+					.flatten()
+					.chain(iter::once(Ident::new("impl", name_span).into()))
+					.chain(impl_ty)
+					.chain(iter::once(Group::new(Delimiter::Brace, item).into())),
+			);
 		} else {
-			call.push(Punct::new(';', Spacing::Alone).into());
+			output.extend(item);
 		}
 
-		output.extend(cfg.into_iter().flatten());
-		output.extend(self.visibility.map(TokenTree::from));
-		output.extend([
-			TokenTree::from(self.r#fn),
-			self.name.into(),
-			Group::new(
-				Delimiter::Parenthesis,
-				self.parms
-					.into_iter()
-					.flat_map(|p| {
-						[p.name.into(), p.colon.into()]
-							.into_iter()
-							.chain(p.ty)
-							.chain(p.comma.into_iter().map(TokenTree::from))
-					})
-					.collect(),
-			)
-			.into(),
-		]);
-		output.extend(
-			self.ret_ty
-				.into_iter()
-				.flat_map(|(arrow, _, ty)| arrow.into_iter().chain(ty)),
-		);
-		output.extend(iter::once(TokenTree::from(Group::new(
-			Delimiter::Brace,
-			assembly
-				.chain(import_js)
-				.chain(import)
-				.chain(call)
-				.collect(),
-		))));
+		Ok(())
+	}
+
+	/// Returns the type members have to be implemented on.
+	fn impl_ty(
+		&self,
+		member: Option<&JsMember>,
+		js_function_attr: Option<&JsFunction>,
+		ret_ty: Option<&(SpanRange, Vec<TokenTree>)>,
+	) -> Result<Option<Vec<TokenTree>>, TokenStream> {
+		let Some(member) = member else {
+			return Ok(None);
+		};
+
+		if let Some(JsFunction::Embed(_) | JsFunction::Import) = js_function_attr {
+			return Err(compile_error(
+				self.name.span(),
+				"`js_embed` and `js_import` can't be used together with `method`, `getter`, \
+				 `setter`, `constructor` or `static_method_of`",
+			));
+		}
+
+		let receiver = || {
+			let error = || {
+				compile_error(
+					self.name.span(),
+					"expected `self: &<type>` as the first parameter",
+				)
+			};
+
+			let parm = self
+				.parms
+				.first()
+				.filter(|parm| parm.name_string == "self")
+				.ok_or_else(error)?;
+
+			match parm.ty.as_slice() {
+				[TokenTree::Punct(p), ty @ ..] if p.as_char() == '&' && !ty.is_empty() => {
+					Ok(ty.to_vec())
+				}
+				_ => Err(error()),
+			}
+		};
+
+		let ty = match member {
+			JsMember::Method => receiver()?,
+			JsMember::Getter(_) => {
+				if self.parms.len() != 1 || ret_ty.is_none() {
+					return Err(compile_error(
+						self.name.span(),
+						"`getter` expects only `self` and a return type",
+					));
+				}
+
+				receiver()?
+			}
+			JsMember::Setter(_) => {
+				if self.parms.len() != 2 || ret_ty.is_some() {
+					return Err(compile_error(
+						self.name.span(),
+						"`setter` expects `self`, a value and no return type",
+					));
+				}
+
+				receiver()?
+			}
+			JsMember::Constructor => ret_ty.map(|(_, ty)| ty.clone()).ok_or_else(|| {
+				compile_error(self.name.span(), "`constructor` requires a return type")
+			})?,
+			JsMember::StaticMethodOf(ty) => ty.clone(),
+		};
+
+		if let JsMember::Constructor | JsMember::StaticMethodOf(_) = member
+			&& self.parms.iter().any(|parm| parm.name_string == "self")
+		{
+			return Err(compile_error(
+				self.name.span(),
+				"`constructor` and `static_method_of` don't support `self`",
+			));
+		}
+
+		Ok(Some(ty))
 	}
 }
 
@@ -884,6 +1900,9 @@ struct ExternType {
 	visibility: Option<Ident>,
 	name: Ident,
 	generic_group: Option<GenericGroup>,
+	extends: Vec<Vec<TokenTree>>,
+	type_check: Option<TypeCheck>,
+	ts_type: Option<String>,
 }
 
 struct GenericGroup {
@@ -916,136 +1935,172 @@ impl ExternType {
 
 		let name = parse_ident(&mut stream, r#type.span(), "identifier after `type`")?;
 
+		let generic_group = if let Some(TokenTree::Punct(p)) = stream.peek()
+			&& p.as_char() == '<'
+		{
+			Some(Self::parse_generics(stream, &name)?)
+		} else {
+			None
+		};
+
+		let mut extends = Vec::new();
+
+		if let Some(TokenTree::Punct(p)) = stream.peek()
+			&& p.as_char() == ':'
+		{
+			let Some(TokenTree::Punct(colon)) = stream.next() else {
+				unreachable!()
+			};
+			let mut span = colon.span();
+
+			loop {
+				let mut parent = Vec::new();
+				parse_ty_or_value(stream, span, "parent type", &mut parent)?;
+				extends.push(parent);
+
+				match stream.peek() {
+					Some(TokenTree::Punct(p)) if p.as_char() == '+' => {
+						span = stream.next().unwrap().span();
+					}
+					_ => break,
+				}
+			}
+		}
+
 		match stream.next() {
 			Some(TokenTree::Punct(p)) if p.as_char() == ';' => Ok(Self {
 				visibility,
 				name,
-				generic_group: None,
+				generic_group,
+				extends,
+				type_check: None,
+				ts_type: None,
 			}),
-			Some(TokenTree::Punct(open)) if open.as_char() == '<' => {
-				let mut generics = Vec::new();
+			token => Err(compile_error(
+				token.map_or_else(|| name.span(), |token| token.span()),
+				"expected `;`",
+			)),
+		}
+	}
 
-				let generic_group = 'outer: loop {
-					let Some(token) = stream.next() else {
-						return Err(compile_error(
-							name.span(),
-							"expected generic identifier or `>`",
-						));
-					};
+	fn parse_generics(
+		stream: &mut Peekable<token_stream::IntoIter>,
+		name: &Ident,
+	) -> Result<GenericGroup, TokenStream> {
+		let Some(TokenTree::Punct(open)) = stream.next() else {
+			unreachable!()
+		};
+		let mut generics = Vec::new();
 
-					match token {
-						TokenTree::Punct(close) if close.as_char() == '>' => {
-							break GenericGroup {
-								open,
-								generics,
-								close,
-							};
-						}
-						_ => (),
-					}
+		Ok('outer: loop {
+			let Some(token) = stream.next() else {
+				return Err(compile_error(
+					name.span(),
+					"expected generic identifier or `>`",
+				));
+			};
 
-					let TokenTree::Ident(name) = token else {
-						return Err(compile_error(token.span(), "expected generic identifier"));
+			match token {
+				TokenTree::Punct(close) if close.as_char() == '>' => {
+					break GenericGroup {
+						open,
+						generics,
+						close,
 					};
+				}
+				_ => (),
+			}
 
-					let mut generic = Generic {
-						name,
-						traits: None,
-						default: None,
-						comma: None,
-					};
+			let TokenTree::Ident(name) = token else {
+				return Err(compile_error(token.span(), "expected generic identifier"));
+			};
 
-					'inner: while let Some(token) = stream.next() {
-						match token {
-							TokenTree::Punct(colon) if colon.as_char() == ':' => {
-								let mut traits = Vec::new();
-								parse_ty_or_value(
-									stream,
-									colon.span(),
-									"generic trait",
-									&mut traits,
-								)?;
-
-								while let Some(token) = stream.peek() {
-									match token {
-										TokenTree::Punct(close) if close.as_char() == '>' => {
-											generic.traits = Some((colon, traits));
-											break 'inner;
-										}
-										TokenTree::Punct(p) if p.as_char() == '+' => {
-											traits.push(stream.next().unwrap());
-										}
-										TokenTree::Punct(p) if p.as_char() == '=' => {
-											generic.traits = Some((colon, traits));
-											continue 'inner;
-										}
-										_ => {
-											parse_ty_or_value(
-												stream,
-												colon.span(),
-												"generic trait",
-												&mut traits,
-											)?;
-										}
-									}
+			let mut generic = Generic {
+				name,
+				traits: None,
+				default: None,
+				comma: None,
+			};
+
+			'inner: while let Some(token) = stream.next() {
+				match token {
+					TokenTree::Punct(colon) if colon.as_char() == ':' => {
+						let mut traits = Vec::new();
+						parse_ty_or_value(stream, colon.span(), "generic trait", &mut traits)?;
+
+						while let Some(token) = stream.peek() {
+							match token {
+								TokenTree::Punct(close) if close.as_char() == '>' => {
+									generic.traits = Some((colon, traits));
+									break 'inner;
 								}
-							}
-							TokenTree::Punct(equal) if equal.as_char() == '=' => {
-								let mut default = Vec::new();
-								parse_ty_or_value(
-									stream,
-									equal.span(),
-									"generic default type",
-									&mut default,
-								)?;
-								generic.default = Some((equal, default));
-
-								if let Some(TokenTree::Punct(p)) = stream.peek()
-									&& p.as_char() == ','
-								{
-									let Some(TokenTree::Punct(comma)) = stream.next() else {
-										unreachable!()
-									};
-									generic.comma = Some(comma);
+								TokenTree::Punct(p) if p.as_char() == '+' => {
+									traits.push(stream.next().unwrap());
+								}
+								TokenTree::Punct(p) if p.as_char() == '=' => {
+									generic.traits = Some((colon, traits));
+									continue 'inner;
+								}
+								_ => {
+									parse_ty_or_value(
+										stream,
+										colon.span(),
+										"generic trait",
+										&mut traits,
+									)?;
 								}
-
-								break;
-							}
-							TokenTree::Punct(p) if p.as_char() == ',' => break,
-							TokenTree::Punct(close) if close.as_char() == '>' => {
-								generics.push(generic);
-
-								break 'outer GenericGroup {
-									open,
-									generics,
-									close,
-								};
-							}
-							token => {
-								return Err(compile_error(token.span(), "expected `:` or `=`"));
 							}
 						}
 					}
+					TokenTree::Punct(equal) if equal.as_char() == '=' => {
+						let mut default = Vec::new();
+						parse_ty_or_value(
+							stream,
+							equal.span(),
+							"generic default type",
+							&mut default,
+						)?;
+						generic.default = Some((equal, default));
 
-					generics.push(generic);
-				};
-
-				expect_punct(stream, ';', name.span(), "`;`", false)?;
+						if let Some(TokenTree::Punct(p)) = stream.peek()
+							&& p.as_char() == ','
+						{
+							let Some(TokenTree::Punct(comma)) = stream.next() else {
+								unreachable!()
+							};
+							generic.comma = Some(comma);
+						}
 
-				Ok(Self {
-					visibility,
-					name,
-					generic_group: Some(generic_group),
-				})
+						break;
+					}
+					TokenTree::Punct(p) if p.as_char() == ',' => break,
+					TokenTree::Punct(close) if close.as_char() == '>' => {
+						generics.push(generic);
+
+						break 'outer GenericGroup {
+							open,
+							generics,
+							close,
+						};
+					}
+					token => {
+						return Err(compile_error(token.span(), "expected `:` or `=`"));
+					}
+				}
 			}
-			token => Err(compile_error(
-				token.map_or_else(|| name.span(), |token| token.span()),
-				"expected `;`",
-			)),
-		}
+
+			generics.push(generic);
+		})
 	}
 
-	fn emit(self, output: &mut TokenStream, js_sys: &[TokenTree], cfg: Option<[TokenTree; 2]>) {
+	fn emit(
+		self,
+		output: &mut TokenStream,
+		js_sys: &[TokenTree],
+		namespace: Option<&str>,
+		cfg: Option<[TokenTree; 2]>,
+	) -> Result<(), TokenStream> {
+		let type_check = self.type_check(js_sys, namespace)?;
 		let cfg: String = cfg.into_iter().flatten().map(|t| t.to_string()).collect();
 		let visibility = self.visibility.map(|i| i.to_string()).unwrap_or_default();
 		let name = self.name.to_string();
@@ -1108,17 +2163,62 @@ impl ExternType {
 			field_values = format!("({js_sys}::hazard::Output::from_raw(raw))");
 		}
 
+		let js_type = self.ts_type.as_deref().map_or_else(String::new, |ts_type| {
+			format!(
+				"const TS_TYPE: &::core::primitive::str = {};",
+				Literal::string(ts_type)
+			)
+		});
+
+		let target;
+		let deref;
+
+		if let Some(parent) = self.extends.first() {
+			target = parent.iter().map(TokenTree::to_string).collect();
+			deref = format!("{js_sys}::r#macro::cast_ref(self)");
+		} else {
+			target = format!("{js_sys}::JsValue");
+			deref = format!("&self.{value}");
+		}
+
+		let mut parents = String::new();
+
+		for parent in &self.extends {
+			let parent: String = parent.iter().map(TokenTree::to_string).collect();
+
+			write!(
+				parents,
+				r"{cfg}
+				impl{generics_with_traits} ::core::convert::AsRef<{parent}> for {name}{generics_names} {{
+					fn as_ref(&self) -> &{parent} {{
+						{js_sys}::r#macro::cast_ref(self)
+					}}
+				}}
+
+				{cfg}
+				impl{generics_with_traits} ::core::convert::From<{name}{generics_names}> for {parent} {{
+					fn from(value: {name}{generics_names}) -> Self {{
+						{js_sys}::r#macro::cast(value)
+					}}
+				}}"
+			)
+			.unwrap();
+		}
+
 		let output_str = format!(
 			r#"{cfg}
 			#[repr(transparent)]
 			{visibility} struct {name}{generics_all}{fields}
 
+			{cfg}
+			unsafe impl{generics_with_traits} {js_sys}::r#macro::JsType for {name}{generics_names} {{ {js_type} }}
+
 			{cfg}
 			impl{generics_with_traits} ::core::ops::Deref for {name}{generics_names} {{
-				type Target = {js_sys}::JsValue;
+				type Target = {target};
 
 				fn deref(&self) -> &Self::Target {{
-					&self.{value}
+					{deref}
 				}}
 			}}
 			
@@ -1148,10 +2248,93 @@ impl ExternType {
 				fn from_raw(raw: Self::Type) -> Self {{
 					Self{field_values}
 				}}
-			}}"#
+			}}
+
+			{cfg}
+			impl{generics_with_traits} ::core::convert::AsRef<{js_sys}::JsValue> for {name}{generics_names} {{
+				fn as_ref(&self) -> &{js_sys}::JsValue {{
+					&self.{value}
+				}}
+			}}
+
+			{cfg}
+			impl{generics_with_traits} ::core::convert::From<{name}{generics_names}> for {js_sys}::JsValue {{
+				fn from(value: {name}{generics_names}) -> Self {{
+					value.{value}
+				}}
+			}}
+
+			{cfg}
+			impl{generics_with_traits} {js_sys}::JsCast for {name}{generics_names} {{
+				fn is_type_of(value: &{js_sys}::JsValue) -> ::core::primitive::bool {{
+					{type_check}
+				}}
+			}}
+
+			{parents}"#
 		);
 
 		output.extend(TokenStream::from_str(&output_str).unwrap());
+
+		Ok(())
+	}
+
+	/// Returns the body of `JsCast::is_type_of()`.
+	fn type_check(
+		&self,
+		js_sys: &[TokenTree],
+		namespace: Option<&str>,
+	) -> Result<String, TokenStream> {
+		let js_sys_string: String = js_sys.iter().map(TokenTree::to_string).collect();
+		let class = |class: &str| {
+			if let Some(namespace) = namespace {
+				format!("{namespace}.{class}")
+			} else {
+				class.to_owned()
+			}
+		};
+
+		match &self.type_check {
+			None => Ok(format!(
+				"{js_sys_string}::r#macro::instanceof(value, {})",
+				Literal::string(&class(&self.name.to_string()))
+			)),
+			Some(TypeCheck::Instanceof(instanceof)) => Ok(format!(
+				"{js_sys_string}::r#macro::instanceof(value, {})",
+				Literal::string(&class(instanceof))
+			)),
+			Some(TypeCheck::IsTypeOf(is_type_of)) => {
+				// The predicate is embedded and called through a regular import, which is
+				// prefixed with the type to avoid collisions between types.
+				let prefix = class(&self.name.to_string());
+				let embed = format!("{prefix}.is_type_of");
+
+				let mut stream = TokenStream::from_str(&format!(
+					"fn is_type_of(value: & {js_sys_string}::JsValue) -> ::core::primitive::bool;"
+				))
+				.unwrap()
+				.into_iter()
+				.peekable();
+				let mut import = TokenStream::new();
+				ExternFn::parse(&mut stream)?.emit(
+					&mut import,
+					js_sys,
+					Some(&prefix),
+					None,
+					FnAttributes {
+						function: Some(JsFunction::Embed(embed.clone())),
+						member: None,
+						catch: false,
+					},
+				)?;
+
+				Ok(format!(
+					"{js_sys_string}::js_bindgen::embed_js!(name = {}, {});\n\n{import}\n\nis_type_of(value)",
+					Literal::string(&embed),
+					Literal::string(&escape_braces(is_type_of)),
+				))
+			}
+		}
 	}
 }
 
@@ -1186,6 +2369,99 @@ fn select<'a>(
 		])
 }
 
+/// Inserts the JS conversion of every parameter, if there is any.
+fn js_conv_parms<'a>(
+	js_sys: &'a [TokenTree],
+	parms: &'a [Parameter],
+) -> impl 'a + Iterator<Item = TokenTree> {
+	parms.iter().flat_map(|p| {
+		select(
+			js_sys,
+			"",
+			iter::once(Literal::string(&format!("\t{} = ", p.name_string)).into()),
+			js_select_parms(js_sys, iter::once(p)),
+			p.ty_span,
+		)
+		.chain(select(
+			js_sys,
+			"",
+			js_sys_hazard(&p.ty, js_sys, "Input", "JS_CONV", p.ty_span),
+			js_select_parms(js_sys, iter::once(p)),
+			p.ty_span,
+		))
+		.chain(select(
+			js_sys,
+			"",
+			iter::once(Literal::string(&format!("({})\n", p.name_string)).into()),
+			js_select_parms(js_sys, iter::once(p)),
+			p.ty_span,
+		))
+	})
+}
+
+/// Returns the JS conversion of the return value, followed by the parentheses
+/// around the call, which are only inserted if there is a conversion.
+fn js_conv_ret(
+	js_sys: &[TokenTree],
+	r#trait: &'static str,
+	ty: &[TokenTree],
+	span: SpanRange,
+) -> [Vec<TokenTree>; 3] {
+	let check_list = || js_select_ret(js_sys, r#trait, ty, span);
+
+	[
+		iter::once(Ident::new("interpolate", span.start).into())
+			.chain(js_sys_hazard(ty, js_sys, r#trait, "JS_CONV", span))
+			.chain(iter::once(Punct::new(',', Spacing::Alone).into()))
+			.collect(),
+		select(
+			js_sys,
+			"",
+			iter::once(Literal::string("(").into()),
+			check_list(),
+			span,
+		)
+		.collect(),
+		select(
+			js_sys,
+			"",
+			iter::once(Literal::string(")").into()),
+			check_list(),
+			span,
+		)
+		.collect(),
+	]
+}
+
+/// `interpolate <js_sys>::r#macro::catch_return(<ty as Output>::IMPORT_TYPE),`
+fn js_catch_return<'a>(
+	js_sys: &'a [TokenTree],
+	ty: &'a [TokenTree],
+	span: SpanRange,
+) -> impl 'a + Iterator<Item = TokenTree> {
+	iter::once(Ident::new("interpolate", span.start).into())
+		.chain(path_with_js_sys(js_sys, ["r#macro", "catch_return"], span))
+		.chain([
+			Group::new(
+				Delimiter::Parenthesis,
+				js_sys_hazard(ty, js_sys, "Output", "IMPORT_TYPE", span).collect(),
+			)
+			.into(),
+			Punct::new(',', Spacing::Alone).into(),
+		])
+}
+
+fn js_select_ret(
+	js_sys: &[TokenTree],
+	r#trait: &'static str,
+	ty: &[TokenTree],
+	span: SpanRange,
+) -> TokenStream {
+	js_sys_hazard(ty, js_sys, r#trait, "JS_CONV", span)
+		.chain(iter::once(Punct::new(',', Spacing::Alone).into()))
+		.collect()
+}
+
 fn js_select_parms<'a>(
 	js_sys: &'a [TokenTree],
 	parms: impl Iterator<Item = &'a Parameter>,
@@ -1198,6 +2474,64 @@ fn js_select_parms<'a>(
 		.collect()
 }
 
+/// Extracts `T` from `Result<T, E>`, returns [`None`] if `T` is `()`.
+fn parse_result_ok(
+	ty: &[TokenTree],
+	span: SpanRange,
+) -> Result<Option<(SpanRange, Vec<TokenTree>)>, TokenStream> {
+	let error = || {
+		compile_error(
+			span,
+			"expected `Result<T, JsValue>` return type for `catch`",
+		)
+	};
+
+	let Some(open) = ty
+		.iter()
+		.position(|tok| matches!(tok, TokenTree::Punct(p) if p.as_char() == '<'))
+	else {
+		return Err(error());
+	};
+
+	if !matches!(&ty[..open], [.., TokenTree::Ident(ident)] if ident == "Result")
+		|| !matches!(ty.last(), Some(TokenTree::Punct(p)) if p.as_char() == '>')
+	{
+		return Err(error());
+	}
+
+	let inner = &ty[open + 1..ty.len() - 1];
+	let mut depth = 0_usize;
+	let mut comma = None;
+
+	for (index, tok) in inner.iter().enumerate() {
+		if let TokenTree::Punct(p) = tok {
+			match p.as_char() {
+				'<' => depth += 1,
+				'>' => depth = depth.saturating_sub(1),
+				',' if depth == 0 => {
+					comma = Some(index);
+					break;
+				}
+				_ => (),
+			}
+		}
+	}
+
+	let ok = comma.map(|comma| &inner[..comma]).ok_or_else(error)?;
+
+	match ok {
+		[] => Err(error()),
+		[TokenTree::Group(group)]
+			if group.delimiter() == Delimiter::Parenthesis && group.stream().is_empty() =>
+		{
+			Ok(None)
+		}
+		[first, .., last] | [first @ last] => {
+			Ok(Some(((first.span(), last.span()).into(), ok.to_vec())))
+		}
+	}
+}
+
 fn js_sys_hazard<'a>(
 	ty: &'a [TokenTree],
 	js_sys: &'a [TokenTree],
@@ -1223,6 +2557,37 @@ fn path_with_js_sys<'js_sys>(
 	js_sys.iter().cloned().chain(path(parts, span))
 }
 
+/// Returns the name of a type without its path or generics.
+fn type_name(ty: &[TokenTree]) -> String {
+	ty.iter()
+		.take_while(|tok| !matches!(tok, TokenTree::Punct(p) if p.as_char() == '<'))
+		.filter_map(|tok| match tok {
+			TokenTree::Ident(ident) => Some(ident.to_string()),
+			_ => None,
+		})
+		.last()
+		.unwrap_or_default()
+}
+
+fn escape_braces(string: &str) -> String {
+	string.replace('{', "{{").replace('}', "}}")
+}
+
+/// Parses the optional `= "..."` of an attribute.
+fn parse_optional_value(
+	stream: &mut Peekable<token_stream::IntoIter>,
+	span: Span,
+) -> Result<Option<String>, TokenStream> {
+	match stream.peek() {
+		Some(TokenTree::Punct(p)) if p.as_char() == '=' => {
+			let punct = expect_punct(&mut *stream, '=', span, "`= \"...\"`", false)?;
+			let (_, string) = parse_string_literal(stream, punct.span(), "`\"...\"`", false)?;
+			Ok(Some(string))
+		}
+		_ => Ok(None),
+	}
+}
+
 fn parse_punct(
 	mut stream: impl Iterator<Item = TokenTree>,
 	previous_span: impl Into<SpanRange>,
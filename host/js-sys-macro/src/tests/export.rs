@@ -0,0 +1,200 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[track_caller]
+fn test(attr: TokenStream, input: TokenStream, expected: TokenStream) {
+	let output = crate::js_export_internal(attr, input).unwrap_or_else(|e| e);
+
+	let output = syn::parse2(output).unwrap();
+	let output = prettyplease::unparse(&output);
+	let expected = syn::parse2(expected).unwrap();
+	let expected = prettyplease::unparse(&expected);
+
+	similar_asserts::assert_eq!(expected, output);
+}
+
+#[test]
+fn basic() {
+	test(
+		TokenStream::new(),
+		quote! {
+			pub fn greet(name: &str, count: u32) -> JsString {
+				todo!()
+			}
+		},
+		quote! {
+			pub fn greet(name: &str, count: u32) -> JsString {
+				todo!()
+			}
+
+			const _: () = {
+				::js_sys::js_bindgen::export_js!(
+					name = "greet",
+					"(name, count) => {{\n{}{}{}{}{}{}\treturn {}{}this.#instance.exports['test_crate.greet'](name, count){}\n}}",
+					interpolate ::js_sys::r#macro::select("", "\tname = ", [<str as ::js_sys::hazard::ExportInput>::JS_CONV,]),
+					interpolate <str as ::js_sys::hazard::ExportInput>::JS_CONV,
+					interpolate ::js_sys::r#macro::select("", "(name)\n", [<str as ::js_sys::hazard::ExportInput>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "\tcount = ", [<u32 as ::js_sys::hazard::ExportInput>::JS_CONV,]),
+					interpolate <u32 as ::js_sys::hazard::ExportInput>::JS_CONV,
+					interpolate ::js_sys::r#macro::select("", "(count)\n", [<u32 as ::js_sys::hazard::ExportInput>::JS_CONV,]),
+					interpolate <JsString as ::js_sys::hazard::ExportOutput>::JS_CONV,
+					interpolate ::js_sys::r#macro::select("", "(", [<JsString as ::js_sys::hazard::ExportOutput>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", ")", [<JsString as ::js_sys::hazard::ExportOutput>::JS_CONV,]),
+				);
+
+				::js_sys::js_bindgen::type_ts!(
+					name = "greet",
+					"(name: {}, count: {}): {}",
+					interpolate <str as ::js_sys::hazard::ExportInput>::TS_TYPE,
+					interpolate <u32 as ::js_sys::hazard::ExportInput>::TS_TYPE,
+					interpolate <JsString as ::js_sys::hazard::ExportOutput>::TS_TYPE,
+				);
+
+				#[unsafe(export_name = "test_crate.greet")]
+				extern "C" fn __js_export_greet(
+					name: <str as ::js_sys::hazard::ExportInput>::Type,
+					count: <u32 as ::js_sys::hazard::ExportInput>::Type,
+				) -> <JsString as ::js_sys::hazard::ExportOutput>::Type {
+					let name = <str as ::js_sys::hazard::ExportInput>::from_raw(name);
+					let count = <u32 as ::js_sys::hazard::ExportInput>::from_raw(count);
+					<JsString as ::js_sys::hazard::ExportOutput>::into_raw(
+						greet(::core::borrow::Borrow::borrow(&name), count),
+					)
+				}
+			};
+		},
+	);
+}
+
+#[test]
+fn primitives() {
+	test(
+		TokenStream::new(),
+		quote! {
+			fn convert(a: i8, b: u64, c: bool, d: char) -> i64 {
+				todo!()
+			}
+		},
+		quote! {
+			fn convert(a: i8, b: u64, c: bool, d: char) -> i64 {
+				todo!()
+			}
+
+			const _: () = {
+				::js_sys::js_bindgen::export_js!(
+					name = "convert",
+					"(a, b, c, d) => {{\n{}{}{}{}{}{}{}{}{}{}{}{}\treturn {}{}this.#instance.exports['test_crate.convert'](a, b, c, d){}\n}}",
+					interpolate ::js_sys::r#macro::select("", "\ta = ", [<i8 as ::js_sys::hazard::ExportInput>::JS_CONV,]),
+					interpolate <i8 as ::js_sys::hazard::ExportInput>::JS_CONV,
+					interpolate ::js_sys::r#macro::select("", "(a)\n", [<i8 as ::js_sys::hazard::ExportInput>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "\tb = ", [<u64 as ::js_sys::hazard::ExportInput>::JS_CONV,]),
+					interpolate <u64 as ::js_sys::hazard::ExportInput>::JS_CONV,
+					interpolate ::js_sys::r#macro::select("", "(b)\n", [<u64 as ::js_sys::hazard::ExportInput>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "\tc = ", [<bool as ::js_sys::hazard::ExportInput>::JS_CONV,]),
+					interpolate <bool as ::js_sys::hazard::ExportInput>::JS_CONV,
+					interpolate ::js_sys::r#macro::select("", "(c)\n", [<bool as ::js_sys::hazard::ExportInput>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "\td = ", [<char as ::js_sys::hazard::ExportInput>::JS_CONV,]),
+					interpolate <char as ::js_sys::hazard::ExportInput>::JS_CONV,
+					interpolate ::js_sys::r#macro::select("", "(d)\n", [<char as ::js_sys::hazard::ExportInput>::JS_CONV,]),
+					interpolate <i64 as ::js_sys::hazard::ExportOutput>::JS_CONV,
+					interpolate ::js_sys::r#macro::select("", "(", [<i64 as ::js_sys::hazard::ExportOutput>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", ")", [<i64 as ::js_sys::hazard::ExportOutput>::JS_CONV,]),
+				);
+
+				::js_sys::js_bindgen::type_ts!(
+					name = "convert",
+					"(a: {}, b: {}, c: {}, d: {}): {}",
+					interpolate <i8 as ::js_sys::hazard::ExportInput>::TS_TYPE,
+					interpolate <u64 as ::js_sys::hazard::ExportInput>::TS_TYPE,
+					interpolate <bool as ::js_sys::hazard::ExportInput>::TS_TYPE,
+					interpolate <char as ::js_sys::hazard::ExportInput>::TS_TYPE,
+					interpolate <i64 as ::js_sys::hazard::ExportOutput>::TS_TYPE,
+				);
+
+				#[unsafe(export_name = "test_crate.convert")]
+				extern "C" fn __js_export_convert(
+					a: <i8 as ::js_sys::hazard::ExportInput>::Type,
+					b: <u64 as ::js_sys::hazard::ExportInput>::Type,
+					c: <bool as ::js_sys::hazard::ExportInput>::Type,
+					d: <char as ::js_sys::hazard::ExportInput>::Type,
+				) -> <i64 as ::js_sys::hazard::ExportOutput>::Type {
+					let a = <i8 as ::js_sys::hazard::ExportInput>::from_raw(a);
+					let b = <u64 as ::js_sys::hazard::ExportInput>::from_raw(b);
+					let c = <bool as ::js_sys::hazard::ExportInput>::from_raw(c);
+					let d = <char as ::js_sys::hazard::ExportInput>::from_raw(d);
+					<i64 as ::js_sys::hazard::ExportOutput>::into_raw(convert(a, b, c, d))
+				}
+			};
+		},
+	);
+}
+
+#[test]
+fn js_name() {
+	test(
+		quote! { js_sys = crate, js_name = "runTask" },
+		quote! {
+			fn run() {}
+		},
+		quote! {
+			fn run() {}
+
+			const _: () = {
+				crate::js_bindgen::export_js!(
+					name = "runTask",
+					"() => {{\n\tthis.#instance.exports['test_crate.runTask']()\n}}",
+				);
+
+				crate::js_bindgen::type_ts!(name = "runTask", "(): void",);
+
+				#[unsafe(export_name = "test_crate.runTask")]
+				extern "C" fn __js_export_run() {
+					run();
+				}
+			};
+		},
+	);
+}
+
+#[test]
+fn shadowing() {
+	test(
+		TokenStream::new(),
+		quote! {
+			fn export(r#type: &JsValue, export: u32) {}
+		},
+		quote! {
+			fn export(r#type: &JsValue, export: u32) {}
+
+			const _: () = {
+				::js_sys::js_bindgen::export_js!(
+					name = "export",
+					"(type, export) => {{\n{}{}{}{}{}{}\tthis.#instance.exports['test_crate.export'](type, export)\n}}",
+					interpolate ::js_sys::r#macro::select("", "\ttype = ", [<JsValue as ::js_sys::hazard::ExportInput>::JS_CONV,]),
+					interpolate <JsValue as ::js_sys::hazard::ExportInput>::JS_CONV,
+					interpolate ::js_sys::r#macro::select("", "(type)\n", [<JsValue as ::js_sys::hazard::ExportInput>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "\texport = ", [<u32 as ::js_sys::hazard::ExportInput>::JS_CONV,]),
+					interpolate <u32 as ::js_sys::hazard::ExportInput>::JS_CONV,
+					interpolate ::js_sys::r#macro::select("", "(export)\n", [<u32 as ::js_sys::hazard::ExportInput>::JS_CONV,]),
+				);
+
+				::js_sys::js_bindgen::type_ts!(
+					name = "export",
+					"(type: {}, export: {}): void",
+					interpolate <JsValue as ::js_sys::hazard::ExportInput>::TS_TYPE,
+					interpolate <u32 as ::js_sys::hazard::ExportInput>::TS_TYPE,
+				);
+
+				#[unsafe(export_name = "test_crate.export")]
+				extern "C" fn __js_export_export(
+					r#type: <JsValue as ::js_sys::hazard::ExportInput>::Type,
+					export: <u32 as ::js_sys::hazard::ExportInput>::Type,
+				) {
+					let r#type = <JsValue as ::js_sys::hazard::ExportInput>::from_raw(r#type);
+					let export = <u32 as ::js_sys::hazard::ExportInput>::from_raw(export);
+					export(::core::borrow::Borrow::borrow(&r#type), export);
+				}
+			};
+		},
+	);
+}
@@ -11,6 +11,7 @@ fn basic() {
 			}
 		},
 		quote! {
+			#[cfg(target_family = "wasm")]
 			pub fn log(data: &JsValue) {
 				::js_sys::js_bindgen::unsafe_embed_asm!(
 					".import_module test_crate.import.log, test_crate",
@@ -36,9 +37,9 @@ fn basic() {
 					name = "log",
 					"{}{}{}{}{}",
 					interpolate ::js_sys::r#macro::select("globalThis.log", "(data) => {\n", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
-					interpolate ::js_sys::r#macro::select("", "\tdata", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "\tdata = ", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
 					interpolate ::js_sys::r#macro::select("", <&JsValue as ::js_sys::hazard::Input>::JS_CONV, [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
-					interpolate ::js_sys::r#macro::select("", "\n", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "(data)\n", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
 					interpolate ::js_sys::r#macro::select("", "\tglobalThis.log(data)\n}", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
 				);
 
@@ -49,6 +50,11 @@ fn basic() {
 
 				unsafe { log(<&JsValue as ::js_sys::hazard::Input>::into_raw(data)) };
 			}
+
+			#[cfg(not(target_family = "wasm"))]
+			pub fn log(_: &JsValue) {
+				::js_sys::r#macro::unsupported("log")
+			}
 		},
 		indoc::indoc!(
 			".import_module test_crate.import.log, test_crate
@@ -79,6 +85,7 @@ fn namespace() {
 			}
 		},
 		quote! {
+			#[cfg(target_family = "wasm")]
 			pub fn log(data: &JsValue) {
 				::js_sys::js_bindgen::unsafe_embed_asm!(
 					".import_module test_crate.import.console.log, test_crate",
@@ -108,9 +115,9 @@ fn namespace() {
 						"(data) => {\n",
 						[<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]
 					),
-					interpolate ::js_sys::r#macro::select("", "\tdata", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "\tdata = ", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
 					interpolate ::js_sys::r#macro::select("", <&JsValue as ::js_sys::hazard::Input>::JS_CONV, [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
-					interpolate ::js_sys::r#macro::select("", "\n", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "(data)\n", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
 					interpolate ::js_sys::r#macro::select("", "\tglobalThis.console.log(data)\n}", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
 				);
 
@@ -121,6 +128,11 @@ fn namespace() {
 
 				unsafe { log(<&JsValue as ::js_sys::hazard::Input>::into_raw(data)) };
 			}
+
+			#[cfg(not(target_family = "wasm"))]
+			pub fn log(_: &JsValue) {
+				::js_sys::r#macro::unsupported("log")
+			}
 		},
 		indoc::indoc!(
 			".import_module test_crate.import.console.log, test_crate
@@ -151,6 +163,7 @@ fn js_sys() {
 			}
 		},
 		quote! {
+			#[cfg(target_family = "wasm")]
 			pub fn log(data: &JsValue) {
 				js_sys::js_bindgen::unsafe_embed_asm!(
 					".import_module test_crate.import.log, test_crate",
@@ -176,9 +189,9 @@ fn js_sys() {
 					name = "log",
 					"{}{}{}{}{}",
 					interpolate js_sys::r#macro::select("globalThis.log", "(data) => {\n", [<&JsValue as js_sys::hazard::Input>::JS_CONV,]),
-					interpolate js_sys::r#macro::select("", "\tdata", [<&JsValue as js_sys::hazard::Input>::JS_CONV,]),
+					interpolate js_sys::r#macro::select("", "\tdata = ", [<&JsValue as js_sys::hazard::Input>::JS_CONV,]),
 					interpolate js_sys::r#macro::select("", <&JsValue as js_sys::hazard::Input>::JS_CONV, [<&JsValue as js_sys::hazard::Input>::JS_CONV,]),
-					interpolate js_sys::r#macro::select("", "\n", [<&JsValue as js_sys::hazard::Input>::JS_CONV,]),
+					interpolate js_sys::r#macro::select("", "(data)\n", [<&JsValue as js_sys::hazard::Input>::JS_CONV,]),
 					interpolate js_sys::r#macro::select("", "\tglobalThis.log(data)\n}", [<&JsValue as js_sys::hazard::Input>::JS_CONV,]),
 				);
 
@@ -189,6 +202,11 @@ fn js_sys() {
 
 				unsafe { log(<&JsValue as js_sys::hazard::Input>::into_raw(data)) };
 			}
+
+			#[cfg(not(target_family = "wasm"))]
+			pub fn log(_: &JsValue) {
+				js_sys::r#macro::unsupported("log")
+			}
 		},
 		indoc::indoc!(
 			".import_module test_crate.import.log, test_crate
@@ -219,6 +237,7 @@ fn two_parameters() {
 			}
 		},
 		quote! {
+			#[cfg(target_family = "wasm")]
 			pub fn log(data1: &JsValue, data2: &JsValue) {
 				::js_sys::js_bindgen::unsafe_embed_asm!(
 					".import_module test_crate.import.log, test_crate",
@@ -256,12 +275,12 @@ fn two_parameters() {
 						"(data1, data2) => {\n",
 						[<&JsValue as ::js_sys::hazard::Input>::JS_CONV,<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]
 					),
-					interpolate ::js_sys::r#macro::select("", "\tdata1", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "\tdata1 = ", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
 					interpolate ::js_sys::r#macro::select("", <&JsValue as ::js_sys::hazard::Input>::JS_CONV, [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
-					interpolate ::js_sys::r#macro::select("", "\n", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
-					interpolate ::js_sys::r#macro::select("", "\tdata2", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "(data1)\n", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "\tdata2 = ", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
 					interpolate ::js_sys::r#macro::select("", <&JsValue as ::js_sys::hazard::Input>::JS_CONV, [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
-					interpolate ::js_sys::r#macro::select("", "\n", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "(data2)\n", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
 					interpolate ::js_sys::r#macro::select(
 						"",
 						"\tglobalThis.log(data1, data2)\n}",
@@ -282,6 +301,11 @@ fn two_parameters() {
 					<&JsValue as ::js_sys::hazard::Input>::into_raw(data2),
 				) };
 			}
+
+			#[cfg(not(target_family = "wasm"))]
+			pub fn log(_: &JsValue, _: &JsValue) {
+				::js_sys::r#macro::unsupported("log")
+			}
 		},
 		indoc::indoc!(
 			".import_module test_crate.import.log, test_crate
@@ -316,6 +340,7 @@ fn empty() {
 			}
 		},
 		quote! {
+			#[cfg(target_family = "wasm")]
 			pub fn log() {
 				::js_sys::js_bindgen::unsafe_embed_asm!(
 					".import_module test_crate.import.log, test_crate",
@@ -341,6 +366,11 @@ fn empty() {
 
 				unsafe { log() };
 			}
+
+			#[cfg(not(target_family = "wasm"))]
+			pub fn log() {
+				::js_sys::r#macro::unsupported("log")
+			}
 		},
 		indoc::indoc!(
 			".import_module test_crate.import.log, test_crate
@@ -368,6 +398,7 @@ fn js_name() {
 			}
 		},
 		quote! {
+			#[cfg(target_family = "wasm")]
 			pub fn logx(data: &JsValue) {
 				::js_sys::js_bindgen::unsafe_embed_asm!(
 					".import_module test_crate.import.logx, test_crate",
@@ -393,9 +424,9 @@ fn js_name() {
 					name = "logx",
 					"{}{}{}{}{}",
 					interpolate ::js_sys::r#macro::select("globalThis.log", "(data) => {\n", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
-					interpolate ::js_sys::r#macro::select("", "\tdata", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "\tdata = ", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
 					interpolate ::js_sys::r#macro::select("", <&JsValue as ::js_sys::hazard::Input>::JS_CONV, [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
-					interpolate ::js_sys::r#macro::select("", "\n", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "(data)\n", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
 					interpolate ::js_sys::r#macro::select("", "\tglobalThis.log(data)\n}", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
 				);
 
@@ -406,6 +437,11 @@ fn js_name() {
 
 				unsafe { logx(<&JsValue as ::js_sys::hazard::Input>::into_raw(data)) };
 			}
+
+			#[cfg(not(target_family = "wasm"))]
+			pub fn logx(_: &JsValue) {
+				::js_sys::r#macro::unsupported("logx")
+			}
 		},
 		indoc::indoc!(
 			".import_module test_crate.import.logx, test_crate
@@ -437,6 +473,7 @@ fn js_embed() {
 			}
 		},
 		quote! {
+			#[cfg(target_family = "wasm")]
 			pub fn log(data: &JsValue) {
 				::js_sys::js_bindgen::unsafe_embed_asm!(
 					".import_module test_crate.import.log, test_crate",
@@ -463,9 +500,9 @@ fn js_embed() {
 					required_embed = "custom",
 					"{}{}{}{}{}",
 					interpolate ::js_sys::r#macro::select("this.#jsEmbed.test_crate['custom']", "(data) => {\n", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
-					interpolate ::js_sys::r#macro::select("", "\tdata", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "\tdata = ", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
 					interpolate ::js_sys::r#macro::select("", <&JsValue as ::js_sys::hazard::Input>::JS_CONV, [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
-					interpolate ::js_sys::r#macro::select("", "\n", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "(data)\n", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
 					interpolate ::js_sys::r#macro::select("", "\tthis.#jsEmbed.test_crate['custom'](data)\n}", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
 				);
 
@@ -476,6 +513,11 @@ fn js_embed() {
 
 				unsafe { log(<&JsValue as ::js_sys::hazard::Input>::into_raw(data)) };
 			}
+
+			#[cfg(not(target_family = "wasm"))]
+			pub fn log(_: &JsValue) {
+				::js_sys::r#macro::unsupported("log")
+			}
 		},
 		indoc::indoc!(
 			".import_module test_crate.import.log, test_crate
@@ -507,6 +549,7 @@ fn js_import() {
 			}
 		},
 		quote! {
+			#[cfg(target_family = "wasm")]
 			pub fn log(data: &JsValue) {
 				::js_sys::js_bindgen::unsafe_embed_asm!(
 					".import_module test_crate.import.log, test_crate",
@@ -537,6 +580,11 @@ fn js_import() {
 
 				unsafe { log(<&JsValue as ::js_sys::hazard::Input>::into_raw(data)) };
 			}
+
+			#[cfg(not(target_family = "wasm"))]
+			pub fn log(_: &JsValue) {
+				::js_sys::r#macro::unsupported("log")
+			}
 		},
 		indoc::indoc!(
 			".import_module test_crate.import.log, test_crate
@@ -567,6 +615,7 @@ fn r#return() {
 			}
 		},
 		quote! {
+			#[cfg(target_family = "wasm")]
 			pub fn is_nan() -> JsValue {
 				::js_sys::js_bindgen::unsafe_embed_asm!(
 					".import_module test_crate.import.is_nan, test_crate",
@@ -589,7 +638,14 @@ fn r#return() {
 
 				::js_sys::js_bindgen::import_js!(
 					name = "is_nan",
-					"globalThis.is_nan"
+					"{}{}{}{}{}{}{}",
+					interpolate ::js_sys::r#macro::select("globalThis.is_nan", "() => {\n", [<JsValue as ::js_sys::hazard::Output>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "\treturn ", [<JsValue as ::js_sys::hazard::Output>::JS_CONV,]),
+					interpolate <JsValue as ::js_sys::hazard::Output>::JS_CONV,
+					interpolate ::js_sys::r#macro::select("", "(", [<JsValue as ::js_sys::hazard::Output>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "globalThis.is_nan()", [<JsValue as ::js_sys::hazard::Output>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", ")", [<JsValue as ::js_sys::hazard::Output>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "\n}", [<JsValue as ::js_sys::hazard::Output>::JS_CONV,]),
 				);
 
 				unsafe extern "C" {
@@ -599,6 +655,11 @@ fn r#return() {
 
 				<JsValue as ::js_sys::hazard::Output>::from_raw(unsafe { is_nan() })
 			}
+
+			#[cfg(not(target_family = "wasm"))]
+			pub fn is_nan() -> JsValue {
+				::js_sys::r#macro::unsupported("is_nan")
+			}
 		},
 		indoc::indoc!(
 			".import_module test_crate.import.is_nan, test_crate
@@ -618,6 +679,175 @@ fn r#return() {
 	);
 }
 
+#[test]
+fn catch() {
+	super::test(
+		TokenStream::new(),
+		quote! {
+			extern "C" {
+				#[js_sys(js_name = "JSON.parse", catch)]
+				pub fn parse(text: &JsValue) -> Result<JsValue, JsValue>;
+			}
+		},
+		quote! {
+			#[cfg(target_family = "wasm")]
+			pub fn parse(text: &JsValue) -> Result<JsValue, JsValue> {
+				::js_sys::js_bindgen::unsafe_embed_asm!(
+					".import_module test_crate.import.parse, test_crate",
+					".import_name test_crate.import.parse, parse",
+					".functype test_crate.import.parse ({},) -> ({})",
+					"",
+					"{}",
+					"",
+					"{}",
+					"",
+					".globl test_crate.parse",
+					"test_crate.parse:",
+					"\t.functype test_crate.parse ({},) -> ({})",
+					"\tlocal.get 0",
+					"\t{}",
+					"\tcall test_crate.import.parse",
+					"\t{}",
+					"\tend_function",
+					interpolate <&JsValue as ::js_sys::hazard::Input>::IMPORT_TYPE,
+					interpolate <JsValue as ::js_sys::hazard::Output>::IMPORT_TYPE,
+					interpolate <&JsValue as ::js_sys::hazard::Input>::IMPORT_FUNC,
+					interpolate <JsValue as ::js_sys::hazard::Output>::IMPORT_FUNC,
+					interpolate <&JsValue as ::js_sys::hazard::Input>::TYPE,
+					interpolate <JsValue as ::js_sys::hazard::Output>::TYPE,
+					interpolate <&JsValue as ::js_sys::hazard::Input>::CONV,
+					interpolate <JsValue as ::js_sys::hazard::Output>::CONV,
+				);
+
+				::js_sys::js_bindgen::import_js!(
+					name = "parse",
+					"(text) => {{\n{}{}{}\ttry {{\n\t\treturn {}{}globalThis.JSON.parse(text){}\n\t}} catch (error) {{\n\t\tconst exception = this.#jsEmbed.js_sys['exception']\n\t\texception.thrown = true\n\t\texception.error = error\n\t\treturn {}\n\t}}\n}}",
+					interpolate ::js_sys::r#macro::select("", "\ttext = ", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", <&JsValue as ::js_sys::hazard::Input>::JS_CONV, [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "(text)\n", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+					interpolate <JsValue as ::js_sys::hazard::Output>::JS_CONV,
+					interpolate ::js_sys::r#macro::select("", "(", [<JsValue as ::js_sys::hazard::Output>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", ")", [<JsValue as ::js_sys::hazard::Output>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::catch_return(<JsValue as ::js_sys::hazard::Output>::IMPORT_TYPE),
+				);
+
+				unsafe extern "C" {
+					#[link_name = "test_crate.parse"]
+					fn parse(text: <&JsValue as ::js_sys::hazard::Input>::Type) -> <JsValue as ::js_sys::hazard::Output>::Type;
+				}
+
+				::js_sys::r#macro::catch::<JsValue>(unsafe {
+					parse(<&JsValue as ::js_sys::hazard::Input>::into_raw(text))
+				})
+			}
+
+			#[cfg(not(target_family = "wasm"))]
+			pub fn parse(_: &JsValue) -> Result<JsValue, JsValue> {
+				::js_sys::r#macro::unsupported("parse")
+			}
+		},
+		indoc::indoc!(
+			".import_module test_crate.import.parse, test_crate
+			.import_name test_crate.import.parse, parse
+			.functype test_crate.import.parse (externref,) -> (externref)
+
+			.functype js_sys.externref.get (i32) -> (externref)
+
+			.functype js_sys.externref.insert (externref) -> (i32)
+
+			.globl test_crate.parse
+			test_crate.parse:
+				.functype test_crate.parse (i32,) -> (i32)
+				local.get 0
+				call js_sys.externref.get
+				call test_crate.import.parse
+				call js_sys.externref.insert
+				end_function"
+		),
+		indoc::indoc!(
+			"(text) => {
+				try {
+					return globalThis.JSON.parse(text)
+				} catch (error) {
+					const exception = this.#jsEmbed.js_sys['exception']
+					exception.thrown = true
+					exception.error = error
+					return undefined
+				}
+			}"
+		),
+	);
+}
+
+#[test]
+fn catch_unit() {
+	super::test(
+		TokenStream::new(),
+		quote! {
+			extern "C" {
+				#[js_sys(catch)]
+				pub fn run() -> Result<(), JsValue>;
+			}
+		},
+		quote! {
+			#[cfg(target_family = "wasm")]
+			pub fn run() -> Result<(), JsValue> {
+				::js_sys::js_bindgen::unsafe_embed_asm!(
+					".import_module test_crate.import.run, test_crate",
+					".import_name test_crate.import.run, run",
+					".functype test_crate.import.run () -> ()",
+					"",
+					".globl test_crate.run",
+					"test_crate.run:",
+					"\t.functype test_crate.run () -> ()",
+					"\tcall test_crate.import.run",
+					"\tend_function",
+				);
+
+				::js_sys::js_bindgen::import_js!(
+					name = "run",
+					"() => {{\n\ttry {{\n\t\tglobalThis.run()\n\t}} catch (error) {{\n\t\tconst exception = this.#jsEmbed.js_sys['exception']\n\t\texception.thrown = true\n\t\texception.error = error\n\t}}\n}}",
+				);
+
+				unsafe extern "C" {
+					#[link_name = "test_crate.run"]
+					fn run();
+				}
+
+				unsafe { run() };
+				::js_sys::r#macro::catch_unit()
+			}
+
+			#[cfg(not(target_family = "wasm"))]
+			pub fn run() -> Result<(), JsValue> {
+				::js_sys::r#macro::unsupported("run")
+			}
+		},
+		indoc::indoc!(
+			".import_module test_crate.import.run, test_crate
+			.import_name test_crate.import.run, run
+			.functype test_crate.import.run () -> ()
+
+			.globl test_crate.run
+			test_crate.run:
+				.functype test_crate.run () -> ()
+				call test_crate.import.run
+				end_function"
+		),
+		indoc::indoc!(
+			"() => {
+				try {
+					globalThis.run()
+				} catch (error) {
+					const exception = this.#jsEmbed.js_sys['exception']
+					exception.thrown = true
+					exception.error = error
+				}
+			}"
+		),
+	);
+}
+
 #[test]
 fn pointer() {
 	super::test(
@@ -628,6 +858,7 @@ fn pointer() {
 			}
 		},
 		quote! {
+			#[cfg(target_family = "wasm")]
 			fn array(ptr: *const u8) -> JsString {
 				::js_sys::js_bindgen::unsafe_embed_asm!(
 					".import_module test_crate.import.array, test_crate",
@@ -657,16 +888,21 @@ fn pointer() {
 
 				::js_sys::js_bindgen::import_js!(
 					name = "array",
-					"{}{}{}{}{}",
-					interpolate ::js_sys::r#macro::select("globalThis.array", "(ptr) => {\n", [<*const u8 as ::js_sys::hazard::Input>::JS_CONV,]),
-					interpolate ::js_sys::r#macro::select("", "\tptr", [<*const u8 as ::js_sys::hazard::Input> ::JS_CONV,]),
+					"{}{}{}{}{}{}{}{}{}{}",
+					interpolate ::js_sys::r#macro::select("globalThis.array", "(ptr) => {\n", [<*const u8 as ::js_sys::hazard::Input>::JS_CONV, <JsString as ::js_sys::hazard::Output>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "\tptr = ", [<*const u8 as ::js_sys::hazard::Input> ::JS_CONV,]),
 					interpolate ::js_sys::r#macro::select(
 						"",
 						<*const u8 as ::js_sys::hazard::Input>::JS_CONV,
 						[<*const u8 as ::js_sys::hazard::Input>::JS_CONV,]
 					),
-					interpolate ::js_sys::r#macro::select("", "\n", [<*const u8 as ::js_sys::hazard::Input> ::JS_CONV,]),
-					interpolate ::js_sys::r#macro::select("", "\treturn globalThis.array(ptr)\n}", [<*const u8 as ::js_sys::hazard::Input> ::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "(ptr)\n", [<*const u8 as ::js_sys::hazard::Input> ::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "\treturn ", [<*const u8 as ::js_sys::hazard::Input> ::JS_CONV, <JsString as ::js_sys::hazard::Output>::JS_CONV,]),
+					interpolate <JsString as ::js_sys::hazard::Output>::JS_CONV,
+					interpolate ::js_sys::r#macro::select("", "(", [<JsString as ::js_sys::hazard::Output>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "globalThis.array(ptr)", [<*const u8 as ::js_sys::hazard::Input> ::JS_CONV, <JsString as ::js_sys::hazard::Output>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", ")", [<JsString as ::js_sys::hazard::Output>::JS_CONV,]),
+					interpolate ::js_sys::r#macro::select("", "\n}", [<*const u8 as ::js_sys::hazard::Input> ::JS_CONV, <JsString as ::js_sys::hazard::Output>::JS_CONV,]),
 				);
 
 				unsafe extern "C" {
@@ -681,6 +917,11 @@ fn pointer() {
 					array(<*const u8 as ::js_sys::hazard::Input>::into_raw(ptr))
 				})
 			}
+
+			#[cfg(not(target_family = "wasm"))]
+			fn array(_: *const u8) -> JsString {
+				::js_sys::r#macro::unsupported("array")
+			}
 		},
 		indoc::indoc!(
 			".import_module test_crate.import.array, test_crate
@@ -702,7 +943,7 @@ fn pointer() {
 		),
 		indoc::indoc!(
 			"(ptr) => {
-				ptr >>>= 0
+				ptr = ((value) => value >>> 0)(ptr)
 				return globalThis.array(ptr)
 			}"
 		),
@@ -721,6 +962,7 @@ fn cfg() {
 		},
 		quote! {
 			#[cfg(all())]
+			#[cfg(target_family = "wasm")]
 			pub fn log() {
 				::js_sys::js_bindgen::unsafe_embed_asm!(
 					".import_module test_crate.import.log, test_crate",
@@ -746,6 +988,12 @@ fn cfg() {
 
 				unsafe { log() };
 			}
+
+			#[cfg(all())]
+			#[cfg(not(target_family = "wasm"))]
+			pub fn log() {
+				::js_sys::r#macro::unsupported("log")
+			}
 		},
 		indoc::indoc!(
 			".import_module test_crate.import.log, test_crate
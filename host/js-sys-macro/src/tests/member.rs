@@ -0,0 +1,555 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Expected output of `pub type Array;`.
+fn array() -> TokenStream {
+	quote! {
+		#[repr(transparent)]
+		pub struct Array(::js_sys::JsValue);
+
+		unsafe impl ::js_sys::r#macro::JsType for Array {}
+
+		impl ::core::ops::Deref for Array {
+			type Target = ::js_sys::JsValue;
+
+			fn deref(&self) -> &Self::Target {
+				&self.0
+			}
+		}
+
+		unsafe impl ::js_sys::hazard::Input for &Array {
+			const IMPORT_FUNC: &'static ::core::primitive::str = ".functype js_sys.externref.get (i32) -> (externref)";
+			const IMPORT_TYPE: &'static ::core::primitive::str = "externref";
+			const TYPE: &'static ::core::primitive::str = "i32";
+			const CONV: &'static ::core::primitive::str = "call js_sys.externref.get";
+
+			type Type = ::core::primitive::i32;
+
+			fn into_raw(self) -> Self::Type {
+				::js_sys::hazard::Input::into_raw(&self.0)
+			}
+		}
+
+		unsafe impl ::js_sys::hazard::Output for Array {
+			const IMPORT_FUNC: &::core::primitive::str = ".functype js_sys.externref.insert (externref) -> (i32)";
+			const IMPORT_TYPE: &::core::primitive::str = "externref";
+			const TYPE: &::core::primitive::str = "i32";
+			const CONV: &::core::primitive::str = "call js_sys.externref.insert";
+
+			type Type = ::core::primitive::i32;
+
+			fn from_raw(raw: Self::Type) -> Self {
+				Self(::js_sys::hazard::Output::from_raw(raw))
+			}
+		}
+
+		impl ::core::convert::AsRef<::js_sys::JsValue> for Array {
+			fn as_ref(&self) -> &::js_sys::JsValue {
+				&self.0
+			}
+		}
+
+		impl ::core::convert::From<Array> for ::js_sys::JsValue {
+			fn from(value: Array) -> Self {
+				value.0
+			}
+		}
+
+		impl ::js_sys::JsCast for Array {
+			fn is_type_of(value: &::js_sys::JsValue) -> ::core::primitive::bool {
+				::js_sys::r#macro::instanceof(value, "Array")
+			}
+		}
+	}
+}
+
+#[test]
+fn method() {
+	let array = array();
+
+	super::test(
+		TokenStream::new(),
+		quote! {
+			extern "C" {
+				pub type Array;
+
+				#[js_sys(method)]
+				pub fn push(self: &Array, value: &JsValue);
+			}
+		},
+		quote! {
+			#array
+
+			impl Array {
+				#[cfg(target_family = "wasm")]
+				pub fn push(self: &Array, value: &JsValue) {
+					::js_sys::js_bindgen::unsafe_embed_asm!(
+						".import_module test_crate.import.Array.push, test_crate",
+						".import_name test_crate.import.Array.push, Array.push",
+						".functype test_crate.import.Array.push ({},{},) -> ()",
+						"",
+						"{}",
+						"",
+						"{}",
+						"",
+						".globl test_crate.Array.push",
+						"test_crate.Array.push:",
+						"\t.functype test_crate.Array.push ({},{},) -> ()",
+						"\tlocal.get 0",
+						"\t{}",
+						"\tlocal.get 1",
+						"\t{}",
+						"\tcall test_crate.import.Array.push",
+						"\tend_function",
+						interpolate <&Array as ::js_sys::hazard::Input>::IMPORT_TYPE,
+						interpolate <&JsValue as ::js_sys::hazard::Input>::IMPORT_TYPE,
+						interpolate <&Array as ::js_sys::hazard::Input>::IMPORT_FUNC,
+						interpolate <&JsValue as ::js_sys::hazard::Input>::IMPORT_FUNC,
+						interpolate <&Array as ::js_sys::hazard::Input>::TYPE,
+						interpolate <&JsValue as ::js_sys::hazard::Input>::TYPE,
+						interpolate <&Array as ::js_sys::hazard::Input>::CONV,
+						interpolate <&JsValue as ::js_sys::hazard::Input>::CONV,
+					);
+
+					::js_sys::js_bindgen::import_js!(
+						name = "Array.push",
+						"(self, value) => {{\n{}{}{}{}{}{}\tself.push(value)\n}}",
+						interpolate ::js_sys::r#macro::select("", "\tself = ", [<&Array as ::js_sys::hazard::Input>::JS_CONV,]),
+						interpolate ::js_sys::r#macro::select("", <&Array as ::js_sys::hazard::Input>::JS_CONV, [<&Array as ::js_sys::hazard::Input>::JS_CONV,]),
+						interpolate ::js_sys::r#macro::select("", "(self)\n", [<&Array as ::js_sys::hazard::Input>::JS_CONV,]),
+						interpolate ::js_sys::r#macro::select("", "\tvalue = ", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+						interpolate ::js_sys::r#macro::select("", <&JsValue as ::js_sys::hazard::Input>::JS_CONV, [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+						interpolate ::js_sys::r#macro::select("", "(value)\n", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+					);
+
+					unsafe extern "C" {
+						#[link_name = "test_crate.Array.push"]
+						fn push(
+							this: <&Array as ::js_sys::hazard::Input>::Type,
+							value: <&JsValue as ::js_sys::hazard::Input>::Type
+						);
+					}
+
+					unsafe { push(<&Array as ::js_sys::hazard::Input>::into_raw(self), <&JsValue as ::js_sys::hazard::Input>::into_raw(value)) };
+				}
+
+				#[cfg(not(target_family = "wasm"))]
+				pub fn push(self: &Array, _: &JsValue) {
+					::js_sys::r#macro::unsupported("push")
+				}
+			}
+		},
+		indoc::indoc!(
+			".import_module test_crate.import.Array.push, test_crate
+			.import_name test_crate.import.Array.push, Array.push
+			.functype test_crate.import.Array.push (externref,externref,) -> ()
+
+			.functype js_sys.externref.get (i32) -> (externref)
+
+			.functype js_sys.externref.get (i32) -> (externref)
+
+			.globl test_crate.Array.push
+			test_crate.Array.push:
+				.functype test_crate.Array.push (i32,i32,) -> ()
+				local.get 0
+				call js_sys.externref.get
+				local.get 1
+				call js_sys.externref.get
+				call test_crate.import.Array.push
+				end_function"
+		),
+		indoc::indoc!(
+			"(self, value) => {
+				self.push(value)
+			}"
+		),
+	);
+}
+
+#[test]
+fn getter() {
+	let array = array();
+
+	super::test(
+		TokenStream::new(),
+		quote! {
+			extern "C" {
+				pub type Array;
+
+				#[js_sys(getter = "length")]
+				pub fn len(self: &Array) -> JsValue;
+			}
+		},
+		quote! {
+			#array
+
+			impl Array {
+				#[cfg(target_family = "wasm")]
+				pub fn len(self: &Array) -> JsValue {
+					::js_sys::js_bindgen::unsafe_embed_asm!(
+						".import_module test_crate.import.Array.len, test_crate",
+						".import_name test_crate.import.Array.len, Array.len",
+						".functype test_crate.import.Array.len ({},) -> ({})",
+						"",
+						"{}",
+						"",
+						"{}",
+						"",
+						".globl test_crate.Array.len",
+						"test_crate.Array.len:",
+						"\t.functype test_crate.Array.len ({},) -> ({})",
+						"\tlocal.get 0",
+						"\t{}",
+						"\tcall test_crate.import.Array.len",
+						"\t{}",
+						"\tend_function",
+						interpolate <&Array as ::js_sys::hazard::Input>::IMPORT_TYPE,
+						interpolate <JsValue as ::js_sys::hazard::Output>::IMPORT_TYPE,
+						interpolate <&Array as ::js_sys::hazard::Input>::IMPORT_FUNC,
+						interpolate <JsValue as ::js_sys::hazard::Output>::IMPORT_FUNC,
+						interpolate <&Array as ::js_sys::hazard::Input>::TYPE,
+						interpolate <JsValue as ::js_sys::hazard::Output>::TYPE,
+						interpolate <&Array as ::js_sys::hazard::Input>::CONV,
+						interpolate <JsValue as ::js_sys::hazard::Output>::CONV,
+					);
+
+					::js_sys::js_bindgen::import_js!(
+						name = "Array.len",
+						"(self) => {{\n{}{}{}\treturn {}{}self.length{}\n}}",
+						interpolate ::js_sys::r#macro::select("", "\tself = ", [<&Array as ::js_sys::hazard::Input>::JS_CONV,]),
+						interpolate ::js_sys::r#macro::select("", <&Array as ::js_sys::hazard::Input>::JS_CONV, [<&Array as ::js_sys::hazard::Input>::JS_CONV,]),
+						interpolate ::js_sys::r#macro::select("", "(self)\n", [<&Array as ::js_sys::hazard::Input>::JS_CONV,]),
+						interpolate <JsValue as ::js_sys::hazard::Output>::JS_CONV,
+						interpolate ::js_sys::r#macro::select("", "(", [<JsValue as ::js_sys::hazard::Output>::JS_CONV,]),
+						interpolate ::js_sys::r#macro::select("", ")", [<JsValue as ::js_sys::hazard::Output>::JS_CONV,]),
+					);
+
+					unsafe extern "C" {
+						#[link_name = "test_crate.Array.len"]
+						fn len(
+							this: <&Array as ::js_sys::hazard::Input>::Type
+						) -> <JsValue as ::js_sys::hazard::Output>::Type;
+					}
+
+					<JsValue as ::js_sys::hazard::Output>::from_raw(unsafe { len(<&Array as ::js_sys::hazard::Input>::into_raw(self)) })
+				}
+
+				#[cfg(not(target_family = "wasm"))]
+				pub fn len(self: &Array) -> JsValue {
+					::js_sys::r#macro::unsupported("len")
+				}
+			}
+		},
+		indoc::indoc!(
+			".import_module test_crate.import.Array.len, test_crate
+			.import_name test_crate.import.Array.len, Array.len
+			.functype test_crate.import.Array.len (externref,) -> (externref)
+
+			.functype js_sys.externref.get (i32) -> (externref)
+
+			.functype js_sys.externref.insert (externref) -> (i32)
+
+			.globl test_crate.Array.len
+			test_crate.Array.len:
+				.functype test_crate.Array.len (i32,) -> (i32)
+				local.get 0
+				call js_sys.externref.get
+				call test_crate.import.Array.len
+				call js_sys.externref.insert
+				end_function"
+		),
+		indoc::indoc!(
+			"(self) => {
+				return self.length
+			}"
+		),
+	);
+}
+
+#[test]
+fn setter() {
+	let array = array();
+
+	super::test(
+		TokenStream::new(),
+		quote! {
+			extern "C" {
+				pub type Array;
+
+				#[js_sys(setter)]
+				pub fn set_length(self: &Array, value: &JsValue);
+			}
+		},
+		quote! {
+			#array
+
+			impl Array {
+				#[cfg(target_family = "wasm")]
+				pub fn set_length(self: &Array, value: &JsValue) {
+					::js_sys::js_bindgen::unsafe_embed_asm!(
+						".import_module test_crate.import.Array.set_length, test_crate",
+						".import_name test_crate.import.Array.set_length, Array.set_length",
+						".functype test_crate.import.Array.set_length ({},{},) -> ()",
+						"",
+						"{}",
+						"",
+						"{}",
+						"",
+						".globl test_crate.Array.set_length",
+						"test_crate.Array.set_length:",
+						"\t.functype test_crate.Array.set_length ({},{},) -> ()",
+						"\tlocal.get 0",
+						"\t{}",
+						"\tlocal.get 1",
+						"\t{}",
+						"\tcall test_crate.import.Array.set_length",
+						"\tend_function",
+						interpolate <&Array as ::js_sys::hazard::Input>::IMPORT_TYPE,
+						interpolate <&JsValue as ::js_sys::hazard::Input>::IMPORT_TYPE,
+						interpolate <&Array as ::js_sys::hazard::Input>::IMPORT_FUNC,
+						interpolate <&JsValue as ::js_sys::hazard::Input>::IMPORT_FUNC,
+						interpolate <&Array as ::js_sys::hazard::Input>::TYPE,
+						interpolate <&JsValue as ::js_sys::hazard::Input>::TYPE,
+						interpolate <&Array as ::js_sys::hazard::Input>::CONV,
+						interpolate <&JsValue as ::js_sys::hazard::Input>::CONV,
+					);
+
+					::js_sys::js_bindgen::import_js!(
+						name = "Array.set_length",
+						"(self, value) => {{\n{}{}{}{}{}{}\tself.length = value\n}}",
+						interpolate ::js_sys::r#macro::select("", "\tself = ", [<&Array as ::js_sys::hazard::Input>::JS_CONV,]),
+						interpolate ::js_sys::r#macro::select("", <&Array as ::js_sys::hazard::Input>::JS_CONV, [<&Array as ::js_sys::hazard::Input>::JS_CONV,]),
+						interpolate ::js_sys::r#macro::select("", "(self)\n", [<&Array as ::js_sys::hazard::Input>::JS_CONV,]),
+						interpolate ::js_sys::r#macro::select("", "\tvalue = ", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+						interpolate ::js_sys::r#macro::select("", <&JsValue as ::js_sys::hazard::Input>::JS_CONV, [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+						interpolate ::js_sys::r#macro::select("", "(value)\n", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+					);
+
+					unsafe extern "C" {
+						#[link_name = "test_crate.Array.set_length"]
+						fn set_length(
+							this: <&Array as ::js_sys::hazard::Input>::Type,
+							value: <&JsValue as ::js_sys::hazard::Input>::Type
+						);
+					}
+
+					unsafe { set_length(<&Array as ::js_sys::hazard::Input>::into_raw(self), <&JsValue as ::js_sys::hazard::Input>::into_raw(value)) };
+				}
+
+				#[cfg(not(target_family = "wasm"))]
+				pub fn set_length(self: &Array, _: &JsValue) {
+					::js_sys::r#macro::unsupported("set_length")
+				}
+			}
+		},
+		indoc::indoc!(
+			".import_module test_crate.import.Array.set_length, test_crate
+			.import_name test_crate.import.Array.set_length, Array.set_length
+			.functype test_crate.import.Array.set_length (externref,externref,) -> ()
+
+			.functype js_sys.externref.get (i32) -> (externref)
+
+			.functype js_sys.externref.get (i32) -> (externref)
+
+			.globl test_crate.Array.set_length
+			test_crate.Array.set_length:
+				.functype test_crate.Array.set_length (i32,i32,) -> ()
+				local.get 0
+				call js_sys.externref.get
+				local.get 1
+				call js_sys.externref.get
+				call test_crate.import.Array.set_length
+				end_function"
+		),
+		indoc::indoc!(
+			"(self, value) => {
+				self.length = value
+			}"
+		),
+	);
+}
+
+#[test]
+fn constructor() {
+	let array = array();
+
+	super::test(
+		TokenStream::new(),
+		quote! {
+			extern "C" {
+				pub type Array;
+
+				#[js_sys(constructor)]
+				pub fn new() -> Array;
+			}
+		},
+		quote! {
+			#array
+
+			impl Array {
+				#[cfg(target_family = "wasm")]
+				pub fn new() -> Array {
+					::js_sys::js_bindgen::unsafe_embed_asm!(
+						".import_module test_crate.import.Array.new, test_crate",
+						".import_name test_crate.import.Array.new, Array.new",
+						".functype test_crate.import.Array.new () -> ({})",
+						"",
+						"{}",
+						"",
+						".globl test_crate.Array.new",
+						"test_crate.Array.new:",
+						"\t.functype test_crate.Array.new () -> ({})",
+						"\tcall test_crate.import.Array.new",
+						"\t{}",
+						"\tend_function",
+						interpolate <Array as ::js_sys::hazard::Output>::IMPORT_TYPE,
+						interpolate <Array as ::js_sys::hazard::Output>::IMPORT_FUNC,
+						interpolate <Array as ::js_sys::hazard::Output>::TYPE,
+						interpolate <Array as ::js_sys::hazard::Output>::CONV,
+					);
+
+					::js_sys::js_bindgen::import_js!(
+						name = "Array.new",
+						"() => {{\n\treturn {}{}new globalThis.Array(){}\n}}",
+						interpolate <Array as ::js_sys::hazard::Output>::JS_CONV,
+						interpolate ::js_sys::r#macro::select("", "(", [<Array as ::js_sys::hazard::Output>::JS_CONV,]),
+						interpolate ::js_sys::r#macro::select("", ")", [<Array as ::js_sys::hazard::Output>::JS_CONV,]),
+					);
+
+					unsafe extern "C" {
+						#[link_name = "test_crate.Array.new"]
+						fn new(
+
+						) -> <Array as ::js_sys::hazard::Output>::Type;
+					}
+
+					<Array as ::js_sys::hazard::Output>::from_raw(unsafe { new() })
+				}
+
+				#[cfg(not(target_family = "wasm"))]
+				pub fn new() -> Array {
+					::js_sys::r#macro::unsupported("new")
+				}
+			}
+		},
+		indoc::indoc!(
+			".import_module test_crate.import.Array.new, test_crate
+			.import_name test_crate.import.Array.new, Array.new
+			.functype test_crate.import.Array.new () -> (externref)
+
+			.functype js_sys.externref.insert (externref) -> (i32)
+
+			.globl test_crate.Array.new
+			test_crate.Array.new:
+				.functype test_crate.Array.new () -> (i32)
+				call test_crate.import.Array.new
+				call js_sys.externref.insert
+				end_function"
+		),
+		indoc::indoc!(
+			"() => {
+				return new globalThis.Array()
+			}"
+		),
+	);
+}
+
+#[test]
+fn static_method_of() {
+	let array = array();
+
+	super::test(
+		TokenStream::new(),
+		quote! {
+			extern "C" {
+				pub type Array;
+
+				#[js_sys(static_method_of = Array, js_name = "isArray")]
+				pub fn is_array(value: &JsValue) -> JsValue;
+			}
+		},
+		quote! {
+			#array
+
+			impl Array {
+				#[cfg(target_family = "wasm")]
+				pub fn is_array(value: &JsValue) -> JsValue {
+					::js_sys::js_bindgen::unsafe_embed_asm!(
+						".import_module test_crate.import.Array.is_array, test_crate",
+						".import_name test_crate.import.Array.is_array, Array.is_array",
+						".functype test_crate.import.Array.is_array ({},) -> ({})",
+						"",
+						"{}",
+						"",
+						"{}",
+						"",
+						".globl test_crate.Array.is_array",
+						"test_crate.Array.is_array:",
+						"\t.functype test_crate.Array.is_array ({},) -> ({})",
+						"\tlocal.get 0",
+						"\t{}",
+						"\tcall test_crate.import.Array.is_array",
+						"\t{}",
+						"\tend_function",
+						interpolate <&JsValue as ::js_sys::hazard::Input>::IMPORT_TYPE,
+						interpolate <JsValue as ::js_sys::hazard::Output>::IMPORT_TYPE,
+						interpolate <&JsValue as ::js_sys::hazard::Input>::IMPORT_FUNC,
+						interpolate <JsValue as ::js_sys::hazard::Output>::IMPORT_FUNC,
+						interpolate <&JsValue as ::js_sys::hazard::Input>::TYPE,
+						interpolate <JsValue as ::js_sys::hazard::Output>::TYPE,
+						interpolate <&JsValue as ::js_sys::hazard::Input>::CONV,
+						interpolate <JsValue as ::js_sys::hazard::Output>::CONV,
+					);
+
+					::js_sys::js_bindgen::import_js!(
+						name = "Array.is_array",
+						"(value) => {{\n{}{}{}\treturn {}{}globalThis.Array.isArray(value){}\n}}",
+						interpolate ::js_sys::r#macro::select("", "\tvalue = ", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+						interpolate ::js_sys::r#macro::select("", <&JsValue as ::js_sys::hazard::Input>::JS_CONV, [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+						interpolate ::js_sys::r#macro::select("", "(value)\n", [<&JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+						interpolate <JsValue as ::js_sys::hazard::Output>::JS_CONV,
+						interpolate ::js_sys::r#macro::select("", "(", [<JsValue as ::js_sys::hazard::Output>::JS_CONV,]),
+						interpolate ::js_sys::r#macro::select("", ")", [<JsValue as ::js_sys::hazard::Output>::JS_CONV,]),
+					);
+
+					unsafe extern "C" {
+						#[link_name = "test_crate.Array.is_array"]
+						fn is_array(
+							value: <&JsValue as ::js_sys::hazard::Input>::Type
+						) -> <JsValue as ::js_sys::hazard::Output>::Type;
+					}
+
+					<JsValue as ::js_sys::hazard::Output>::from_raw(unsafe { is_array(<&JsValue as ::js_sys::hazard::Input>::into_raw(value)) })
+				}
+
+				#[cfg(not(target_family = "wasm"))]
+				pub fn is_array(_: &JsValue) -> JsValue {
+					::js_sys::r#macro::unsupported("is_array")
+				}
+			}
+		},
+		indoc::indoc!(
+			".import_module test_crate.import.Array.is_array, test_crate
+			.import_name test_crate.import.Array.is_array, Array.is_array
+			.functype test_crate.import.Array.is_array (externref,) -> (externref)
+
+			.functype js_sys.externref.get (i32) -> (externref)
+
+			.functype js_sys.externref.insert (externref) -> (i32)
+
+			.globl test_crate.Array.is_array
+			test_crate.Array.is_array:
+				.functype test_crate.Array.is_array (i32,) -> (i32)
+				local.get 0
+				call js_sys.externref.get
+				call test_crate.import.Array.is_array
+				call js_sys.externref.insert
+				end_function"
+		),
+		indoc::indoc!(
+			"(value) => {
+				return globalThis.Array.isArray(value)
+			}"
+		),
+	);
+}
@@ -1,8 +1,9 @@
+mod export;
 mod function;
+mod member;
 mod r#type;
 
-use std::ffi::OsStr;
-use std::io::{self, Cursor};
+use std::io::Cursor;
 use std::path::Path;
 use std::process::Command;
 use std::{env, fs};
@@ -10,7 +11,7 @@ use std::{env, fs};
 use anyhow::{Context, Result};
 use cargo_metadata::{Artifact, CompilerMessage, Message, Target};
 use itertools::Itertools;
-use js_bindgen_ld_shared::{JsBindgenAssemblySectionParser, JsBindgenImportSectionParser};
+use js_bindgen_ld_shared::{Arch, JsBindgenAssemblySectionParser, JsBindgenImportSectionParser};
 use proc_macro2::TokenStream;
 use wasmparser::{Parser, Payload};
 
@@ -188,9 +189,9 @@ fn inner(tmp: &Path, source: &str) -> Result<(Option<String>, Option<String>)> {
 								);
 								assembly_output = Some(assembly.to_owned());
 								js_bindgen_ld_shared::assembly_to_object(
-									OsStr::new("wasm32"),
+									Arch::Wasm32,
+									&["call-indirect-overlong", "reference-types"],
 									assembly,
-									&mut io::sink(),
 								)?;
 							}
 							Payload::CustomSection(c)
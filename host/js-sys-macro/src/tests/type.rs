@@ -14,6 +14,8 @@ fn basic() {
 			#[repr(transparent)]
 			pub struct JsString(::js_sys::JsValue);
 
+			unsafe impl ::js_sys::r#macro::JsType for JsString {}
+
 			impl ::core::ops::Deref for JsString {
 				type Target = ::js_sys::JsValue;
 
@@ -47,12 +49,517 @@ fn basic() {
 					Self(::js_sys::hazard::Output::from_raw(raw))
 				}
 			}
+
+			impl ::core::convert::AsRef<::js_sys::JsValue> for JsString {
+				fn as_ref(&self) -> &::js_sys::JsValue {
+					&self.0
+				}
+			}
+
+			impl ::core::convert::From<JsString> for ::js_sys::JsValue {
+				fn from(value: JsString) -> Self {
+					value.0
+				}
+			}
+
+			impl ::js_sys::JsCast for JsString {
+				fn is_type_of(value: &::js_sys::JsValue) -> ::core::primitive::bool {
+					::js_sys::r#macro::instanceof(value, "JsString")
+				}
+			}
+		},
+		None,
+		None,
+	);
+}
+
+#[test]
+fn extends() {
+	super::test(
+		TokenStream::new(),
+		quote! {
+			extern "C" {
+				pub type HtmlElement: Element + Node;
+			}
+		},
+		quote! {
+			#[repr(transparent)]
+			pub struct HtmlElement(::js_sys::JsValue);
+
+			unsafe impl ::js_sys::r#macro::JsType for HtmlElement {}
+
+			impl ::core::ops::Deref for HtmlElement {
+				type Target = Element;
+
+				fn deref(&self) -> &Self::Target {
+					::js_sys::r#macro::cast_ref(self)
+				}
+			}
+
+			unsafe impl ::js_sys::hazard::Input for &HtmlElement {
+				const IMPORT_FUNC: &'static ::core::primitive::str = ".functype js_sys.externref.get (i32) -> (externref)";
+				const IMPORT_TYPE: &'static ::core::primitive::str = "externref";
+				const TYPE: &'static ::core::primitive::str = "i32";
+				const CONV: &'static ::core::primitive::str = "call js_sys.externref.get";
+
+				type Type = ::core::primitive::i32;
+
+				fn into_raw(self) -> Self::Type {
+					::js_sys::hazard::Input::into_raw(&self.0)
+				}
+			}
+
+			unsafe impl ::js_sys::hazard::Output for HtmlElement {
+				const IMPORT_FUNC: &::core::primitive::str = ".functype js_sys.externref.insert (externref) -> (i32)";
+				const IMPORT_TYPE: &::core::primitive::str = "externref";
+				const TYPE: &::core::primitive::str = "i32";
+				const CONV: &::core::primitive::str = "call js_sys.externref.insert";
+
+				type Type = ::core::primitive::i32;
+
+				fn from_raw(raw: Self::Type) -> Self {
+					Self(::js_sys::hazard::Output::from_raw(raw))
+				}
+			}
+
+			impl ::core::convert::AsRef<::js_sys::JsValue> for HtmlElement {
+				fn as_ref(&self) -> &::js_sys::JsValue {
+					&self.0
+				}
+			}
+
+			impl ::core::convert::From<HtmlElement> for ::js_sys::JsValue {
+				fn from(value: HtmlElement) -> Self {
+					value.0
+				}
+			}
+
+			impl ::js_sys::JsCast for HtmlElement {
+				fn is_type_of(value: &::js_sys::JsValue) -> ::core::primitive::bool {
+					::js_sys::r#macro::instanceof(value, "HtmlElement")
+				}
+			}
+
+			impl ::core::convert::AsRef<Element> for HtmlElement {
+				fn as_ref(&self) -> &Element {
+					::js_sys::r#macro::cast_ref(self)
+				}
+			}
+
+			impl ::core::convert::From<HtmlElement> for Element {
+				fn from(value: HtmlElement) -> Self {
+					::js_sys::r#macro::cast(value)
+				}
+			}
+
+			impl ::core::convert::AsRef<Node> for HtmlElement {
+				fn as_ref(&self) -> &Node {
+					::js_sys::r#macro::cast_ref(self)
+				}
+			}
+
+			impl ::core::convert::From<HtmlElement> for Node {
+				fn from(value: HtmlElement) -> Self {
+					::js_sys::r#macro::cast(value)
+				}
+			}
+		},
+		None,
+		None,
+	);
+}
+
+#[test]
+fn extends_attribute() {
+	super::test(
+		TokenStream::new(),
+		quote! {
+			extern "C" {
+				#[js_sys(extends = Element)]
+				pub type HtmlElement;
+			}
+		},
+		quote! {
+			#[repr(transparent)]
+			pub struct HtmlElement(::js_sys::JsValue);
+
+			unsafe impl ::js_sys::r#macro::JsType for HtmlElement {}
+
+			impl ::core::ops::Deref for HtmlElement {
+				type Target = Element;
+
+				fn deref(&self) -> &Self::Target {
+					::js_sys::r#macro::cast_ref(self)
+				}
+			}
+
+			unsafe impl ::js_sys::hazard::Input for &HtmlElement {
+				const IMPORT_FUNC: &'static ::core::primitive::str = ".functype js_sys.externref.get (i32) -> (externref)";
+				const IMPORT_TYPE: &'static ::core::primitive::str = "externref";
+				const TYPE: &'static ::core::primitive::str = "i32";
+				const CONV: &'static ::core::primitive::str = "call js_sys.externref.get";
+
+				type Type = ::core::primitive::i32;
+
+				fn into_raw(self) -> Self::Type {
+					::js_sys::hazard::Input::into_raw(&self.0)
+				}
+			}
+
+			unsafe impl ::js_sys::hazard::Output for HtmlElement {
+				const IMPORT_FUNC: &::core::primitive::str = ".functype js_sys.externref.insert (externref) -> (i32)";
+				const IMPORT_TYPE: &::core::primitive::str = "externref";
+				const TYPE: &::core::primitive::str = "i32";
+				const CONV: &::core::primitive::str = "call js_sys.externref.insert";
+
+				type Type = ::core::primitive::i32;
+
+				fn from_raw(raw: Self::Type) -> Self {
+					Self(::js_sys::hazard::Output::from_raw(raw))
+				}
+			}
+
+			impl ::core::convert::AsRef<::js_sys::JsValue> for HtmlElement {
+				fn as_ref(&self) -> &::js_sys::JsValue {
+					&self.0
+				}
+			}
+
+			impl ::core::convert::From<HtmlElement> for ::js_sys::JsValue {
+				fn from(value: HtmlElement) -> Self {
+					value.0
+				}
+			}
+
+			impl ::js_sys::JsCast for HtmlElement {
+				fn is_type_of(value: &::js_sys::JsValue) -> ::core::primitive::bool {
+					::js_sys::r#macro::instanceof(value, "HtmlElement")
+				}
+			}
+
+			impl ::core::convert::AsRef<Element> for HtmlElement {
+				fn as_ref(&self) -> &Element {
+					::js_sys::r#macro::cast_ref(self)
+				}
+			}
+
+			impl ::core::convert::From<HtmlElement> for Element {
+				fn from(value: HtmlElement) -> Self {
+					::js_sys::r#macro::cast(value)
+				}
+			}
+		},
+		None,
+		None,
+	);
+}
+
+#[test]
+fn instanceof() {
+	super::test(
+		quote! { namespace = "WebAssembly" },
+		quote! {
+			extern "C" {
+				#[js_sys(instanceof = "Memory")]
+				pub type JsMemory;
+			}
+		},
+		quote! {
+			#[repr(transparent)]
+			pub struct JsMemory(::js_sys::JsValue);
+
+			unsafe impl ::js_sys::r#macro::JsType for JsMemory {}
+
+			impl ::core::ops::Deref for JsMemory {
+				type Target = ::js_sys::JsValue;
+
+				fn deref(&self) -> &Self::Target {
+					&self.0
+				}
+			}
+
+			unsafe impl ::js_sys::hazard::Input for &JsMemory {
+				const IMPORT_FUNC: &'static ::core::primitive::str = ".functype js_sys.externref.get (i32) -> (externref)";
+				const IMPORT_TYPE: &'static ::core::primitive::str = "externref";
+				const TYPE: &'static ::core::primitive::str = "i32";
+				const CONV: &'static ::core::primitive::str = "call js_sys.externref.get";
+
+				type Type = ::core::primitive::i32;
+
+				fn into_raw(self) -> Self::Type {
+					::js_sys::hazard::Input::into_raw(&self.0)
+				}
+			}
+
+			unsafe impl ::js_sys::hazard::Output for JsMemory {
+				const IMPORT_FUNC: &::core::primitive::str = ".functype js_sys.externref.insert (externref) -> (i32)";
+				const IMPORT_TYPE: &::core::primitive::str = "externref";
+				const TYPE: &::core::primitive::str = "i32";
+				const CONV: &::core::primitive::str = "call js_sys.externref.insert";
+
+				type Type = ::core::primitive::i32;
+
+				fn from_raw(raw: Self::Type) -> Self {
+					Self(::js_sys::hazard::Output::from_raw(raw))
+				}
+			}
+
+			impl ::core::convert::AsRef<::js_sys::JsValue> for JsMemory {
+				fn as_ref(&self) -> &::js_sys::JsValue {
+					&self.0
+				}
+			}
+
+			impl ::core::convert::From<JsMemory> for ::js_sys::JsValue {
+				fn from(value: JsMemory) -> Self {
+					value.0
+				}
+			}
+
+			impl ::js_sys::JsCast for JsMemory {
+				fn is_type_of(value: &::js_sys::JsValue) -> ::core::primitive::bool {
+					::js_sys::r#macro::instanceof(value, "WebAssembly.Memory")
+				}
+			}
 		},
 		None,
 		None,
 	);
 }
 
+#[test]
+fn ts_type() {
+	super::test(
+		quote! { namespace = "WebAssembly" },
+		quote! {
+			extern "C" {
+				#[js_sys(instanceof = "Memory", ts_type = "WebAssembly.Memory")]
+				pub type JsMemory;
+			}
+		},
+		quote! {
+			#[repr(transparent)]
+			pub struct JsMemory(::js_sys::JsValue);
+
+			unsafe impl ::js_sys::r#macro::JsType for JsMemory {
+				const TS_TYPE: &::core::primitive::str = "WebAssembly.Memory";
+			}
+
+			impl ::core::ops::Deref for JsMemory {
+				type Target = ::js_sys::JsValue;
+
+				fn deref(&self) -> &Self::Target {
+					&self.0
+				}
+			}
+
+			unsafe impl ::js_sys::hazard::Input for &JsMemory {
+				const IMPORT_FUNC: &'static ::core::primitive::str = ".functype js_sys.externref.get (i32) -> (externref)";
+				const IMPORT_TYPE: &'static ::core::primitive::str = "externref";
+				const TYPE: &'static ::core::primitive::str = "i32";
+				const CONV: &'static ::core::primitive::str = "call js_sys.externref.get";
+
+				type Type = ::core::primitive::i32;
+
+				fn into_raw(self) -> Self::Type {
+					::js_sys::hazard::Input::into_raw(&self.0)
+				}
+			}
+
+			unsafe impl ::js_sys::hazard::Output for JsMemory {
+				const IMPORT_FUNC: &::core::primitive::str = ".functype js_sys.externref.insert (externref) -> (i32)";
+				const IMPORT_TYPE: &::core::primitive::str = "externref";
+				const TYPE: &::core::primitive::str = "i32";
+				const CONV: &::core::primitive::str = "call js_sys.externref.insert";
+
+				type Type = ::core::primitive::i32;
+
+				fn from_raw(raw: Self::Type) -> Self {
+					Self(::js_sys::hazard::Output::from_raw(raw))
+				}
+			}
+
+			impl ::core::convert::AsRef<::js_sys::JsValue> for JsMemory {
+				fn as_ref(&self) -> &::js_sys::JsValue {
+					&self.0
+				}
+			}
+
+			impl ::core::convert::From<JsMemory> for ::js_sys::JsValue {
+				fn from(value: JsMemory) -> Self {
+					value.0
+				}
+			}
+
+			impl ::js_sys::JsCast for JsMemory {
+				fn is_type_of(value: &::js_sys::JsValue) -> ::core::primitive::bool {
+					::js_sys::r#macro::instanceof(value, "WebAssembly.Memory")
+				}
+			}
+		},
+		None,
+		None,
+	);
+}
+
+#[test]
+fn is_type_of() {
+	super::test(
+		TokenStream::new(),
+		quote! {
+			extern "C" {
+				#[js_sys(is_type_of = "(value) => typeof value === 'string'")]
+				pub type JsString;
+			}
+		},
+		quote! {
+			#[repr(transparent)]
+			pub struct JsString(::js_sys::JsValue);
+
+			unsafe impl ::js_sys::r#macro::JsType for JsString {}
+
+			impl ::core::ops::Deref for JsString {
+				type Target = ::js_sys::JsValue;
+
+				fn deref(&self) -> &Self::Target {
+					&self.0
+				}
+			}
+
+			unsafe impl ::js_sys::hazard::Input for &JsString {
+				const IMPORT_FUNC: &'static ::core::primitive::str = ".functype js_sys.externref.get (i32) -> (externref)";
+				const IMPORT_TYPE: &'static ::core::primitive::str = "externref";
+				const TYPE: &'static ::core::primitive::str = "i32";
+				const CONV: &'static ::core::primitive::str = "call js_sys.externref.get";
+
+				type Type = ::core::primitive::i32;
+
+				fn into_raw(self) -> Self::Type {
+					::js_sys::hazard::Input::into_raw(&self.0)
+				}
+			}
+
+			unsafe impl ::js_sys::hazard::Output for JsString {
+				const IMPORT_FUNC: &::core::primitive::str = ".functype js_sys.externref.insert (externref) -> (i32)";
+				const IMPORT_TYPE: &::core::primitive::str = "externref";
+				const TYPE: &::core::primitive::str = "i32";
+				const CONV: &::core::primitive::str = "call js_sys.externref.insert";
+
+				type Type = ::core::primitive::i32;
+
+				fn from_raw(raw: Self::Type) -> Self {
+					Self(::js_sys::hazard::Output::from_raw(raw))
+				}
+			}
+
+			impl ::core::convert::AsRef<::js_sys::JsValue> for JsString {
+				fn as_ref(&self) -> &::js_sys::JsValue {
+					&self.0
+				}
+			}
+
+			impl ::core::convert::From<JsString> for ::js_sys::JsValue {
+				fn from(value: JsString) -> Self {
+					value.0
+				}
+			}
+
+			impl ::js_sys::JsCast for JsString {
+				fn is_type_of(value: &::js_sys::JsValue) -> ::core::primitive::bool {
+					::js_sys::js_bindgen::embed_js!(
+					name = "JsString.is_type_of",
+					"(value) => typeof value === 'string'"
+				);
+
+				#[cfg(target_family = "wasm")]
+				fn is_type_of(value: &::js_sys::JsValue) -> ::core::primitive::bool {
+					::js_sys::js_bindgen::unsafe_embed_asm!(
+						".import_module test_crate.import.JsString.is_type_of, test_crate",
+						".import_name test_crate.import.JsString.is_type_of, JsString.is_type_of",
+						".functype test_crate.import.JsString.is_type_of ({},) -> ({})",
+						"",
+						"{}",
+						"",
+						"{}",
+						"",
+						".globl test_crate.JsString.is_type_of",
+						"test_crate.JsString.is_type_of:",
+						"\t.functype test_crate.JsString.is_type_of ({},) -> ({})",
+						"\tlocal.get 0",
+						"\t{}",
+						"\tcall test_crate.import.JsString.is_type_of",
+						"\t{}",
+						"\tend_function",
+						interpolate <&::js_sys::JsValue as ::js_sys::hazard::Input>::IMPORT_TYPE,
+						interpolate <::core::primitive::bool as ::js_sys::hazard::Output>::IMPORT_TYPE,
+						interpolate <&::js_sys::JsValue as ::js_sys::hazard::Input>::IMPORT_FUNC,
+						interpolate <::core::primitive::bool as ::js_sys::hazard::Output>::IMPORT_FUNC,
+						interpolate <&::js_sys::JsValue as ::js_sys::hazard::Input>::TYPE,
+						interpolate <::core::primitive::bool as ::js_sys::hazard::Output>::TYPE,
+						interpolate <&::js_sys::JsValue as ::js_sys::hazard::Input>::CONV,
+						interpolate <::core::primitive::bool as ::js_sys::hazard::Output>::CONV,
+					);
+
+					::js_sys::js_bindgen::import_js!(
+						name = "JsString.is_type_of",
+						required_embed = "JsString.is_type_of",
+						"{}{}{}{}{}{}{}{}{}{}",
+						interpolate ::js_sys::r#macro::select("this.#jsEmbed.test_crate['JsString.is_type_of']", "(value) => {\n", [<&::js_sys::JsValue as ::js_sys::hazard::Input>::JS_CONV, <::core::primitive::bool as ::js_sys::hazard::Output>::JS_CONV,]),
+						interpolate ::js_sys::r#macro::select("", "\tvalue = ", [<&::js_sys::JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+						interpolate ::js_sys::r#macro::select("", <&::js_sys::JsValue as ::js_sys::hazard::Input>::JS_CONV, [<&::js_sys::JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+						interpolate ::js_sys::r#macro::select("", "(value)\n", [<&::js_sys::JsValue as ::js_sys::hazard::Input>::JS_CONV,]),
+						interpolate ::js_sys::r#macro::select("", "\treturn ", [<&::js_sys::JsValue as ::js_sys::hazard::Input>::JS_CONV, <::core::primitive::bool as ::js_sys::hazard::Output>::JS_CONV,]),
+						interpolate <::core::primitive::bool as ::js_sys::hazard::Output>::JS_CONV,
+						interpolate ::js_sys::r#macro::select("", "(", [<::core::primitive::bool as ::js_sys::hazard::Output>::JS_CONV,]),
+						interpolate ::js_sys::r#macro::select("", "this.#jsEmbed.test_crate['JsString.is_type_of'](value)", [<&::js_sys::JsValue as ::js_sys::hazard::Input>::JS_CONV, <::core::primitive::bool as ::js_sys::hazard::Output>::JS_CONV,]),
+						interpolate ::js_sys::r#macro::select("", ")", [<::core::primitive::bool as ::js_sys::hazard::Output>::JS_CONV,]),
+						interpolate ::js_sys::r#macro::select("", "\n}", [<&::js_sys::JsValue as ::js_sys::hazard::Input>::JS_CONV, <::core::primitive::bool as ::js_sys::hazard::Output>::JS_CONV,]),
+					);
+
+					unsafe extern "C" {
+						#[link_name = "test_crate.JsString.is_type_of"]
+						fn is_type_of(
+							value: <&::js_sys::JsValue as ::js_sys::hazard::Input>::Type,
+						) -> <::core::primitive::bool as ::js_sys::hazard::Output>::Type;
+					}
+
+					<::core::primitive::bool as ::js_sys::hazard::Output>::from_raw(unsafe {
+						is_type_of(<&::js_sys::JsValue as ::js_sys::hazard::Input>::into_raw(value))
+					})
+				}
+
+				#[cfg(not(target_family = "wasm"))]
+				fn is_type_of(_: &::js_sys::JsValue) -> ::core::primitive::bool {
+					::js_sys::r#macro::unsupported("is_type_of")
+				}
+
+				is_type_of(value)
+				}
+			}
+		},
+		indoc::indoc!(
+			".import_module test_crate.import.JsString.is_type_of, test_crate
+			.import_name test_crate.import.JsString.is_type_of, JsString.is_type_of
+			.functype test_crate.import.JsString.is_type_of (externref,) -> (i32)
+
+			.functype js_sys.externref.get (i32) -> (externref)
+
+
+
+
+			.globl test_crate.JsString.is_type_of
+			test_crate.JsString.is_type_of:
+				.functype test_crate.JsString.is_type_of (i32,) -> (i32)
+				local.get 0
+				call js_sys.externref.get
+				call test_crate.import.JsString.is_type_of
+				
+				end_function"
+		),
+		"this.#jsEmbed.test_crate['JsString.is_type_of']",
+	);
+}
+
 #[test]
 fn generic() {
 	super::test(
@@ -69,6 +576,8 @@ fn generic() {
 				_type: ::core::marker::PhantomData<T>
 			}
 
+			unsafe impl<T> ::js_sys::r#macro::JsType for JsString<T> {}
+
 			impl<T> ::core::ops::Deref for JsString<T> {
 				type Target = ::js_sys::JsValue;
 
@@ -105,6 +614,24 @@ fn generic() {
 					}
 				}
 			}
+
+			impl<T> ::core::convert::AsRef<::js_sys::JsValue> for JsString<T> {
+				fn as_ref(&self) -> &::js_sys::JsValue {
+					&self.value
+				}
+			}
+
+			impl<T> ::core::convert::From<JsString<T>> for ::js_sys::JsValue {
+				fn from(value: JsString<T>) -> Self {
+					value.value
+				}
+			}
+
+			impl<T> ::js_sys::JsCast for JsString<T> {
+				fn is_type_of(value: &::js_sys::JsValue) -> ::core::primitive::bool {
+					::js_sys::r#macro::instanceof(value, "JsString")
+				}
+			}
 		},
 		None,
 		None,
@@ -127,6 +654,8 @@ fn default() {
 				_type: ::core::marker::PhantomData<T>
 			}
 
+			unsafe impl<T> ::js_sys::r#macro::JsType for JsString<T> {}
+
 			impl<T> ::core::ops::Deref for JsString<T> {
 				type Target = ::js_sys::JsValue;
 
@@ -163,6 +692,24 @@ fn default() {
 					}
 				}
 			}
+
+			impl<T> ::core::convert::AsRef<::js_sys::JsValue> for JsString<T> {
+				fn as_ref(&self) -> &::js_sys::JsValue {
+					&self.value
+				}
+			}
+
+			impl<T> ::core::convert::From<JsString<T>> for ::js_sys::JsValue {
+				fn from(value: JsString<T>) -> Self {
+					value.value
+				}
+			}
+
+			impl<T> ::js_sys::JsCast for JsString<T> {
+				fn is_type_of(value: &::js_sys::JsValue) -> ::core::primitive::bool {
+					::js_sys::r#macro::instanceof(value, "JsString")
+				}
+			}
 		},
 		None,
 		None,
@@ -185,6 +732,8 @@ fn r#trait() {
 				_type: ::core::marker::PhantomData<T>
 			}
 
+			unsafe impl<T: Sized> ::js_sys::r#macro::JsType for JsString<T> {}
+
 			impl<T: Sized> ::core::ops::Deref for JsString<T> {
 				type Target = ::js_sys::JsValue;
 
@@ -221,6 +770,24 @@ fn r#trait() {
 					}
 				}
 			}
+
+			impl<T: Sized> ::core::convert::AsRef<::js_sys::JsValue> for JsString<T> {
+				fn as_ref(&self) -> &::js_sys::JsValue {
+					&self.value
+				}
+			}
+
+			impl<T: Sized> ::core::convert::From<JsString<T>> for ::js_sys::JsValue {
+				fn from(value: JsString<T>) -> Self {
+					value.value
+				}
+			}
+
+			impl<T: Sized> ::js_sys::JsCast for JsString<T> {
+				fn is_type_of(value: &::js_sys::JsValue) -> ::core::primitive::bool {
+					::js_sys::r#macro::instanceof(value, "JsString")
+				}
+			}
 		},
 		None,
 		None,
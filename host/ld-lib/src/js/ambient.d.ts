@@ -0,0 +1,6 @@
+// Replaced by the linker.
+declare const JBG_PLACEHOLDER_MEMORY: WebAssembly.Memory
+declare const JBG_PLACEHOLDER_JS_EMBED: Record<string, Record<string, any>>
+declare const JBG_PLACEHOLDER_IMPORT_OBJECT: WebAssembly.Imports
+declare const JBG_PLACEHOLDER_JS_EXPORTS: Record<string, Function>
+declare const JBG_PLACEHOLDER_INLINE_WASM: BufferSource | undefined
@@ -1,10 +1,19 @@
+export interface Exports extends WebAssembly.Exports {
+    JBG_PLACEHOLDER_EXPORTS: never;
+}
+export interface Instance extends WebAssembly.Instance {
+    readonly exports: Exports;
+}
+export interface JsBindgen {
+    JBG_PLACEHOLDER_JS_EXPORTS: never;
+}
 export declare class JsBindgen {
     #private;
     constructor(module: WebAssembly.Module);
     get importObject(): WebAssembly.Imports;
     extendImportObject(imports: WebAssembly.Imports): void;
     setInstance(instance: WebAssembly.Instance): void;
-    instantiate(): Promise<WebAssembly.Instance>;
-    static instantiateStreaming(): Promise<WebAssembly.Instance>;
-    static instantiateStreaming(...args: Parameters<typeof fetch>): Promise<WebAssembly.Instance>;
+    instantiate(): Promise<Instance>;
+    static instantiateStreaming(): Promise<Instance>;
+    static instantiateStreaming(...args: Parameters<typeof fetch>): Promise<Instance>;
 }
@@ -12,6 +12,7 @@ export class JsBindgen {
         this.#module = module;
         this.#jsEmbed = JBG_PLACEHOLDER_JS_EMBED;
         this.#importObject = JBG_PLACEHOLDER_IMPORT_OBJECT;
+        Object.assign(this, JBG_PLACEHOLDER_JS_EXPORTS);
     }
     get importObject() {
         if (this.#finished) {
@@ -54,11 +55,18 @@ export class JsBindgen {
             throw "create a new `JsBindgen` class";
         }
         this.#finished = true;
-        return WebAssembly.instantiate(this.#module, this.#importObject);
+        return WebAssembly.instantiate(this.#module, this.#importObject).then((instance) => {
+            this.#instance = instance;
+            return instance;
+        });
     }
     static async instantiateStreaming(...args) {
         let response;
         if (args.length === 0) {
+            const wasm = JBG_PLACEHOLDER_INLINE_WASM;
+            if (wasm !== undefined) {
+                return new JsBindgen(await WebAssembly.compile(wasm)).instantiate();
+            }
             const url = import.meta.url.replace(/\.mjs$/, ".wasm");
             response = fetch(url);
         }
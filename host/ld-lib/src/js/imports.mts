@@ -1,6 +1,16 @@
-declare const JBG_PLACEHOLDER_MEMORY: WebAssembly.Memory
-declare const JBG_PLACEHOLDER_JS_EMBED: Record<string, Record<string, any>>
-declare const JBG_PLACEHOLDER_IMPORT_OBJECT: WebAssembly.Imports
+// Replaced by the linker with the typed exports of the Wasm module.
+export interface Exports extends WebAssembly.Exports {
+	JBG_PLACEHOLDER_EXPORTS: never
+}
+
+export interface Instance extends WebAssembly.Instance {
+	readonly exports: Exports
+}
+
+// Replaced by the linker with the JS wrappers of the exported functions.
+export interface JsBindgen {
+	JBG_PLACEHOLDER_JS_EXPORTS: never
+}
 
 export class JsBindgen {
 	#finished = false
@@ -17,6 +27,7 @@ export class JsBindgen {
 		this.#module = module
 		this.#jsEmbed = JBG_PLACEHOLDER_JS_EMBED
 		this.#importObject = JBG_PLACEHOLDER_IMPORT_OBJECT
+		Object.assign(this, JBG_PLACEHOLDER_JS_EXPORTS)
 	}
 
 	get importObject(): WebAssembly.Imports {
@@ -62,24 +73,31 @@ export class JsBindgen {
 		this.#finished = true
 	}
 
-	instantiate(): Promise<WebAssembly.Instance> {
+	instantiate(): Promise<Instance> {
 		if (this.#finished) {
 			throw "create a new `JsBindgen` class"
 		}
 
 		this.#finished = true
-		return WebAssembly.instantiate(this.#module, this.#importObject)
+		return WebAssembly.instantiate(this.#module, this.#importObject).then((instance) => {
+			this.#instance = instance
+			return instance as Instance
+		})
 	}
 
-	static instantiateStreaming(): Promise<WebAssembly.Instance>
-	static instantiateStreaming(...args: Parameters<typeof fetch>): Promise<WebAssembly.Instance>
+	static instantiateStreaming(): Promise<Instance>
+	static instantiateStreaming(...args: Parameters<typeof fetch>): Promise<Instance>
 
-	static async instantiateStreaming(
-		...args: Parameters<typeof fetch> | []
-	): Promise<WebAssembly.Instance> {
+	static async instantiateStreaming(...args: Parameters<typeof fetch> | []): Promise<Instance> {
 		let response
 
 		if (args.length === 0) {
+			const wasm = JBG_PLACEHOLDER_INLINE_WASM
+
+			if (wasm !== undefined) {
+				return new JsBindgen(await WebAssembly.compile(wasm)).instantiate()
+			}
+
 			const url = import.meta.url.replace(/\.mjs$/, ".wasm")
 			response = fetch(url)
 		} else {
@@ -1,54 +1,136 @@
+mod template;
+#[cfg(test)]
+mod tests;
+mod typescript;
+
+use std::collections::BTreeMap;
 use std::io::Write;
-use std::str;
+use std::str::{self, FromStr};
 
 use anyhow::{Context, Result, bail, ensure};
-use foldhash::fast::FixedState;
 use hashbrown::{HashMap, HashSet};
 use itertools::{Itertools, Position};
-use js_bindgen_ld_shared::{JsBindgenEmbedSectionParser, JsBindgenImportSectionParser};
+use js_bindgen_ld_shared::{
+	JsBindgenEmbedSectionParser, JsBindgenExportSectionParser, JsBindgenImportSectionParser,
+	JsBindgenMainMemorySectionParser, JsBindgenTypeSectionParser,
+};
 use wasm_encoder::{
 	EntityType, ImportSection, Module, ProducersField, ProducersSection, RawSection, Section,
 };
 use wasmparser::{CustomSectionReader, Encoding, Import, KnownCustom, Parser, Payload, TypeRef};
 
-const IMPORTS_JS: &str = include_str!("js/imports.mjs");
+/// Methods of the `JsBindgen` class that JS exports can't override.
+const RESERVED_METHODS: &[&str] = &[
+	"constructor",
+	"importObject",
+	"extendImportObject",
+	"setInstance",
+	"instantiate",
+	"instantiateSync",
+];
+
+/// The flavor of the generated JS file.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum JsTarget {
+	/// An ES module.
+	#[default]
+	Esm,
+	/// A `CommonJS` module.
+	Cjs,
+	/// A classic script defining a global `JsBindgen` class.
+	Script,
+	/// An ES module for Node.js with synchronous instantiation.
+	NodeSync,
+}
+
+impl JsTarget {
+	/// File extension of the generated JS file.
+	#[must_use]
+	pub fn js_extension(self) -> &'static str {
+		match self {
+			Self::Esm | Self::NodeSync => "mjs",
+			Self::Cjs => "cjs",
+			Self::Script => "js",
+		}
+	}
+
+	/// File extension of the generated TypeScript declaration file.
+	#[must_use]
+	pub fn ts_extension(self) -> &'static str {
+		match self {
+			Self::Esm | Self::NodeSync => "d.mts",
+			Self::Cjs => "d.cts",
+			Self::Script => "d.ts",
+		}
+	}
+}
+
+impl FromStr for JsTarget {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self> {
+		Ok(match s {
+			"esm" => Self::Esm,
+			"cjs" => Self::Cjs,
+			"script" => Self::Script,
+			"node-sync" => Self::NodeSync,
+			_ => bail!(
+				"found unknown JS target `{s}`, expected `esm`, `cjs`, `script` or `node-sync`"
+			),
+		})
+	}
+}
+
+/// How to inline the Wasm module into the generated JS file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InlineWasm {
+	/// Base64 encoded string.
+	Base64,
+	/// String with one character per byte, which is more compact than Base64
+	/// but requires the JS file to be served as UTF-8.
+	BinaryString,
+}
 
-/// This removes our custom sections and generates the JS import file.
-pub fn post_processing(wasm_input: &[u8], mut js_output: impl Write) -> Result<Vec<u8>> {
-	// Find main memory first.
+impl FromStr for InlineWasm {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self> {
+		Ok(match s {
+			"base64" => Self::Base64,
+			"binary-string" => Self::BinaryString,
+			_ => bail!(
+				"found unknown Wasm inline encoding `{s}`, expected `base64` or `binary-string`"
+			),
+		})
+	}
+}
+
+/// This removes our custom sections and generates the JS import file and its
+/// TypeScript declaration file.
+pub fn post_processing(
+	wasm_input: &[u8],
+	target: JsTarget,
+	inline_wasm: Option<InlineWasm>,
+	mut js_output: impl Write,
+	ts_output: impl Write,
+) -> Result<Vec<u8>> {
+	// Find main memory and check the encoding of our custom sections first.
 	let mut main_memory = None;
 
 	for payload in Parser::new(0).parse_all(wasm_input) {
 		let payload = payload.context("input should be valid Wasm")?;
 
 		if let Payload::CustomSection(c) = payload
-			&& c.name() == "js_bindgen.main_memory"
+			&& c.name().starts_with("js_bindgen.")
 		{
-			let mut data = c.data();
-			let module_len = u16::from_le_bytes(
-				data.split_off(..2)
-					.context("invalid main memory encoding")?
-					.try_into()
-					.unwrap(),
-			);
-			let module = data
-				.split_off(..module_len.into())
-				.and_then(|b| str::from_utf8(b).ok())
-				.context("invalid main memory encoding")?;
-			let name_len = u16::from_le_bytes(
-				data.split_off(..2)
-					.context("invalid main memory encoding")?
-					.try_into()
-					.unwrap(),
-			);
-			let name = data
-				.split_off(..name_len.into())
-				.and_then(|b| str::from_utf8(b).ok())
-				.context("invalid main memory encoding")?;
-			ensure!(data.is_empty(), "invalid main memory encoding");
-
-			main_memory = Some((module, name));
-			break;
+			js_bindgen_ld_shared::check_custom_section_version(&c)
+				.context("found incompatible custom section in the linked Wasm output")?;
+
+			if c.name() == "js_bindgen.main_memory" {
+				let mut parser = JsBindgenMainMemorySectionParser::new(&c);
+				main_memory = parser.next();
+				ensure!(parser.next().is_none(), "found multiple main memories");
+			}
 		}
 	}
 
@@ -58,6 +140,9 @@ pub fn post_processing(wasm_input: &[u8], mut js_output: impl Write) -> Result<V
 	let mut wasm_output = Vec::new();
 
 	let mut js_store = JsStore::default();
+	let mut type_hints = HashMap::new();
+	// Ordered so the JS output doesn't depend on the order of the input objects.
+	let mut js_exports = BTreeMap::new();
 	let mut memory = None;
 
 	for payload in Parser::new(0).parse_all(wasm_input) {
@@ -75,17 +160,7 @@ pub fn post_processing(wasm_input: &[u8], mut js_output: impl Write) -> Result<V
 				let mut import_section = ImportSection::new();
 
 				for i in i.into_imports() {
-					let mut import = i.context("import should be parsable")?;
-
-					// This is `llvm-mc` workaround for 32-bit tables when compiling to Wasm64.
-					// See https://github.com/llvm/llvm-project/issues/172907.
-					// TODO: This linker is supposed to be agnostic towards `js-sys`.
-					if let TypeRef::Table(t) = &mut import.ty
-						&& t.table64 && import.module == "js_sys"
-						&& import.name == "externref.table"
-					{
-						t.table64 = false;
-					}
+					let import = i.context("import should be parsable")?;
 
 					import_section.import(
 						import.module,
@@ -125,11 +200,40 @@ pub fn post_processing(wasm_input: &[u8], mut js_output: impl Write) -> Result<V
 			Payload::CustomSection(c) if c.name().starts_with("js_bindgen.embed.") => {
 				let stripped = c.name().strip_prefix("js_bindgen.embed.").unwrap();
 				let (module, name) = stripped.split_once('.').with_context(|| {
-					format!("found incorrectly formatted JS import custom section name: {stripped}",)
+					format!("found incorrectly formatted JS import custom section name: {stripped}")
 				})?;
 
 				js_store.add_js_embed(module, name, &c)?;
 			}
+			// Extract all JS wrappers of exports.
+			Payload::CustomSection(c) if c.name().starts_with("js_bindgen.export.") => {
+				let name = c.name().strip_prefix("js_bindgen.export.").unwrap();
+				let mut parser = JsBindgenExportSectionParser::new(&c);
+				let js = parser
+					.next()
+					.with_context(|| format!("found no JS export for `{name}`"))?;
+
+				ensure!(
+					!RESERVED_METHODS.contains(&name),
+					"found JS export `{name}` conflicting with a `JsBindgen` method"
+				);
+
+				if parser.next().is_some() || js_exports.insert(name, js).is_some() {
+					bail!("found multiple JS exports for `{name}`");
+				}
+			}
+			// Extract all TypeScript type hints.
+			Payload::CustomSection(c) if c.name().starts_with("js_bindgen.type.") => {
+				let name = c.name().strip_prefix("js_bindgen.type.").unwrap();
+				let mut parser = JsBindgenTypeSectionParser::new(&c);
+				let hint = parser
+					.next()
+					.with_context(|| format!("found no TypeScript type for `{name}`"))?;
+
+				if parser.next().is_some() || type_hints.insert(name, hint).is_some() {
+					bail!("found multiple TypeScript types for `{name}`");
+				}
+			}
 			Payload::CustomSection(c) if c.name() == "producers" => {
 				let KnownCustom::Producers(c) = c.as_known() else {
 					bail!("unexpected producer section encoding")
@@ -172,9 +276,12 @@ pub fn post_processing(wasm_input: &[u8], mut js_output: impl Write) -> Result<V
 	let memory = memory.context("main memory should be present")?;
 	js_store.assert_expected()?;
 
-	let (js_memory, rest) = IMPORTS_JS.split_once("JBG_PLACEHOLDER_MEMORY").unwrap();
+	let js_template = target.js_template();
+	let (js_memory, rest) = js_template.split_once("JBG_PLACEHOLDER_MEMORY").unwrap();
 	let (js_embed, rest) = rest.split_once("JBG_PLACEHOLDER_JS_EMBED").unwrap();
-	let (js_import_object, js_rest) = rest.split_once("JBG_PLACEHOLDER_IMPORT_OBJECT").unwrap();
+	let (js_import_object, rest) = rest.split_once("JBG_PLACEHOLDER_IMPORT_OBJECT").unwrap();
+	let (js_exports_object, rest) = rest.split_once("JBG_PLACEHOLDER_JS_EXPORTS").unwrap();
+	let (js_inline_wasm, js_rest) = rest.split_once("JBG_PLACEHOLDER_INLINE_WASM").unwrap();
 
 	// `WebAssembly.Memory`.
 	js_output.write_all(js_memory.as_bytes())?;
@@ -240,13 +347,13 @@ pub fn post_processing(wasm_input: &[u8], mut js_output: impl Write) -> Result<V
 
 	for (module, names) in js_store
 		.js_import()
-		.into_iter()
+		.iter()
 		.filter(|(_, names)| !names.values().all(Option::is_none))
 	{
 		writeln!(js_output, "\t\t\t{module}: {{")?;
 
 		for (name, js) in names
-			.into_iter()
+			.iter()
 			.filter_map(|(name, js)| js.map(|js| (name, js)))
 		{
 			write!(js_output, "\t\t\t\t'{name}': ")?;
@@ -267,20 +374,109 @@ pub fn post_processing(wasm_input: &[u8], mut js_output: impl Write) -> Result<V
 
 	js_output.write_all(b"\t\t}")?;
 
+	// Exported functions.
+	js_output.write_all(js_exports_object.as_bytes())?;
+
+	js_output.write_all(b"{\n")?;
+
+	for (name, js) in &js_exports {
+		write!(js_output, "\t\t\t'{name}': ")?;
+
+		for (position, line) in js.lines().with_position() {
+			js_output.write_all(line.as_bytes())?;
+
+			if let Position::First | Position::Middle = position {
+				js_output.write_all(b"\n\t\t\t")?;
+			}
+		}
+
+		js_output.write_all(b",\n")?;
+	}
+
+	js_output.write_all(b"\t\t}")?;
+
+	// Inlined Wasm module.
+	js_output.write_all(js_inline_wasm.as_bytes())?;
+
+	match inline_wasm {
+		Some(InlineWasm::Base64) => {
+			js_output.write_all(b"Uint8Array.from(atob(\"")?;
+			write_base64(&mut js_output, &wasm_output)?;
+			js_output.write_all(b"\"), c => c.charCodeAt(0))")?;
+		}
+		Some(InlineWasm::BinaryString) => {
+			js_output.write_all(b"Uint8Array.from(\"")?;
+			write_binary_string(&mut js_output, &wasm_output)?;
+			js_output.write_all(b"\", c => c.charCodeAt(0))")?;
+		}
+		None => js_output.write_all(b"undefined")?,
+	}
+
 	// Finish
 	js_output.write_all(js_rest.as_bytes())?;
 
+	typescript::write_declaration(
+		&wasm_output,
+		&target.ts_template(),
+		&type_hints,
+		js_exports.keys().copied(),
+		ts_output,
+	)?;
+
 	Ok(wasm_output)
 }
 
-type FixedHashMap<K, V> = HashMap<K, V, FixedState>;
+fn write_base64(mut output: impl Write, data: &[u8]) -> Result<()> {
+	const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+	for chunk in data.chunks(3) {
+		let bytes = [
+			chunk[0],
+			chunk.get(1).copied().unwrap_or(0),
+			chunk.get(2).copied().unwrap_or(0),
+		];
+		let value = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+		let mut encoded = [b'='; 4];
+
+		for (index, byte) in encoded.iter_mut().enumerate().take(chunk.len() + 1) {
+			*byte = ALPHABET[(value >> (18 - index * 6)) as usize & 0x3F];
+		}
+
+		output.write_all(&encoded)?;
+	}
+
+	Ok(())
+}
+
+/// Writes every byte as the character with the same code point.
+fn write_binary_string(mut output: impl Write, data: &[u8]) -> Result<()> {
+	for (index, byte) in data.iter().copied().enumerate() {
+		match byte {
+			b'"' => output.write_all(b"\\\"")?,
+			b'\\' => output.write_all(b"\\\\")?,
+			b'\n' => output.write_all(b"\\n")?,
+			b'\r' => output.write_all(b"\\r")?,
+			b'\t' => output.write_all(b"\\t")?,
+			// `\0` can't be followed by a digit.
+			0 if !data.get(index + 1).is_some_and(u8::is_ascii_digit) => {
+				output.write_all(b"\\0")?;
+			}
+			0x20..=0x7E => output.write_all(&[byte])?,
+			0x00..=0x1F | 0x7F => write!(output, "\\x{byte:02X}")?,
+			0x80..=0xFF => write!(output, "{}", char::from(byte))?,
+		}
+	}
+
+	Ok(())
+}
 
 #[derive(Default)]
 struct JsStore<'a> {
-	import: FixedHashMap<&'a str, FixedHashMap<&'a str, Option<&'a str>>>,
+	// Ordered so the JS output doesn't depend on the order of the input objects.
+	import: BTreeMap<&'a str, BTreeMap<&'a str, Option<&'a str>>>,
 	expected_import: HashMap<&'a str, HashSet<&'a str>>,
 	provided_import: HashMap<&'a str, HashMap<&'a str, Option<JsWithEmbed<'a>>>>,
-	embed: FixedHashMap<&'a str, FixedHashMap<&'a str, &'a str>>,
+	embed: BTreeMap<&'a str, BTreeMap<&'a str, &'a str>>,
 	expected_embed: HashMap<&'a str, HashSet<&'a str>>,
 	provided_embed: HashMap<&'a str, HashMap<&'a str, JsWithEmbed<'a>>>,
 }
@@ -462,11 +658,11 @@ impl<'a> JsStore<'a> {
 		Ok(())
 	}
 
-	fn js_import(&self) -> &FixedHashMap<&'a str, FixedHashMap<&'a str, Option<&'a str>>> {
+	fn js_import(&self) -> &BTreeMap<&'a str, BTreeMap<&'a str, Option<&'a str>>> {
 		&self.import
 	}
 
-	fn js_embed(&self) -> &FixedHashMap<&'a str, FixedHashMap<&'a str, &'a str>> {
+	fn js_embed(&self) -> &BTreeMap<&'a str, BTreeMap<&'a str, &'a str>> {
 		&self.embed
 	}
 }
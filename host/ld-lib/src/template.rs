@@ -0,0 +1,206 @@
+//! The JS and TypeScript declaration files of all [`JsTarget`]s are generated
+//! from the ES module flavor, `js/imports.mjs` and `js/imports.d.mts`, which
+//! `tsc` compiles from `js/imports.mts`. Other flavors replace its prologue,
+//! everything up to and including the class declaration, and its epilogue, the
+//! methods at the end of the class that load the Wasm module.
+//!
+//! The prologues and epilogues below aren't type-checked, so the tests make sure
+//! every flavor still splits into them and the unchanged body.
+
+use std::borrow::Cow;
+
+use crate::JsTarget;
+
+const JS_TEMPLATE: &str = include_str!("js/imports.mjs");
+const TS_TEMPLATE: &str = include_str!("js/imports.d.mts");
+
+/// The prologue ends with this line.
+const CLASS: &str = "class JsBindgen {\n";
+/// The epilogue starts with the first line starting with this.
+const JS_EPILOGUE: &str = "\n    static ";
+const TS_EPILOGUE: &str = "\n    instantiate(";
+
+impl JsTarget {
+	pub(crate) fn js_template(self) -> Cow<'static, str> {
+		match self {
+			Self::Esm => Cow::Borrowed(JS_TEMPLATE),
+			Self::Cjs => substitute(
+				split_js(JS_TEMPLATE),
+				Some(CJS_PROLOGUE),
+				Some(CJS_EPILOGUE),
+			),
+			Self::Script => substitute(
+				split_js(JS_TEMPLATE),
+				Some(SCRIPT_PROLOGUE),
+				Some(SCRIPT_EPILOGUE),
+			),
+			Self::NodeSync => substitute(
+				split_js(JS_TEMPLATE),
+				Some(NODE_SYNC_PROLOGUE),
+				Some(NODE_SYNC_EPILOGUE),
+			),
+		}
+	}
+
+	pub(crate) fn ts_template(self) -> Cow<'static, str> {
+		match self {
+			// Declarations of ES and `CommonJS` modules are identical.
+			Self::Esm | Self::Cjs => Cow::Borrowed(TS_TEMPLATE),
+			Self::Script => substitute(
+				split_ts(TS_TEMPLATE),
+				Some(SCRIPT_TS_PROLOGUE),
+				Some(SCRIPT_TS_EPILOGUE),
+			),
+			Self::NodeSync => substitute(split_ts(TS_TEMPLATE), None, Some(NODE_SYNC_TS_EPILOGUE)),
+		}
+	}
+}
+
+/// Replaces the prologue and epilogue of a split template, [`None`] keeps the
+/// one of the template.
+fn substitute(
+	[template_prologue, body, template_epilogue]: [&str; 3],
+	prologue: Option<&str>,
+	epilogue: Option<&str>,
+) -> Cow<'static, str> {
+	Cow::Owned(format!(
+		"{}{body}{}",
+		prologue.unwrap_or(template_prologue),
+		epilogue.unwrap_or(template_epilogue),
+	))
+}
+
+/// Splits a JS file into prologue, body and epilogue.
+pub(crate) fn split_js(js: &str) -> [&str; 3] {
+	split(js, JS_EPILOGUE)
+}
+
+/// Splits a TypeScript declaration file into prologue, body and epilogue.
+pub(crate) fn split_ts(ts: &str) -> [&str; 3] {
+	split(ts, TS_EPILOGUE)
+}
+
+fn split<'a>(template: &'a str, epilogue_start: &str) -> [&'a str; 3] {
+	let body_start = template
+		.find(CLASS)
+		.expect("template should declare the class")
+		+ CLASS.len();
+	let body_end = template[body_start..]
+		.find(epilogue_start)
+		.expect("template should have an epilogue")
+		+ body_start
+		+ 1;
+
+	[
+		&template[..body_start],
+		&template[body_start..body_end],
+		&template[body_end..],
+	]
+}
+
+const CJS_PROLOGUE: &str = r#""use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.JsBindgen = void 0;
+const promises_1 = require("node:fs/promises");
+class JsBindgen {
+"#;
+
+const CJS_EPILOGUE: &str = r#"    static async instantiateStreaming(...args) {
+        let module;
+        if (args.length === 0) {
+            const wasm = JBG_PLACEHOLDER_INLINE_WASM;
+            if (wasm !== undefined) {
+                return new JsBindgen(await WebAssembly.compile(wasm)).instantiate();
+            }
+            // Node.js and Electron don't support `fetch` with `file:` URLs.
+            const path = __filename.replace(/\.cjs$/, ".wasm");
+            module = await WebAssembly.compile(await (0, promises_1.readFile)(path));
+        }
+        else {
+            module = await WebAssembly.compileStreaming(fetch(...args));
+        }
+        return new JsBindgen(module).instantiate();
+    }
+}
+exports.JsBindgen = JsBindgen;
+"#;
+
+const SCRIPT_PROLOGUE: &str = "class JsBindgen {\n";
+
+/// `document.currentScript` is only available while the script is executing,
+/// which includes the initialization of static fields.
+const SCRIPT_EPILOGUE: &str = r#"    static #url = document.currentScript?.src;
+    static async instantiateStreaming(...args) {
+        let response;
+        if (args.length === 0) {
+            const wasm = JBG_PLACEHOLDER_INLINE_WASM;
+            if (wasm !== undefined) {
+                return new JsBindgen(await WebAssembly.compile(wasm)).instantiate();
+            }
+            if (JsBindgen.#url === undefined) {
+                throw "failed to determine the script URL, pass the Wasm URL instead";
+            }
+            const url = JsBindgen.#url.replace(/\.js$/, ".wasm");
+            response = fetch(url);
+        }
+        else {
+            response = fetch(...args);
+        }
+        const module = await WebAssembly.compileStreaming(response);
+        return new JsBindgen(module).instantiate();
+    }
+}
+"#;
+
+const NODE_SYNC_PROLOGUE: &str = r#"import { readFileSync } from "node:fs";
+export class JsBindgen {
+"#;
+
+const NODE_SYNC_EPILOGUE: &str = r#"    static instantiateSync(path) {
+        const wasm = JBG_PLACEHOLDER_INLINE_WASM;
+        let module;
+        if (path === undefined && wasm !== undefined) {
+            module = new WebAssembly.Module(wasm);
+        }
+        else {
+            path ??= new URL(import.meta.url.replace(/\.mjs$/, ".wasm"));
+            module = new WebAssembly.Module(readFileSync(path));
+        }
+        return new JsBindgen(module).instantiateSync();
+    }
+    instantiateSync() {
+        if (this.#finished) {
+            throw "create a new `JsBindgen` class";
+        }
+        this.#finished = true;
+        const instance = new WebAssembly.Instance(this.#module, this.#importObject);
+        this.#instance = instance;
+        return instance;
+    }
+}
+"#;
+
+/// Declarations of a classic script are global, so they need unique names.
+const SCRIPT_TS_PROLOGUE: &str = r"interface JsBindgenExports extends WebAssembly.Exports {
+    JBG_PLACEHOLDER_EXPORTS: never;
+}
+interface JsBindgenInstance extends WebAssembly.Instance {
+    readonly exports: JsBindgenExports;
+}
+interface JsBindgen {
+    JBG_PLACEHOLDER_JS_EXPORTS: never;
+}
+declare class JsBindgen {
+";
+
+const SCRIPT_TS_EPILOGUE: &str = r"    instantiate(): Promise<JsBindgenInstance>;
+    static instantiateStreaming(): Promise<JsBindgenInstance>;
+    static instantiateStreaming(...args: Parameters<typeof fetch>): Promise<JsBindgenInstance>;
+}
+";
+
+const NODE_SYNC_TS_EPILOGUE: &str = r"    instantiate(): Promise<Instance>;
+    instantiateSync(): Instance;
+    static instantiateSync(path?: string | URL): Instance;
+}
+";
@@ -0,0 +1,126 @@
+use crate::{write_base64, write_binary_string};
+
+fn base64(data: &[u8]) -> String {
+	let mut output = Vec::new();
+	write_base64(&mut output, data).unwrap();
+	String::from_utf8(output).unwrap()
+}
+
+fn binary_string(data: &[u8]) -> String {
+	let mut output = Vec::new();
+	write_binary_string(&mut output, data).unwrap();
+	String::from_utf8(output).unwrap()
+}
+
+/// Decodes like JS `atob()`.
+fn decode_base64(input: &str) -> Vec<u8> {
+	const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+	assert_eq!(input.len() % 4, 0, "found unpadded Base64: {input}");
+	let mut output = Vec::new();
+
+	for chunk in input.as_bytes().chunks(4) {
+		let padding = chunk.iter().rev().take_while(|c| **c == b'=').count();
+		let mut value = 0;
+
+		for c in chunk {
+			let digit = match c {
+				b'=' => 0,
+				c => ALPHABET.iter().position(|a| a == c).unwrap(),
+			};
+			value = (value << 6) | u32::try_from(digit).unwrap();
+		}
+
+		output.extend_from_slice(&value.to_be_bytes()[1..4 - padding]);
+	}
+
+	output
+}
+
+/// Decodes the content of a JS string literal with one character per byte.
+fn decode_binary_string(input: &str) -> Vec<u8> {
+	let mut output = Vec::new();
+	let mut chars = input.chars().peekable();
+
+	while let Some(c) = chars.next() {
+		let byte = match c {
+			'\\' => match chars.next().unwrap() {
+				'"' => b'"',
+				'\\' => b'\\',
+				'n' => b'\n',
+				'r' => b'\r',
+				't' => b'\t',
+				'0' => {
+					assert!(
+						!chars.peek().is_some_and(char::is_ascii_digit),
+						"found `\\0` followed by a digit: {input}"
+					);
+					0
+				}
+				'x' => {
+					let hex: String = chars.by_ref().take(2).collect();
+					u8::from_str_radix(&hex, 16).unwrap()
+				}
+				c => panic!("found unexpected escape `\\{c}`: {input}"),
+			},
+			'"' | '\n' | '\r' => panic!("found unescaped `{c:?}`: {input}"),
+			c => u8::try_from(c).unwrap(),
+		};
+
+		output.push(byte);
+	}
+
+	output
+}
+
+/// Every byte value in lengths that aren't all divisible by 3.
+fn inputs() -> Vec<Vec<u8>> {
+	let all: Vec<u8> = (0..=u8::MAX).collect();
+
+	(0..=6)
+		.map(|len| all[..len].to_vec())
+		.chain([all[1..].to_vec(), all[2..].to_vec()])
+		.chain((0..=u8::MAX).map(|byte| vec![byte; 5]))
+		.chain([all.clone()])
+		.collect()
+}
+
+#[test]
+fn base64_vectors() {
+	// https://datatracker.ietf.org/doc/html/rfc4648#section-10
+	for (data, expected) in [
+		("", ""),
+		("f", "Zg=="),
+		("fo", "Zm8="),
+		("foo", "Zm9v"),
+		("foob", "Zm9vYg=="),
+		("fooba", "Zm9vYmE="),
+		("foobar", "Zm9vYmFy"),
+	] {
+		assert_eq!(base64(data.as_bytes()), expected);
+	}
+}
+
+#[test]
+fn base64_round_trip() {
+	for data in inputs() {
+		assert_eq!(decode_base64(&base64(&data)), data);
+	}
+}
+
+#[test]
+fn binary_string_round_trip() {
+	for data in inputs() {
+		assert_eq!(decode_binary_string(&binary_string(&data)), data);
+	}
+}
+
+#[test]
+fn binary_string_null() {
+	assert_eq!(binary_string(b"\0a\0"), "\\0a\\0");
+	assert_eq!(binary_string(b"\x001"), "\\x001");
+	assert_eq!(
+		decode_binary_string(&binary_string(b"\x001\x00")),
+		b"\x001\x00"
+	);
+}
@@ -0,0 +1,2 @@
+mod inline_wasm;
+mod template;
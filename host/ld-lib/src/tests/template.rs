@@ -0,0 +1,71 @@
+use crate::JsTarget;
+use crate::template::{split_js, split_ts};
+
+const TARGETS: [JsTarget; 4] = [
+	JsTarget::Esm,
+	JsTarget::Cjs,
+	JsTarget::Script,
+	JsTarget::NodeSync,
+];
+
+#[test]
+fn js_placeholders() {
+	for target in TARGETS {
+		let template = target.js_template();
+
+		for placeholder in [
+			"JBG_PLACEHOLDER_MEMORY",
+			"JBG_PLACEHOLDER_JS_EMBED",
+			"JBG_PLACEHOLDER_IMPORT_OBJECT",
+			"JBG_PLACEHOLDER_JS_EXPORTS",
+			"JBG_PLACEHOLDER_INLINE_WASM",
+		] {
+			assert_eq!(
+				template.matches(placeholder).count(),
+				1,
+				"{target:?}: {placeholder}"
+			);
+		}
+	}
+}
+
+#[test]
+fn ts_placeholders() {
+	for target in TARGETS {
+		let template = target.ts_template();
+
+		for placeholder in ["JBG_PLACEHOLDER_EXPORTS", "JBG_PLACEHOLDER_JS_EXPORTS"] {
+			assert_eq!(
+				template.matches(placeholder).count(),
+				1,
+				"{target:?}: {placeholder}"
+			);
+		}
+
+		assert!(template.ends_with("}\n"), "{target:?}");
+	}
+}
+
+#[test]
+fn js_bodies() {
+	let esm = JsTarget::Esm.js_template();
+	let [_, body, _] = split_js(&esm);
+
+	for target in TARGETS {
+		let template = target.js_template();
+		let [_, target_body, _] = split_js(&template);
+		assert_eq!(body, target_body, "{target:?}");
+	}
+}
+
+#[test]
+fn ts_bodies() {
+	let esm = JsTarget::Esm.ts_template();
+	let [_, body, _] = split_ts(&esm);
+
+	for target in TARGETS {
+		let template = target.ts_template();
+		let [_, target_body, _] = split_ts(&template);
+		assert_eq!(body, target_body, "{target:?}");
+	}
+}
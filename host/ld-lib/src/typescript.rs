@@ -0,0 +1,156 @@
+use std::io::Write;
+
+use anyhow::{Context, Result};
+use hashbrown::HashMap;
+use wasmparser::{ExternalKind, FuncType, Parser, Payload, TypeRef, ValType};
+
+/// Writes the TypeScript declaration file with typed signatures for every
+/// export. Type hints embedded by the macros take precedence over the types
+/// derived from the Wasm signature and are the only source of types for JS
+/// exports.
+pub(crate) fn write_declaration<'a>(
+	wasm_input: &[u8],
+	template: &str,
+	type_hints: &HashMap<&str, &str>,
+	js_exports: impl IntoIterator<Item = &'a str>,
+	mut ts_output: impl Write,
+) -> Result<()> {
+	let mut types = Vec::new();
+	let mut functions = Vec::new();
+	let mut exports = Vec::new();
+
+	for payload in Parser::new(0).parse_all(wasm_input) {
+		match payload.context("input should be valid Wasm")? {
+			Payload::TypeSection(t) => {
+				for ty in t.into_iter_err_on_gc_types() {
+					types.push(ty.context("only function types are supported")?);
+				}
+			}
+			Payload::ImportSection(i) => {
+				for import in i.into_imports() {
+					if let TypeRef::Func(ty) | TypeRef::FuncExact(ty) =
+						import.context("import should be parsable")?.ty
+					{
+						functions.push(ty);
+					}
+				}
+			}
+			Payload::FunctionSection(f) => {
+				for ty in f {
+					functions.push(ty.context("function should be parsable")?);
+				}
+			}
+			Payload::ExportSection(e) => {
+				for export in e {
+					exports.push(export.context("export should be parsable")?);
+				}
+			}
+			_ => (),
+		}
+	}
+
+	let (start, rest) = split_placeholder_line(template, "JBG_PLACEHOLDER_EXPORTS");
+	let (middle, rest) = split_placeholder_line(rest, "JBG_PLACEHOLDER_JS_EXPORTS");
+
+	ts_output.write_all(start.as_bytes())?;
+
+	for export in exports {
+		let name = property_name(export.name);
+
+		let ty = match export.kind {
+			ExternalKind::Func | ExternalKind::FuncExact => {
+				if let Some(hint) = type_hints.get(export.name) {
+					writeln!(ts_output, "    {name}{hint};")?;
+					continue;
+				}
+
+				let ty = functions
+					.get(export.index as usize)
+					.and_then(|ty| types.get(*ty as usize))
+					.with_context(|| format!("found invalid function export `{}`", export.name))?;
+				writeln!(ts_output, "    {name}{};", function_signature(ty))?;
+				continue;
+			}
+			ExternalKind::Table => "WebAssembly.Table",
+			ExternalKind::Memory => "WebAssembly.Memory",
+			ExternalKind::Global => "WebAssembly.Global",
+			ExternalKind::Tag => "WebAssembly.Tag",
+		};
+
+		writeln!(ts_output, "    readonly {name}: {ty};")?;
+	}
+
+	ts_output.write_all(middle.as_bytes())?;
+
+	for name in js_exports {
+		let hint = type_hints
+			.get(name)
+			.copied()
+			.unwrap_or("(...args: unknown[]): unknown");
+		writeln!(ts_output, "    {}{hint};", property_name(name))?;
+	}
+
+	ts_output.write_all(rest.as_bytes())?;
+
+	Ok(())
+}
+
+/// Splits the template around the whole line containing the placeholder.
+fn split_placeholder_line<'t>(template: &'t str, placeholder: &str) -> (&'t str, &'t str) {
+	let (start, rest) = template.split_once(placeholder).unwrap();
+	let start = &start[..start.rfind('\n').map_or(0, |index| index + 1)];
+	let rest = &rest[rest.find('\n').map_or(rest.len(), |index| index + 1)..];
+
+	(start, rest)
+}
+
+fn function_signature(ty: &FuncType) -> String {
+	let params = ty
+		.params()
+		.iter()
+		.enumerate()
+		.map(|(index, ty)| format!("arg{index}: {}", value_type(*ty)))
+		.collect::<Vec<_>>()
+		.join(", ");
+
+	let result = match ty.results() {
+		[] => String::from("void"),
+		[ty] => value_type(*ty).to_owned(),
+		results => format!(
+			"[{}]",
+			results
+				.iter()
+				.map(|ty| value_type(*ty))
+				.collect::<Vec<_>>()
+				.join(", ")
+		),
+	};
+
+	format!("({params}): {result}")
+}
+
+fn value_type(ty: ValType) -> &'static str {
+	match ty {
+		ValType::I32 | ValType::F32 | ValType::F64 => "number",
+		ValType::I64 => "bigint",
+		// Can't be passed to or from JS.
+		ValType::V128 => "never",
+		ValType::Ref(ty) if ty.is_func_ref() => "Function | null",
+		ValType::Ref(_) => "unknown",
+	}
+}
+
+/// Quotes export names that aren't valid identifiers.
+fn property_name(name: &str) -> String {
+	let mut chars = name.chars();
+	let valid = chars
+		.next()
+		.is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+		&& chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+
+	if valid {
+		name.to_owned()
+	} else {
+		format!("'{}'", name.replace('\\', "\\\\").replace('\'', "\\'"))
+	}
+}
@@ -0,0 +1,1009 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use hashbrown::HashMap;
+use wasm_encoder::{
+	CustomSection, Encode, EntityType, FunctionSection, ImportSection, LinkingSection, MemoryType,
+	Module, RawSection, RefType, SectionId, SymbolTable, TableSection, TableType, TypeSection,
+	ValType,
+};
+
+/// See <https://github.com/WebAssembly/tool-conventions/blob/main/Linking.md#relocation-sections>.
+const R_WASM_FUNCTION_INDEX_LEB: u8 = 0;
+const R_WASM_TABLE_NUMBER_LEB: u8 = 20;
+
+/// Instructions without any immediates.
+const PLAIN_INSTRUCTIONS: [(&str, &[u8]); 9] = [
+	("unreachable", &[0x00]),
+	("nop", &[0x01]),
+	("return", &[0x0F]),
+	("drop", &[0x1A]),
+	("select", &[0x1B]),
+	("ref.null_func", &[0xD0, 0x70]),
+	("ref.null_extern", &[0xD0, 0x6F]),
+	("ref.is_null", &[0xD1]),
+	("end_function", &[0x0B]),
+];
+
+/// Numeric instructions without any immediates, starting with opcode `0x45`.
+const NUMERIC_INSTRUCTIONS: [&str; 128] = [
+	"i32.eqz",
+	"i32.eq",
+	"i32.ne",
+	"i32.lt_s",
+	"i32.lt_u",
+	"i32.gt_s",
+	"i32.gt_u",
+	"i32.le_s",
+	"i32.le_u",
+	"i32.ge_s",
+	"i32.ge_u",
+	"i64.eqz",
+	"i64.eq",
+	"i64.ne",
+	"i64.lt_s",
+	"i64.lt_u",
+	"i64.gt_s",
+	"i64.gt_u",
+	"i64.le_s",
+	"i64.le_u",
+	"i64.ge_s",
+	"i64.ge_u",
+	"f32.eq",
+	"f32.ne",
+	"f32.lt",
+	"f32.gt",
+	"f32.le",
+	"f32.ge",
+	"f64.eq",
+	"f64.ne",
+	"f64.lt",
+	"f64.gt",
+	"f64.le",
+	"f64.ge",
+	"i32.clz",
+	"i32.ctz",
+	"i32.popcnt",
+	"i32.add",
+	"i32.sub",
+	"i32.mul",
+	"i32.div_s",
+	"i32.div_u",
+	"i32.rem_s",
+	"i32.rem_u",
+	"i32.and",
+	"i32.or",
+	"i32.xor",
+	"i32.shl",
+	"i32.shr_s",
+	"i32.shr_u",
+	"i32.rotl",
+	"i32.rotr",
+	"i64.clz",
+	"i64.ctz",
+	"i64.popcnt",
+	"i64.add",
+	"i64.sub",
+	"i64.mul",
+	"i64.div_s",
+	"i64.div_u",
+	"i64.rem_s",
+	"i64.rem_u",
+	"i64.and",
+	"i64.or",
+	"i64.xor",
+	"i64.shl",
+	"i64.shr_s",
+	"i64.shr_u",
+	"i64.rotl",
+	"i64.rotr",
+	"f32.abs",
+	"f32.neg",
+	"f32.ceil",
+	"f32.floor",
+	"f32.trunc",
+	"f32.nearest",
+	"f32.sqrt",
+	"f32.add",
+	"f32.sub",
+	"f32.mul",
+	"f32.div",
+	"f32.min",
+	"f32.max",
+	"f32.copysign",
+	"f64.abs",
+	"f64.neg",
+	"f64.ceil",
+	"f64.floor",
+	"f64.trunc",
+	"f64.nearest",
+	"f64.sqrt",
+	"f64.add",
+	"f64.sub",
+	"f64.mul",
+	"f64.div",
+	"f64.min",
+	"f64.max",
+	"f64.copysign",
+	"i32.wrap_i64",
+	"i32.trunc_f32_s",
+	"i32.trunc_f32_u",
+	"i32.trunc_f64_s",
+	"i32.trunc_f64_u",
+	"i64.extend_i32_s",
+	"i64.extend_i32_u",
+	"i64.trunc_f32_s",
+	"i64.trunc_f32_u",
+	"i64.trunc_f64_s",
+	"i64.trunc_f64_u",
+	"f32.convert_i32_s",
+	"f32.convert_i32_u",
+	"f32.convert_i64_s",
+	"f32.convert_i64_u",
+	"f32.demote_f64",
+	"f64.convert_i32_s",
+	"f64.convert_i32_u",
+	"f64.convert_i64_s",
+	"f64.convert_i64_u",
+	"f64.promote_f32",
+	"i32.reinterpret_f32",
+	"i64.reinterpret_f64",
+	"f32.reinterpret_i32",
+	"f64.reinterpret_i64",
+	"i32.extend8_s",
+	"i32.extend16_s",
+	"i64.extend8_s",
+	"i64.extend16_s",
+	"i64.extend32_s",
+];
+
+/// Saturating truncation instructions, starting with opcode `0xFC 0x00`.
+const TRUNC_SAT_INSTRUCTIONS: [&str; 8] = [
+	"i32.trunc_sat_f32_s",
+	"i32.trunc_sat_f32_u",
+	"i32.trunc_sat_f64_s",
+	"i32.trunc_sat_f64_u",
+	"i64.trunc_sat_f32_s",
+	"i64.trunc_sat_f32_u",
+	"i64.trunc_sat_f64_s",
+	"i64.trunc_sat_f64_u",
+];
+
+/// Table instructions taking a table symbol as immediate.
+const TABLE_INSTRUCTIONS: [(&str, &[u8]); 5] = [
+	("table.get", &[0x25]),
+	("table.set", &[0x26]),
+	("table.grow", &[0xFC, 0x0F]),
+	("table.size", &[0xFC, 0x10]),
+	("table.fill", &[0xFC, 0x11]),
+];
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Arch {
+	Wasm32,
+	Wasm64,
+}
+
+#[derive(Debug)]
+pub struct AssemblyError {
+	line: usize,
+	text: String,
+	message: String,
+}
+
+impl AssemblyError {
+	/// The line number, starting at 1, in the assembly this error originated
+	/// from.
+	#[must_use]
+	pub fn line(&self) -> usize {
+		self.line
+	}
+}
+
+impl Display for AssemblyError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "line {}: {}\n\t{}", self.line, self.message, self.text)
+	}
+}
+
+impl Error for AssemblyError {}
+
+/// Converts LLVM s-format assembly to an object file the linker can consume.
+///
+/// Only the subset of LLVM's Wasm assembly emitted by `js-bindgen` is
+/// supported. The object file is marked with the given target features, which
+/// should match the features the surrounding objects were compiled with.
+pub fn assembly_to_object(
+	arch: Arch,
+	features: &[&str],
+	assembly: &str,
+) -> Result<Vec<u8>, AssemblyError> {
+	let mut assembler = Assembler::new(features, assembly);
+
+	for (index, line) in assembly.lines().enumerate() {
+		assembler
+			.line(index + 1, line)
+			.map_err(|message| assembler.error(index + 1, message))?;
+	}
+
+	assembler.finish(arch)
+}
+
+struct Assembler<'a> {
+	features: &'a [&'a str],
+	source: Vec<&'a str>,
+	symbols: Vec<Symbol<'a>>,
+	lookup: HashMap<&'a str, usize>,
+	functions: Vec<Function>,
+	current: Option<Function>,
+}
+
+struct Symbol<'a> {
+	name: &'a str,
+	line: usize,
+	kind: Option<SymbolKind>,
+	import_module: Option<&'a str>,
+	import_name: Option<&'a str>,
+	global: bool,
+	defined: bool,
+	used: bool,
+}
+
+#[derive(Clone, PartialEq)]
+enum SymbolKind {
+	Function(FuncType),
+	Table(Table),
+}
+
+#[derive(Clone, PartialEq)]
+struct FuncType {
+	params: Vec<ValType>,
+	results: Vec<ValType>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct Table {
+	element_type: RefType,
+	minimum: u64,
+	maximum: Option<u64>,
+}
+
+struct Function {
+	symbol: usize,
+	line: usize,
+	params: usize,
+	locals: Vec<ValType>,
+	body: Vec<u8>,
+	relocations: Vec<Relocation>,
+}
+
+struct Relocation {
+	ty: u8,
+	offset: usize,
+	symbol: usize,
+}
+
+impl<'a> Assembler<'a> {
+	fn new(features: &'a [&'a str], assembly: &'a str) -> Self {
+		Self {
+			features,
+			source: assembly.lines().collect(),
+			symbols: Vec::new(),
+			lookup: HashMap::new(),
+			functions: Vec::new(),
+			current: None,
+		}
+	}
+
+	fn error(&self, line: usize, message: String) -> AssemblyError {
+		AssemblyError {
+			line,
+			text: self.source[line - 1].trim().to_owned(),
+			message,
+		}
+	}
+
+	fn require(&self, feature: &str, what: impl Display) -> Result<(), String> {
+		if self.features.contains(&feature) {
+			Ok(())
+		} else {
+			Err(format!("{what} requires target feature `{feature}`"))
+		}
+	}
+
+	fn require_val_types(&self, types: &[ValType]) -> Result<(), String> {
+		for ty in types {
+			match ty {
+				ValType::Ref(_) => self.require("reference-types", "reference types")?,
+				ValType::V128 => self.require("simd128", "`v128`")?,
+				ValType::I32 | ValType::I64 | ValType::F32 | ValType::F64 => (),
+			}
+		}
+
+		Ok(())
+	}
+
+	fn line(&mut self, line_number: usize, line: &'a str) -> Result<(), String> {
+		// Strip comments.
+		let line = line.split_once('#').map_or(line, |(line, _)| line).trim();
+
+		if line.is_empty() {
+			return Ok(());
+		}
+
+		if let Some(label) = line.strip_suffix(':') {
+			return self.label(line_number, label);
+		}
+
+		let (op, args) = line
+			.split_once(char::is_whitespace)
+			.map_or((line, ""), |(op, args)| (op, args.trim()));
+
+		if let Some(directive) = op.strip_prefix('.') {
+			self.directive(line_number, directive, args)
+		} else {
+			self.instruction(line_number, op, args)
+		}
+	}
+
+	fn symbol(&mut self, name: &'a str, line: usize) -> Result<usize, String> {
+		if name.is_empty() || name.contains(|c: char| c.is_whitespace() || c == ',') {
+			return Err(format!("invalid symbol name `{name}`"));
+		}
+
+		Ok(*self.lookup.entry(name).or_insert_with(|| {
+			self.symbols.push(Symbol {
+				name,
+				line,
+				kind: None,
+				import_module: None,
+				import_name: None,
+				global: false,
+				defined: false,
+				used: false,
+			});
+			self.symbols.len() - 1
+		}))
+	}
+
+	fn set_kind(&mut self, symbol: usize, kind: SymbolKind) -> Result<(), String> {
+		let symbol = &mut self.symbols[symbol];
+
+		match &symbol.kind {
+			None => {
+				symbol.kind = Some(kind);
+				Ok(())
+			}
+			Some(existing) if *existing == kind => Ok(()),
+			Some(_) => Err(format!(
+				"conflicting type declaration for symbol `{}`",
+				symbol.name
+			)),
+		}
+	}
+
+	fn label(&mut self, line: usize, label: &'a str) -> Result<(), String> {
+		if self.current.is_some() {
+			return Err(String::from(
+				"labels inside function bodies are not supported",
+			));
+		}
+
+		let index = self.symbol(label, line)?;
+		let symbol = &mut self.symbols[index];
+
+		if symbol.defined {
+			return Err(format!("symbol `{label}` is already defined"));
+		}
+
+		if symbol.import_module.is_some() || symbol.import_name.is_some() {
+			return Err(format!("can't define imported symbol `{label}`"));
+		}
+
+		symbol.defined = true;
+
+		// Tables are defined by their label alone, everything else starts a function.
+		if !matches!(symbol.kind, Some(SymbolKind::Table(_))) {
+			self.current = Some(Function {
+				symbol: index,
+				line,
+				params: 0,
+				locals: Vec::new(),
+				body: Vec::new(),
+				relocations: Vec::new(),
+			});
+		}
+
+		Ok(())
+	}
+
+	fn directive(&mut self, line: usize, directive: &str, args: &'a str) -> Result<(), String> {
+		match directive {
+			"functype" => {
+				let (name, signature) = args
+					.split_once(char::is_whitespace)
+					.ok_or("expected `.functype <symbol> (<params>) -> (<results>)`")?;
+				let ty = parse_signature(signature.trim())?;
+				self.require_val_types(&ty.params)?;
+				self.require_val_types(&ty.results)?;
+
+				if ty.results.len() > 1 {
+					self.require("multivalue", "multiple results")?;
+				}
+
+				let index = self.symbol(name, line)?;
+
+				if let Some(function) = &mut self.current
+					&& function.symbol == index
+				{
+					function.params = ty.params.len();
+				}
+
+				self.set_kind(index, SymbolKind::Function(ty))
+			}
+			"import_module" | "import_name" => {
+				let (name, value) = split_pair(args, directive)?;
+				let index = self.symbol(name, line)?;
+				let symbol = &mut self.symbols[index];
+
+				if symbol.defined {
+					return Err(format!("can't import defined symbol `{name}`"));
+				}
+
+				let field = if directive == "import_module" {
+					&mut symbol.import_module
+				} else {
+					&mut symbol.import_name
+				};
+
+				match field {
+					Some(existing) if *existing != value => {
+						Err(format!("conflicting `.{directive}` for `{name}`"))
+					}
+					_ => {
+						*field = Some(value);
+						Ok(())
+					}
+				}
+			}
+			"tabletype" => {
+				self.require("reference-types", "`.tabletype`")?;
+
+				let mut parts = args.split(',').map(str::trim);
+				let name = parts.next().unwrap_or_default();
+				let element_type = match parts.next() {
+					Some("externref") => RefType::EXTERNREF,
+					Some("funcref") => RefType::FUNCREF,
+					Some(ty) => return Err(format!("unsupported table element type `{ty}`")),
+					None => return Err(String::from("expected `.tabletype <symbol>, <type>`")),
+				};
+				let minimum = parts.next().map(parse_u64).transpose()?.unwrap_or(0);
+				let maximum = parts.next().map(parse_u64).transpose()?;
+
+				if let Some(part) = parts.next() {
+					return Err(format!("unexpected `{part}` after `.tabletype`"));
+				}
+
+				let index = self.symbol(name, line)?;
+				self.set_kind(
+					index,
+					SymbolKind::Table(Table {
+						element_type,
+						minimum,
+						maximum,
+					}),
+				)
+			}
+			"globl" => {
+				let index = self.symbol(args, line)?;
+				self.symbols[index].global = true;
+				Ok(())
+			}
+			"local" => {
+				let locals = args
+					.split(',')
+					.map(str::trim)
+					.filter(|ty| !ty.is_empty())
+					.map(parse_val_type)
+					.collect::<Result<Vec<_>, _>>()?;
+				self.require_val_types(&locals)?;
+
+				let function = self
+					.current
+					.as_mut()
+					.ok_or("`.local` outside of a function")?;
+
+				if !function.body.is_empty() {
+					return Err(String::from("`.local` must precede all instructions"));
+				}
+
+				function.locals.extend(locals);
+
+				Ok(())
+			}
+			directive => Err(format!("unsupported directive `.{directive}`")),
+		}
+	}
+
+	fn instruction(&mut self, line: usize, op: &str, args: &'a str) -> Result<(), String> {
+		let Some(mut function) = self.current.take() else {
+			return Err(format!("instruction `{op}` outside of a function"));
+		};
+
+		if !matches!(
+			self.symbols[function.symbol].kind,
+			Some(SymbolKind::Function(_))
+		) {
+			return Err(format!(
+				"expected `.functype` for `{}` before any instruction",
+				self.symbols[function.symbol].name
+			));
+		}
+
+		let expect_no_args = || {
+			if args.is_empty() {
+				Ok(())
+			} else {
+				Err(format!("`{op}` expects no operands"))
+			}
+		};
+
+		if let Some((_, opcode)) = PLAIN_INSTRUCTIONS.iter().find(|(name, _)| *name == op) {
+			expect_no_args()?;
+			function.body.extend_from_slice(opcode);
+
+			if op == "end_function" {
+				self.functions.push(function);
+				return Ok(());
+			}
+		} else if let Some(index) = NUMERIC_INSTRUCTIONS.iter().position(|name| *name == op) {
+			expect_no_args()?;
+			function.body.push(0x45 + u8::try_from(index).unwrap());
+		} else if let Some(index) = TRUNC_SAT_INSTRUCTIONS.iter().position(|name| *name == op) {
+			expect_no_args()?;
+			function.body.push(0xFC);
+			u32::try_from(index).unwrap().encode(&mut function.body);
+		} else if let Some((_, opcode)) = TABLE_INSTRUCTIONS.iter().find(|(name, _)| *name == op) {
+			let symbol = self.symbol(args, line)?;
+
+			if !matches!(self.symbols[symbol].kind, Some(SymbolKind::Table(_))) {
+				return Err(format!("expected table symbol, found `{args}`"));
+			}
+
+			function.body.extend_from_slice(opcode);
+			self.relocation(&mut function, R_WASM_TABLE_NUMBER_LEB, symbol);
+		} else {
+			match op {
+				"local.get" | "local.set" | "local.tee" => {
+					let index = parse_u64(args)?;
+
+					if index >= (function.params + function.locals.len()) as u64 {
+						return Err(format!("local index `{index}` out of bounds"));
+					}
+
+					function.body.push(match op {
+						"local.get" => 0x20,
+						"local.set" => 0x21,
+						_ => 0x22,
+					});
+					index.encode(&mut function.body);
+				}
+				"call" => {
+					let symbol = self.symbol(args, line)?;
+
+					if !matches!(self.symbols[symbol].kind, Some(SymbolKind::Function(_))) {
+						return Err(format!("missing `.functype` for `{args}`"));
+					}
+
+					function.body.push(0x10);
+					self.relocation(&mut function, R_WASM_FUNCTION_INDEX_LEB, symbol);
+				}
+				"i32.const" => {
+					let value = parse_i64(args)?;
+					let value = i32::try_from(value)
+						.ok()
+						.or_else(|| u32::try_from(value).ok().map(u32::cast_signed))
+						.ok_or_else(|| format!("`{args}` out of range for `i32`"))?;
+					function.body.push(0x41);
+					value.encode(&mut function.body);
+				}
+				"i64.const" => {
+					let value = parse_i64(args)?;
+					function.body.push(0x42);
+					value.encode(&mut function.body);
+				}
+				"f32.const" => {
+					let value: f32 = args
+						.parse()
+						.map_err(|_| format!("invalid `f32` constant `{args}`"))?;
+					function.body.push(0x43);
+					function.body.extend_from_slice(&value.to_le_bytes());
+				}
+				"f64.const" => {
+					let value: f64 = args
+						.parse()
+						.map_err(|_| format!("invalid `f64` constant `{args}`"))?;
+					function.body.push(0x44);
+					function.body.extend_from_slice(&value.to_le_bytes());
+				}
+				op => return Err(format!("unsupported instruction `{op}`")),
+			}
+		}
+
+		if let Some(feature) = instruction_feature(op) {
+			self.require(feature, format_args!("`{op}`"))?;
+		}
+
+		self.current = Some(function);
+
+		Ok(())
+	}
+
+	fn relocation(&mut self, function: &mut Function, ty: u8, symbol: usize) {
+		self.symbols[symbol].used = true;
+		function.relocations.push(Relocation {
+			ty,
+			offset: function.body.len(),
+			symbol,
+		});
+		// Placeholder for a padded LEB128, filled in when the final indices are known.
+		function.body.extend_from_slice(&[0; 5]);
+	}
+
+	fn finish(mut self, arch: Arch) -> Result<Vec<u8>, AssemblyError> {
+		if let Some(function) = &self.current {
+			return Err(self.error(
+				function.line,
+				format!(
+					"missing `end_function` for `{}`",
+					self.symbols[function.symbol].name
+				),
+			));
+		}
+
+		for symbol in &self.symbols {
+			if (symbol.defined || symbol.used) && symbol.kind.is_none() {
+				return Err(self.error(
+					symbol.line,
+					format!("missing type declaration for `{}`", symbol.name),
+				));
+			}
+		}
+
+		let mut types: Vec<FuncType> = Vec::new();
+		let mut type_index = |ty: &FuncType| {
+			if let Some(index) = types.iter().position(|existing| existing == ty) {
+				index
+			} else {
+				types.push(ty.clone());
+				types.len() - 1
+			}
+		};
+
+		// Symbol index in the symbol table and the index in its index space.
+		let mut symbol_indices = vec![None; self.symbols.len()];
+		let mut element_indices = vec![0; self.symbols.len()];
+		let mut next_symbol = 0;
+		let mut function_count = 0;
+		let mut table_count = 0;
+
+		// The linker expects the memory to always be imported.
+		let mut imports = ImportSection::new();
+		imports.import(
+			"env",
+			"__linear_memory",
+			MemoryType {
+				minimum: 0,
+				maximum: None,
+				memory64: arch == Arch::Wasm64,
+				shared: false,
+				page_size_log2: None,
+			},
+		);
+
+		for (index, symbol) in self.symbols.iter().enumerate() {
+			if symbol.defined || !symbol.used {
+				continue;
+			}
+
+			let module = symbol.import_module.unwrap_or("env");
+			let name = symbol.import_name.unwrap_or(symbol.name);
+
+			match symbol.kind.as_ref().unwrap() {
+				SymbolKind::Function(ty) => {
+					let ty = u32::try_from(type_index(ty)).unwrap();
+					imports.import(module, name, EntityType::Function(ty));
+					element_indices[index] = function_count;
+					function_count += 1;
+				}
+				SymbolKind::Table(table) => {
+					imports.import(module, name, table.encoder());
+					element_indices[index] = table_count;
+					table_count += 1;
+				}
+			}
+		}
+
+		let mut functions = FunctionSection::new();
+
+		for function in &self.functions {
+			let Some(SymbolKind::Function(ty)) = &self.symbols[function.symbol].kind else {
+				unreachable!("functions always have a signature")
+			};
+			functions.function(u32::try_from(type_index(ty)).unwrap());
+			element_indices[function.symbol] = function_count;
+			function_count += 1;
+		}
+
+		let mut tables = TableSection::new();
+
+		for (index, symbol) in self.symbols.iter().enumerate() {
+			if let Some(SymbolKind::Table(table)) = &symbol.kind
+				&& symbol.defined
+			{
+				tables.table(table.encoder());
+				element_indices[index] = table_count;
+				table_count += 1;
+			}
+		}
+
+		let mut symbol_table = SymbolTable::new();
+
+		for (index, symbol) in self.symbols.iter().enumerate() {
+			if !symbol.defined && !symbol.used {
+				continue;
+			}
+
+			let mut flags = 0;
+			let mut name = Some(symbol.name);
+
+			if symbol.defined {
+				if !symbol.global {
+					flags |= SymbolTable::WASM_SYM_BINDING_LOCAL;
+				}
+			} else {
+				flags |= SymbolTable::WASM_SYM_UNDEFINED;
+
+				if symbol.import_name.is_some() {
+					flags |= SymbolTable::WASM_SYM_EXPLICIT_NAME;
+				} else {
+					name = None;
+				}
+			}
+
+			let element_index = u32::try_from(element_indices[index]).unwrap();
+
+			match symbol.kind.as_ref().unwrap() {
+				SymbolKind::Function(_) => symbol_table.function(flags, element_index, name),
+				SymbolKind::Table(_) => symbol_table.table(flags, element_index, name),
+			};
+
+			symbol_indices[index] = Some(next_symbol);
+			next_symbol += 1;
+		}
+
+		let mut code = Vec::new();
+		let mut relocations = Vec::new();
+		let mut relocation_count = 0_u32;
+		u32::try_from(self.functions.len())
+			.unwrap()
+			.encode(&mut code);
+
+		for function in &mut self.functions {
+			let mut locals = Vec::new();
+			let mut groups: Vec<(u32, ValType)> = Vec::new();
+
+			for ty in &function.locals {
+				match groups.last_mut() {
+					Some((count, last)) if last == ty => *count += 1,
+					_ => groups.push((1, *ty)),
+				}
+			}
+
+			u32::try_from(groups.len()).unwrap().encode(&mut locals);
+
+			for (count, ty) in groups {
+				count.encode(&mut locals);
+				ty.encode(&mut locals);
+			}
+
+			for relocation in &function.relocations {
+				let index = u32::try_from(element_indices[relocation.symbol]).unwrap();
+				function.body[relocation.offset..relocation.offset + 5]
+					.copy_from_slice(&padded_leb128(index));
+			}
+
+			(locals.len() + function.body.len()).encode(&mut code);
+			let start = code.len() + locals.len();
+			code.append(&mut locals);
+			code.append(&mut function.body);
+
+			for relocation in &function.relocations {
+				relocations.push(relocation.ty);
+				(start + relocation.offset).encode(&mut relocations);
+				symbol_indices[relocation.symbol]
+					.expect("relocated symbols are always in the symbol table")
+					.encode(&mut relocations);
+				relocation_count += 1;
+			}
+		}
+
+		let mut module = Module::new();
+		let mut section_count = 0_u32;
+		let mut code_section = None;
+
+		if !types.is_empty() {
+			let mut section = TypeSection::new();
+
+			for ty in &types {
+				section
+					.ty()
+					.function(ty.params.iter().copied(), ty.results.iter().copied());
+			}
+
+			module.section(&section);
+			section_count += 1;
+		}
+
+		module.section(&imports);
+		section_count += 1;
+
+		if !functions.is_empty() {
+			module.section(&functions);
+			section_count += 1;
+		}
+
+		if !tables.is_empty() {
+			module.section(&tables);
+			section_count += 1;
+		}
+
+		if !self.functions.is_empty() {
+			module.section(&RawSection {
+				id: SectionId::Code.into(),
+				data: &code,
+			});
+			code_section = Some(section_count);
+		}
+
+		let mut linking = LinkingSection::new();
+		linking.symbol_table(&symbol_table);
+		module.section(&linking);
+
+		if let Some(code_section) = code_section
+			&& relocation_count > 0
+		{
+			let mut data = Vec::new();
+			code_section.encode(&mut data);
+			relocation_count.encode(&mut data);
+			data.append(&mut relocations);
+
+			module.section(&CustomSection {
+				name: Cow::Borrowed("reloc.CODE"),
+				data: Cow::Owned(data),
+			});
+		}
+
+		let mut features = self.features.to_vec();
+		features.sort_unstable();
+		features.dedup();
+
+		if !features.is_empty() {
+			let mut data = Vec::new();
+			features.len().encode(&mut data);
+
+			for feature in features {
+				data.push(b'+');
+				feature.encode(&mut data);
+			}
+
+			module.section(&CustomSection {
+				name: Cow::Borrowed("target_features"),
+				data: Cow::Owned(data),
+			});
+		}
+
+		Ok(module.finish())
+	}
+}
+
+impl Table {
+	fn encoder(self) -> TableType {
+		TableType {
+			element_type: self.element_type,
+			table64: false,
+			minimum: self.minimum,
+			maximum: self.maximum,
+			shared: false,
+		}
+	}
+}
+
+fn instruction_feature(op: &str) -> Option<&'static str> {
+	if op.starts_with("table.") || op.starts_with("ref.") {
+		Some("reference-types")
+	} else if op.contains(".trunc_sat_") {
+		Some("nontrapping-fptoint")
+	} else if op.contains(".extend8_s") || op.contains(".extend16_s") || op == "i64.extend32_s" {
+		Some("sign-ext")
+	} else {
+		None
+	}
+}
+
+fn parse_signature(signature: &str) -> Result<FuncType, String> {
+	let error = || format!("expected `(<params>) -> (<results>)`, found `{signature}`");
+
+	let (params, results) = signature.split_once("->").ok_or_else(error)?;
+	let parse_list = |list: &str| -> Result<Vec<ValType>, String> {
+		list.trim()
+			.strip_prefix('(')
+			.and_then(|list| list.strip_suffix(')'))
+			.ok_or_else(error)?
+			.split(',')
+			.map(str::trim)
+			.filter(|ty| !ty.is_empty())
+			.map(parse_val_type)
+			.collect()
+	};
+
+	Ok(FuncType {
+		params: parse_list(params)?,
+		results: parse_list(results)?,
+	})
+}
+
+fn parse_val_type(ty: &str) -> Result<ValType, String> {
+	Ok(match ty {
+		"i32" => ValType::I32,
+		"i64" => ValType::I64,
+		"f32" => ValType::F32,
+		"f64" => ValType::F64,
+		"v128" => ValType::V128,
+		"externref" => ValType::EXTERNREF,
+		"funcref" => ValType::FUNCREF,
+		ty => return Err(format!("unsupported value type `{ty}`")),
+	})
+}
+
+fn split_pair<'a>(args: &'a str, directive: &str) -> Result<(&'a str, &'a str), String> {
+	args.split_once(',')
+		.map(|(name, value)| (name.trim(), value.trim()))
+		.filter(|(_, value)| !value.is_empty())
+		.ok_or_else(|| format!("expected `.{directive} <symbol>, <value>`"))
+}
+
+fn parse_u64(value: &str) -> Result<u64, String> {
+	let result = if let Some(hex) = value.strip_prefix("0x") {
+		u64::from_str_radix(hex, 16)
+	} else {
+		value.parse()
+	};
+
+	result.map_err(|_| format!("invalid integer `{value}`"))
+}
+
+fn parse_i64(value: &str) -> Result<i64, String> {
+	if let Some(value) = value.strip_prefix('-') {
+		let value = parse_u64(value)?;
+		0_i64
+			.checked_sub_unsigned(value)
+			.ok_or_else(|| format!("`-{value}` out of range"))
+	} else {
+		let value = parse_u64(value)?;
+		Ok(i64::try_from(value).unwrap_or_else(|_| value.cast_signed()))
+	}
+}
+
+fn padded_leb128(mut value: u32) -> [u8; 5] {
+	let mut output = [0; 5];
+
+	for byte in &mut output[..4] {
+		*byte = (value & 0x7F) as u8 | 0x80;
+		value >>= 7;
+	}
+
+	output[4] = (value & 0x7F) as u8;
+	output
+}
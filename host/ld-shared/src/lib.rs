@@ -1,77 +1,18 @@
+mod assembler;
+#[cfg(test)]
+mod tests;
+
+use std::error::Error;
 use std::ffi::OsStr;
-use std::fmt::{self, Debug, Formatter};
-use std::io::{self, Error, Read, Write};
+use std::fmt::{self, Debug, Display, Formatter};
 use std::path::Path;
-use std::process::{Command, Stdio};
 
+pub use js_bindgen_shared::CUSTOM_SECTION_VERSION;
 use js_bindgen_shared::ReadFile;
 use object::read::archive::ArchiveFile;
-use wasmparser::CustomSectionReader;
-
-/// Currently this simply passes the LLVM s-format assembly to `llvm-mc` to
-/// convert to an object file the linker can consume.
-pub fn assembly_to_object(
-	arch_str: &OsStr,
-	assembly: &str,
-	output: &mut dyn Write,
-) -> Result<(), Error> {
-	let mut child = Command::new("llvm-mc")
-		.arg(format!("-arch={}", arch_str.display()))
-		// In the future we will switch to something supporting auto-detection.
-		.arg("-mattr=+reference-types,+call-indirect-overlong")
-		.arg("-filetype=obj")
-		.stdout(Stdio::piped())
-		.stderr(Stdio::piped())
-		.stdin(Stdio::piped())
-		.spawn()?;
-
-	let stdin = child
-		.stdin
-		.as_mut()
-		.ok_or_else(|| Error::other("`llvm-mc` process should have `stdin`"))?;
-	stdin.write_all(assembly.as_bytes())?;
-
-	let status = child.wait()?;
-
-	if status.success() {
-		io::copy(&mut child.stdout.unwrap(), output)?;
-		Ok(())
-	} else {
-		eprintln!("------ llvm-mc input -------\n{assembly}",);
-
-		let mut stdout = Vec::new();
-		child.stdout.unwrap().read_to_end(&mut stdout)?;
-
-		if !stdout.is_empty() {
-			eprintln!(
-				"------ llvm-mc stdout ------\n{}",
-				String::from_utf8_lossy(&stdout)
-			);
+use wasmparser::{BinaryReader, CustomSectionReader};
 
-			if !stdout.ends_with(b"\n") {
-				eprintln!();
-			}
-		}
-
-		let mut stderr = Vec::new();
-		child.stderr.unwrap().read_to_end(&mut stderr)?;
-
-		if !stderr.is_empty() {
-			eprintln!(
-				"------ llvm-mc stderr ------\n{}",
-				String::from_utf8_lossy(&stderr)
-			);
-
-			if !stderr.ends_with(b"\n") {
-				eprintln!();
-			}
-		}
-
-		Err(Error::other(format!(
-			"`llvm-mc` process failed with status: {status}"
-		)))
-	}
-}
+pub use crate::assembler::{Arch, AssemblyError, assembly_to_object};
 
 pub fn ld_input_parser<E>(
 	input: &OsStr,
@@ -154,6 +95,89 @@ pub fn ld_input_parser<E>(
 	Ok(())
 }
 
+/// Name of a non-existent library linked by `js-sys`. Linking fails unless
+/// `js-bindgen-ld` removes it, which prevents accidentally using the default
+/// linker.
+pub const POISON_LIBRARY: &str = "js-bindgen requires `js-bindgen-ld` as the linker";
+
+/// Returns `true` if the data is LLVM bitcode instead of a Wasm object, e.g.
+/// when compiling with `-Clinker-plugin-lto`.
+#[must_use]
+pub fn is_llvm_bitcode(data: &[u8]) -> bool {
+	// Raw bitcode or the bitcode wrapper format.
+	data.starts_with(b"BC\xC0\xDE") || data.starts_with(&0x0B17_C0DE_u32.to_le_bytes())
+}
+
+/// A custom section entry was encoded with an unsupported version.
+#[derive(Debug)]
+pub struct VersionError {
+	section: String,
+	version: u16,
+	input: Option<String>,
+}
+
+impl VersionError {
+	/// The version the entry was encoded with.
+	#[must_use]
+	pub fn version(&self) -> u16 {
+		self.version
+	}
+
+	/// Names the input the custom section was found in. The crate is derived
+	/// from the file name if it follows `rustc`'s `<crate>-<hash>.*.o` scheme.
+	#[must_use]
+	pub fn with_input(mut self, path: &Path) -> Self {
+		let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+		let input = match file_name.split_once('-') {
+			Some((name, _)) if name.chars().all(|c| c.is_alphanumeric() || c == '_') => {
+				format!("crate `{name}` (`{}`)", path.display())
+			}
+			_ => format!("`{}`", path.display()),
+		};
+
+		self.input = Some(input);
+		self
+	}
+}
+
+impl Display for VersionError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		if let Some(input) = &self.input {
+			write!(
+				f,
+				"{input} was built with `js-bindgen` encoding version {} in custom section `{}`, \
+				 but `js-bindgen-ld` supports version {CUSTOM_SECTION_VERSION}: ",
+				self.version, self.section
+			)?;
+		} else {
+			write!(
+				f,
+				"found custom section `{}` built with `js-bindgen` encoding version {}, but \
+				 `js-bindgen-ld` supports version {CUSTOM_SECTION_VERSION}: ",
+				self.section, self.version
+			)?;
+		}
+
+		if self.version > CUSTOM_SECTION_VERSION {
+			f.write_str("update `js-bindgen-ld`")
+		} else {
+			f.write_str("update `js-bindgen` in the crate that emitted it")
+		}
+	}
+}
+
+impl Error for VersionError {}
+
+/// Checks that all entries of one of our custom sections were encoded with a
+/// supported version.
+pub fn check_custom_section_version(
+	custom_section: &CustomSectionReader<'_>,
+) -> Result<(), VersionError> {
+	let mut parser = CustomSectionParser::new(custom_section);
+	while parser.next_entry()?.is_some() {}
+	Ok(())
+}
+
 #[derive(Clone)]
 pub struct JsBindgenAssemblySectionParser<'cs>(CustomSectionParser<'cs>);
 
@@ -191,6 +215,86 @@ impl<'cs> Iterator for JsBindgenAssemblySectionParser<'cs> {
 	}
 }
 
+/// Parses `js_bindgen.type.<export>` custom sections. Each entry is the
+/// TypeScript signature of the export without its name, e.g. `(value: number):
+/// string`.
+#[derive(Clone)]
+pub struct JsBindgenTypeSectionParser<'cs>(CustomSectionParser<'cs>);
+
+impl<'cs> JsBindgenTypeSectionParser<'cs> {
+	#[must_use]
+	pub fn new(custom_section: &CustomSectionReader<'cs>) -> Self {
+		Self(CustomSectionParser::new(custom_section))
+	}
+}
+
+impl Debug for JsBindgenTypeSectionParser<'_> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		let rest: Vec<_> = self.clone().collect();
+
+		f.debug_tuple("JsBindgenTypeSectionParser")
+			.field(&rest.as_slice())
+			.finish()
+	}
+}
+
+impl<'cs> Iterator for JsBindgenTypeSectionParser<'cs> {
+	type Item = &'cs str;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0
+			.next()
+			.map(str::from_utf8)
+			.transpose()
+			.unwrap_or_else(|error| {
+				panic!(
+					"found invalid TypeScript type encoding `{}`: {error}",
+					self.0.name
+				)
+			})
+	}
+}
+
+/// Parses `js_bindgen.export.<name>` custom sections. Each entry is the JS
+/// function wrapping the Wasm export, which is added as a method to the
+/// `JsBindgen` class.
+#[derive(Clone)]
+pub struct JsBindgenExportSectionParser<'cs>(CustomSectionParser<'cs>);
+
+impl<'cs> JsBindgenExportSectionParser<'cs> {
+	#[must_use]
+	pub fn new(custom_section: &CustomSectionReader<'cs>) -> Self {
+		Self(CustomSectionParser::new(custom_section))
+	}
+}
+
+impl Debug for JsBindgenExportSectionParser<'_> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		let rest: Vec<_> = self.clone().collect();
+
+		f.debug_tuple("JsBindgenExportSectionParser")
+			.field(&rest.as_slice())
+			.finish()
+	}
+}
+
+impl<'cs> Iterator for JsBindgenExportSectionParser<'cs> {
+	type Item = &'cs str;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0
+			.next()
+			.map(str::from_utf8)
+			.transpose()
+			.unwrap_or_else(|error| {
+				panic!(
+					"found invalid JS export encoding `{}`: {error}",
+					self.0.name
+				)
+			})
+	}
+}
+
 #[derive(Clone)]
 pub struct JsBindgenEmbedSectionParser<'cs>(CustomSectionParser<'cs>);
 
@@ -375,6 +479,145 @@ impl<'cs> Iterator for JsBindgenImportSectionParser<'cs> {
 	}
 }
 
+/// Parses the `js_bindgen.main_memory` custom section. Each entry is the module
+/// and name the main memory is imported from.
+#[derive(Clone)]
+pub struct JsBindgenMainMemorySectionParser<'cs>(CustomSectionParser<'cs>);
+
+impl<'cs> JsBindgenMainMemorySectionParser<'cs> {
+	#[must_use]
+	pub fn new(custom_section: &CustomSectionReader<'cs>) -> Self {
+		Self(CustomSectionParser::new(custom_section))
+	}
+}
+
+impl Debug for JsBindgenMainMemorySectionParser<'_> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		let rest: Vec<_> = self.clone().collect();
+
+		f.debug_tuple("JsBindgenMainMemorySectionParser")
+			.field(&rest.as_slice())
+			.finish()
+	}
+}
+
+impl<'cs> Iterator for JsBindgenMainMemorySectionParser<'cs> {
+	type Item = (&'cs str, &'cs str);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().map(|mut data| {
+			let mut string = || {
+				data.split_off(..2)
+					.and_then(|length| {
+						let length = usize::from(u16::from_le_bytes(length.try_into().unwrap()));
+						data.split_off(..length)
+					})
+					.and_then(|string| str::from_utf8(string).ok())
+					.unwrap_or_else(|| {
+						panic!("found invalid main memory encoding `{}`", self.0.name)
+					})
+			};
+
+			let module = string();
+			let name = string();
+			assert!(
+				data.is_empty(),
+				"found invalid main memory encoding `{}`",
+				self.0.name
+			);
+
+			(module, name)
+		})
+	}
+}
+
+/// Parses the `js_bindgen.test` custom section. Each entry describes a single
+/// test.
+#[derive(Clone)]
+pub struct JsBindgenTestSectionParser<'cs>(CustomSectionParser<'cs>);
+
+impl<'cs> JsBindgenTestSectionParser<'cs> {
+	#[must_use]
+	pub fn new(custom_section: &CustomSectionReader<'cs>) -> Self {
+		Self(CustomSectionParser::new(custom_section))
+	}
+}
+
+impl Debug for JsBindgenTestSectionParser<'_> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		let rest: Vec<_> = self.clone().collect();
+
+		f.debug_tuple("JsBindgenTestSectionParser")
+			.field(&rest.as_slice())
+			.finish()
+	}
+}
+
+impl<'cs> Iterator for JsBindgenTestSectionParser<'cs> {
+	type Item = &'cs [u8];
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next()
+	}
+}
+
+/// Parses the `target_features` custom section and yields all used features.
+/// Disallowed features are skipped.
+#[derive(Clone)]
+pub struct TargetFeaturesSectionParser<'cs> {
+	reader: BinaryReader<'cs>,
+	remaining: u32,
+}
+
+impl<'cs> TargetFeaturesSectionParser<'cs> {
+	#[must_use]
+	pub fn new(custom_section: &CustomSectionReader<'cs>) -> Self {
+		let mut reader = BinaryReader::new(custom_section.data(), custom_section.data_offset());
+		let remaining = reader
+			.read_var_u32()
+			.expect("found invalid `target_features` encoding");
+
+		Self { reader, remaining }
+	}
+}
+
+impl Debug for TargetFeaturesSectionParser<'_> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		let rest: Vec<_> = self.clone().collect();
+
+		f.debug_tuple("TargetFeaturesSectionParser")
+			.field(&rest.as_slice())
+			.finish()
+	}
+}
+
+impl<'cs> Iterator for TargetFeaturesSectionParser<'cs> {
+	type Item = &'cs str;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while self.remaining > 0 {
+			self.remaining -= 1;
+
+			let (prefix, feature) = self
+				.reader
+				.read_u8()
+				.and_then(|prefix| Ok((prefix, self.reader.read_string()?)))
+				.unwrap_or_else(|error| {
+					panic!("found invalid `target_features` encoding: {error}")
+				});
+
+			match prefix {
+				// Used or required.
+				b'+' | b'=' => return Some(feature),
+				b'-' => (),
+				_ => panic!("found invalid `target_features` prefix: {prefix:#x}"),
+			}
+		}
+
+		None
+	}
+}
+
 #[derive(Clone)]
 struct CustomSectionParser<'cs> {
 	name: &'cs str,
@@ -388,27 +631,47 @@ impl<'cs> CustomSectionParser<'cs> {
 			data: custom_section.data(),
 		}
 	}
+
+	fn next_entry(&mut self) -> Result<Option<&'cs [u8]>, VersionError> {
+		let Some(version) = self.data.split_off(..2) else {
+			assert!(
+				self.data.is_empty(),
+				"found left over bytes in custom section `{}`: {:?}",
+				self.name,
+				self.data
+			);
+
+			return Ok(None);
+		};
+		let version = u16::from_le_bytes(version.try_into().unwrap());
+
+		// When bumping the version, older versions should keep being supported
+		// here if possible.
+		if version != CUSTOM_SECTION_VERSION {
+			return Err(VersionError {
+				section: self.name.to_owned(),
+				version,
+				input: None,
+			});
+		}
+
+		let data = self
+			.data
+			.split_off(..4)
+			.and_then(|length| {
+				let length = u32::from_le_bytes(length.try_into().unwrap()) as usize;
+				self.data.split_off(..length)
+			})
+			.unwrap_or_else(|| panic!("invalid length encoding in custom section `{}`", self.name));
+
+		Ok(Some(data))
+	}
 }
 
 impl<'cs> Iterator for CustomSectionParser<'cs> {
 	type Item = &'cs [u8];
 
 	fn next(&mut self) -> Option<Self::Item> {
-		if let Some(length) = self.data.split_off(..4) {
-			let length = u32::from_le_bytes(length.try_into().unwrap()) as usize;
-
-			let data = self.data.split_off(..length).unwrap_or_else(|| {
-				panic!("invalid length encoding in custom section `{}`", self.name)
-			});
-
-			Some(data)
-		} else if self.data.is_empty() {
-			None
-		} else {
-			panic!(
-				"found left over bytes in custom section `{}`: {:?}",
-				self.name, self.data
-			);
-		}
+		self.next_entry().unwrap_or_else(|error| panic!("{error}"))
 	}
 }
@@ -0,0 +1,225 @@
+use indoc::indoc;
+use wasmparser::{
+	ImportSectionReader, KnownCustom, Linking, Parser, Payload, RelocationEntry, RelocationType,
+	SymbolInfo, TableType, TypeRef,
+};
+
+use crate::{Arch, TargetFeaturesSectionParser};
+
+const EXTERNREF: &str = indoc! {"
+	.import_module js_sys.externref.table, js_sys
+	.import_name js_sys.externref.table, externref.table
+	.tabletype js_sys.externref.table, externref, 1
+
+	.functype js_sys.externref.next () -> (i32)
+
+	.globl js_sys.externref.insert
+	js_sys.externref.insert:
+		.functype js_sys.externref.insert (externref) -> (i32)
+		.local i32
+		call js_sys.externref.next # Get the next free slot.
+		local.tee 1
+		local.get 0
+		table.set js_sys.externref.table
+		local.get 1
+		end_function
+"};
+
+#[test]
+fn imports() {
+	let object = super::assemble(Arch::Wasm32, EXTERNREF);
+
+	let imports = Parser::new(0)
+		.parse_all(&object)
+		.find_map(|payload| match payload.unwrap() {
+			Payload::ImportSection(i) => Some(i),
+			_ => None,
+		})
+		.map(ImportSectionReader::into_imports)
+		.unwrap()
+		.map(|import| {
+			let import = import.unwrap();
+			(import.module, import.name, import.ty)
+		})
+		.collect::<Vec<_>>();
+
+	assert!(matches!(
+		imports.as_slice(),
+		[
+			("env", "__linear_memory", TypeRef::Memory(memory)),
+			(
+				"js_sys",
+				"externref.table",
+				TypeRef::Table(TableType {
+					table64: false,
+					initial: 1,
+					..
+				})
+			),
+			("env", "js_sys.externref.next", TypeRef::Func(_)),
+		] if !memory.memory64
+	));
+}
+
+#[test]
+fn memory64() {
+	let object = super::assemble(Arch::Wasm64, EXTERNREF);
+
+	let memory64 = Parser::new(0)
+		.parse_all(&object)
+		.find_map(|payload| match payload.unwrap() {
+			Payload::ImportSection(i) => Some(i),
+			_ => None,
+		})
+		.map(ImportSectionReader::into_imports)
+		.unwrap()
+		.find_map(|import| match import.unwrap().ty {
+			TypeRef::Memory(memory) => Some(memory.memory64),
+			_ => None,
+		});
+
+	assert_eq!(memory64, Some(true));
+}
+
+#[test]
+fn linking() {
+	let object = super::assemble(Arch::Wasm32, EXTERNREF);
+	let KnownCustom::Linking(linking) = super::custom_section(&object, "linking") else {
+		unreachable!()
+	};
+	let symbols = linking
+		.subsections()
+		.find_map(|subsection| match subsection.unwrap() {
+			Linking::SymbolTable(symbols) => Some(symbols),
+			_ => None,
+		})
+		.unwrap()
+		.into_iter()
+		.map(Result::unwrap)
+		.collect::<Vec<_>>();
+
+	assert!(matches!(
+		symbols.as_slice(),
+		[
+			SymbolInfo::Table {
+				index: 0,
+				name: Some("js_sys.externref.table"),
+				..
+			},
+			SymbolInfo::Func {
+				index: 0,
+				name: None,
+				..
+			},
+			SymbolInfo::Func {
+				index: 1,
+				name: Some("js_sys.externref.insert"),
+				..
+			},
+		]
+	));
+}
+
+#[test]
+fn relocations() {
+	let object = super::assemble(Arch::Wasm32, EXTERNREF);
+
+	let KnownCustom::Reloc(relocations) = super::custom_section(&object, "reloc.CODE") else {
+		unreachable!()
+	};
+
+	// Type, import, function and code section.
+	assert_eq!(relocations.section_index(), 3);
+	assert_eq!(
+		relocations
+			.entries()
+			.into_iter()
+			.map(Result::unwrap)
+			.map(
+				|RelocationEntry {
+				     ty, offset, index, ..
+				 }| (ty, offset, index)
+			)
+			.collect::<Vec<_>>(),
+		[
+			(RelocationType::FunctionIndexLeb, 6, 1),
+			(RelocationType::TableNumberLeb, 16, 0),
+		]
+	);
+}
+
+#[test]
+fn error_line() {
+	assert_eq!(
+		super::error_line(indoc! {"
+			foo:
+				.functype foo () -> ()
+				i32.const 0
+				i32.foo
+				end_function
+		"}),
+		4
+	);
+	assert_eq!(
+		super::error_line(indoc! {"
+			.functype foo () -> ()
+
+			foo:
+				local.get 0
+				end_function
+		"}),
+		4
+	);
+	assert_eq!(
+		super::error_line(indoc! {"
+			.globl foo
+			foo:
+				.functype foo () -> ()
+				nop
+		"}),
+		2
+	);
+	assert_eq!(super::error_line("\n.section .text,\"\",@"), 2);
+}
+
+#[test]
+fn target_features() {
+	let object = crate::assembly_to_object(
+		Arch::Wasm32,
+		&["simd128", "reference-types", "atomics", "reference-types"],
+		EXTERNREF,
+	)
+	.unwrap();
+
+	let features = Parser::new(0)
+		.parse_all(&object)
+		.find_map(|payload| match payload.unwrap() {
+			Payload::CustomSection(c) if c.name() == "target_features" => {
+				Some(TargetFeaturesSectionParser::new(&c).collect::<Vec<_>>())
+			}
+			_ => None,
+		})
+		.unwrap();
+
+	assert_eq!(features, ["atomics", "reference-types", "simd128"]);
+}
+
+#[test]
+fn missing_target_feature() {
+	let error = crate::assembly_to_object(Arch::Wasm32, &[], EXTERNREF).unwrap_err();
+	assert_eq!(error.line(), 3);
+
+	let error = crate::assembly_to_object(
+		Arch::Wasm32,
+		&["reference-types"],
+		indoc! {"
+			foo:
+				.functype foo (i32) -> (i32)
+				local.get 0
+				i32.extend8_s
+				end_function
+		"},
+	)
+	.unwrap_err();
+	assert_eq!(error.line(), 4);
+}
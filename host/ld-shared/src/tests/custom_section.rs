@@ -0,0 +1,124 @@
+use std::borrow::Cow;
+use std::path::Path;
+
+use wasm_encoder::{CustomSection, Module};
+use wasmparser::{CustomSectionReader, Parser, Payload};
+
+use crate::{
+	CUSTOM_SECTION_VERSION, JsBindgenAssemblySectionParser, JsBindgenMainMemorySectionParser,
+};
+
+fn encode(name: &str, entries: &[(u16, &[u8])]) -> Vec<u8> {
+	let mut data = Vec::new();
+
+	for (version, entry) in entries {
+		data.extend_from_slice(&version.to_le_bytes());
+		data.extend_from_slice(&u32::try_from(entry.len()).unwrap().to_le_bytes());
+		data.extend_from_slice(entry);
+	}
+
+	let mut module = Module::new();
+	module.section(&CustomSection {
+		name: Cow::Borrowed(name),
+		data: Cow::Owned(data),
+	});
+	module.finish()
+}
+
+#[track_caller]
+fn reader(object: &[u8]) -> CustomSectionReader<'_> {
+	Parser::new(0)
+		.parse_all(object)
+		.find_map(|payload| match payload.unwrap() {
+			Payload::CustomSection(c) => Some(c),
+			_ => None,
+		})
+		.unwrap()
+}
+
+#[test]
+fn entries() {
+	let object = encode(
+		"js_bindgen.assembly",
+		&[
+			(CUSTOM_SECTION_VERSION, b"foo"),
+			(CUSTOM_SECTION_VERSION, b""),
+		],
+	);
+	let reader = reader(&object);
+
+	crate::check_custom_section_version(&reader).unwrap();
+	assert_eq!(
+		JsBindgenAssemblySectionParser::new(&reader).collect::<Vec<_>>(),
+		["foo", ""]
+	);
+}
+
+#[test]
+fn main_memory() {
+	let object = encode(
+		"js_bindgen.main_memory",
+		&[(CUSTOM_SECTION_VERSION, b"\x0A\x00js_bindgen\x06\x00memory")],
+	);
+
+	assert_eq!(
+		JsBindgenMainMemorySectionParser::new(&reader(&object)).collect::<Vec<_>>(),
+		[("js_bindgen", "memory")]
+	);
+}
+
+#[test]
+fn newer_version() {
+	let object = encode(
+		"js_bindgen.assembly",
+		&[
+			(CUSTOM_SECTION_VERSION, b"foo"),
+			(CUSTOM_SECTION_VERSION + 1, b"bar"),
+		],
+	);
+	let error = crate::check_custom_section_version(&reader(&object)).unwrap_err();
+
+	assert_eq!(error.version(), CUSTOM_SECTION_VERSION + 1);
+	assert!(
+		error.to_string().ends_with("update `js-bindgen-ld`"),
+		"{error}"
+	);
+}
+
+#[test]
+fn older_version() {
+	let object = encode("js_bindgen.assembly", &[(0, b"foo")]);
+	let error = crate::check_custom_section_version(&reader(&object)).unwrap_err();
+
+	assert_eq!(error.version(), 0);
+	assert!(
+		error
+			.to_string()
+			.ends_with("update `js-bindgen` in the crate that emitted it"),
+		"{error}"
+	);
+}
+
+#[test]
+fn version_error_input() {
+	let object = encode("js_bindgen.assembly", &[(0, b"foo")]);
+	let path = Path::new("deps/foo_bar-0123456789abcdef.foo_bar.1a2b-cgu.0.rcgu.o");
+	let error = crate::check_custom_section_version(&reader(&object))
+		.unwrap_err()
+		.with_input(path);
+
+	assert!(
+		error.to_string().starts_with(&format!(
+			"crate `foo_bar` (`{}`) was built with `js-bindgen` encoding version 0",
+			path.display()
+		)),
+		"{error}"
+	);
+}
+
+#[test]
+#[should_panic = "found custom section `js_bindgen.assembly` built with `js-bindgen` encoding version 65535"]
+fn parser_panics() {
+	let object = encode("js_bindgen.assembly", &[(u16::MAX, b"foo")]);
+	JsBindgenAssemblySectionParser::new(&reader(&object)).for_each(drop);
+}
@@ -0,0 +1,39 @@
+mod assembler;
+mod custom_section;
+
+use wasmparser::{KnownCustom, Parser, Payload, Validator, WasmFeatures};
+
+use crate::{Arch, assembly_to_object};
+
+const FEATURES: &[&str] = &["call-indirect-overlong", "reference-types"];
+
+/// Assembles the given input and validates the resulting object file.
+#[track_caller]
+fn assemble(arch: Arch, assembly: &str) -> Vec<u8> {
+	let object = assembly_to_object(arch, FEATURES, assembly).unwrap();
+	let mut features = WasmFeatures::default();
+	features.set(WasmFeatures::MEMORY64, arch == Arch::Wasm64);
+	Validator::new_with_features(features)
+		.validate_all(&object)
+		.unwrap();
+
+	object
+}
+
+#[track_caller]
+fn custom_section<'a>(object: &'a [u8], name: &str) -> KnownCustom<'a> {
+	Parser::new(0)
+		.parse_all(object)
+		.find_map(|payload| match payload.unwrap() {
+			Payload::CustomSection(c) if c.name() == name => Some(c.as_known()),
+			_ => None,
+		})
+		.unwrap_or_else(|| panic!("missing custom section `{name}`"))
+}
+
+#[track_caller]
+fn error_line(assembly: &str) -> usize {
+	assembly_to_object(Arch::Wasm32, FEATURES, assembly)
+		.unwrap_err()
+		.line()
+}
@@ -2,14 +2,20 @@ mod wasm_ld;
 
 use std::borrow::Cow;
 use std::convert::Infallible;
-use std::ffi::{OsStr, OsString};
+use std::ffi::OsString;
 use std::fs::File;
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::io::BufWriter;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::{self, Command};
 use std::{env, fs};
 
-use js_bindgen_ld_shared::JsBindgenAssemblySectionParser;
+use hashbrown::HashSet;
+use js_bindgen_ld_lib::JsTarget;
+use js_bindgen_ld_shared::{
+	Arch, CUSTOM_SECTION_VERSION, JsBindgenAssemblySectionParser, POISON_LIBRARY,
+	TargetFeaturesSectionParser, VersionError,
+};
 use js_bindgen_shared::ReadFile;
 use wasm_encoder::{CustomSection, Module};
 use wasmparser::{Parser, Payload};
@@ -18,18 +24,44 @@ use crate::wasm_ld::WasmLdArguments;
 
 fn main() {
 	// Read arguments.
-	let args = argfile::expand_args_from(env::args_os(), argfile::parse_response, argfile::PREFIX)
-		.unwrap();
-	let wasm_ld_args = WasmLdArguments::new(&args[1..]);
+	let mut args =
+		argfile::expand_args_from(env::args_os(), argfile::parse_response, argfile::PREFIX)
+			.unwrap();
+
+	// Our own arguments are not passed on to `wasm-ld`.
+	let mut js_target = JsTarget::default();
+	let mut inline_wasm = None;
+	args.retain(|arg| {
+		let arg = arg.to_str().unwrap_or_default();
+
+		if let Some(target) = arg.strip_prefix("--js-bindgen-target=") {
+			js_target = target.parse().unwrap();
+			false
+		} else if let Some(encoding) = arg.strip_prefix("--js-bindgen-inline-wasm=") {
+			inline_wasm = Some(encoding.parse().unwrap());
+			false
+		} else {
+			arg.strip_prefix("-l") != Some(POISON_LIBRARY)
+		}
+	});
 
-	if wasm_ld_args
-		.arg_single("flavor")
-		.filter(|v| *v == "wasm")
-		.is_none()
+	// `js-sys` links a library that doesn't exist, so the default linker fails.
+	while let Some(index) = args
+		.windows(2)
+		.position(|args| args[0] == "-l" && args[1] == POISON_LIBRARY)
 	{
-		panic!("the `js-bindgen-ld` should only be used when compiling to a Wasm target")
+		args.drain(index..index + 2);
 	}
 
+	let wasm_ld_args = WasmLdArguments::new(&args[1..]);
+
+	assert!(
+		wasm_ld_args
+			.arg_single("flavor")
+			.is_some_and(|v| v == "wasm"),
+		"the `js-bindgen-ld` should only be used when compiling to a Wasm target"
+	);
+
 	let output_path = Path::new(
 		wasm_ld_args
 			.arg_single("o")
@@ -37,16 +69,19 @@ fn main() {
 	);
 
 	// With Wasm32 no argument is passed, but Wasm64 requires `-mwasm64`.
-	let arch_str = if let Some(m) = wasm_ld_args.arg_single("m") {
-		if m == "wasm32" || m == "wasm64" {
-			Cow::Borrowed(m)
-		} else {
-			panic!("expected `-m` to either be `wasm32` or `wasm64");
-		}
-	} else {
-		Cow::Owned("wasm32".into())
+	let arch = match wasm_ld_args.arg_single("m") {
+		None => Arch::Wasm32,
+		Some(m) if m == "wasm32" => Arch::Wasm32,
+		Some(m) if m == "wasm64" => Arch::Wasm64,
+		Some(_) => panic!("expected `-m` to either be `wasm32` or `wasm64"),
 	};
 
+	// Assembly is compiled with the same target features as the rest of the code.
+	let features = wasm_ld_args.arg_comma_joined("features=");
+	let extra_features = wasm_ld_args
+		.arg_comma_joined("extra-features=")
+		.unwrap_or_default();
+
 	// Here we store additional arguments we want to pass to `wasm-ld`.
 	let mut add_args: Vec<OsString> = Vec::new();
 
@@ -54,15 +89,67 @@ fn main() {
 	process_main_memory(&wasm_ld_args, &mut add_args);
 
 	// Extract embedded assembly from object files.
+	let mut asm_objects = Vec::new();
+	let mut known_assemblies = HashSet::new();
+	let mut bitcode = Vec::new();
+
 	for input in wasm_ld_args.inputs() {
 		js_bindgen_ld_shared::ld_input_parser::<Infallible>(input, |path, data| {
-			process_object(&arch_str, &mut add_args, path, data);
+			if js_bindgen_ld_shared::is_llvm_bitcode(data) {
+				bitcode.push(path.to_owned());
+			} else {
+				process_object(
+					arch,
+					features.as_deref(),
+					&extra_features,
+					&mut known_assemblies,
+					&mut asm_objects,
+					path,
+					data,
+				);
+			}
+
 			Ok(())
 		});
 	}
 
+	// Link order determines the order of functions and imports in the output. Sort
+	// the object files and our assembly so the output doesn't depend on the order
+	// of the inputs.
+	let link_args = wasm_ld_args.sorted_args();
+	asm_objects.sort_unstable();
+
+	if !bitcode.is_empty() {
+		let mut command = Command::new("rust-lld");
+		command.args(&link_args).args(&add_args).args(&asm_objects);
+
+		process_bitcode(
+			arch,
+			features.as_deref(),
+			&extra_features,
+			&known_assemblies,
+			&mut asm_objects,
+			command,
+			output_path,
+		)
+		.unwrap_or_else(|error| {
+			// The sections of all bitcode inputs are merged by LTO, so we can't tell which
+			// one it came from.
+			let inputs: Vec<_> = bitcode
+				.iter()
+				.map(|path| format!("`{}`", path.display()))
+				.collect();
+			panic!(
+				"{error}\n\tin the LTO output of LLVM bitcode from {}",
+				inputs.join(", ")
+			)
+		});
+	}
+
+	add_args.extend(asm_objects.into_iter().map(OsString::from));
+
 	let status = Command::new("rust-lld")
-		.args(args.iter().skip(1))
+		.args(&link_args)
 		.args(add_args)
 		.status()
 		.unwrap();
@@ -74,12 +161,24 @@ fn main() {
 			env::var_os("CARGO_CRATE_NAME").expect("`CARGO_CRATE_NAME` should be present");
 		let wasm_input = ReadFile::new(output_path).expect("output file should be readable");
 
-		let js_output_path = output_path.with_extension("mjs");
+		let js_output_path = output_path.with_extension(js_target.js_extension());
 		let mut js_output = BufWriter::new(
 			File::create(&js_output_path).expect("output JS file should be writable"),
 		);
 
-		let wasm_output = js_bindgen_ld_lib::post_processing(&wasm_input, &mut js_output).unwrap();
+		let ts_output_path = output_path.with_extension(js_target.ts_extension());
+		let mut ts_output = BufWriter::new(
+			File::create(&ts_output_path).expect("output TypeScript file should be writable"),
+		);
+
+		let wasm_output = js_bindgen_ld_lib::post_processing(
+			&wasm_input,
+			js_target,
+			inline_wasm,
+			&mut js_output,
+			&mut ts_output,
+		)
+		.unwrap();
 		drop(wasm_input);
 
 		// We could write into the file directly, but `wasm-encoder` doesn't support
@@ -90,30 +189,158 @@ fn main() {
 		fs::write(output_path, wasm_output).expect("output Wasm file should be writable");
 
 		js_output.into_inner().unwrap().sync_all().unwrap();
+		ts_output.into_inner().unwrap().sync_all().unwrap();
 
 		// After the linker is done, Cargo copies the final output to be the name of the
-		// package without the fingerprint. We do the same for the JS file. TODO: Skip
-		// when detecting test.
+		// package without the fingerprint. We do the same for the JS and TypeScript
+		// file. TODO: Skip when detecting test.
+		let package_path = output_path.with_file_name(package);
 		fs::copy(
 			js_output_path,
-			output_path.with_file_name(package).with_extension("mjs"),
+			package_path.with_extension(js_target.js_extension()),
 		)
 		.expect("copy JS file should be success");
+		fs::copy(
+			ts_output_path,
+			package_path.with_extension(js_target.ts_extension()),
+		)
+		.expect("copy TypeScript file should be success");
 	}
 
 	process::exit(status.code().unwrap_or(1));
 }
 
 /// Extracts any assembly instructions from `js-bindgen`, builds object files
-/// from them and passes them to the linker.
+/// from them and collects their paths to be passed to the linker.
+///
+/// Assembly is compiled with the target features of the object it was found
+/// in, unless `--features=` overrides them. Like `wasm-ld`, `--extra-features=`
+/// is added on top.
 fn process_object(
-	arch_str: &OsStr,
-	add_args: &mut Vec<OsString>,
+	arch: Arch,
+	features: Option<&[&str]>,
+	extra_features: &[&str],
+	known_assemblies: &mut HashSet<String>,
+	asm_objects: &mut Vec<PathBuf>,
 	archive_path: &Path,
 	object: &[u8],
 ) {
-	// Multiple files from the same object file need different names.
-	let mut file_counter = 0;
+	let (assemblies, object_features) = read_custom_sections(object)
+		.unwrap_or_else(|error| panic!("{}", error.with_input(archive_path)));
+
+	let mut features = features.map_or(object_features, <[_]>::to_vec);
+	features.extend_from_slice(extra_features);
+
+	// The same object can be linked with different features, e.g. by changing
+	// `-Clink-arg=--extra-features=`, which Cargo's fingerprinting doesn't see.
+	let mut hasher = DefaultHasher::new();
+	features.hash(&mut hasher);
+	let features_hash = hasher.finish();
+
+	for (index, assembly) in assemblies.into_iter().enumerate() {
+		known_assemblies.insert(assembly.to_owned());
+
+		// Multiple files from the same object file need different names.
+		let asm_path =
+			archive_path.with_added_extension(format!("asm.{features_hash:016x}.{}.o", index + 1));
+
+		// Only compile if the file doesn't already exist. Existing fingerprinting
+		// ensures freshness of the object, the features are part of the name:
+		// https://doc.rust-lang.org/1.92.0/nightly-rustc/cargo/core/compiler/fingerprint/index.html#fingerprints-and-unithashs
+		if !asm_path.exists() {
+			let asm_object = js_bindgen_ld_shared::assembly_to_object(arch, &features, assembly)
+				.unwrap_or_else(|error| {
+					panic!(
+						"failed to assemble `js_bindgen.assembly` section #{index} in `{}`: \
+						 {error}",
+						archive_path.display()
+					)
+				});
+
+			fs::write(&asm_path, asm_object).expect("output assembly object should be writable");
+		}
+
+		asm_objects.push(asm_path);
+	}
+}
+
+/// Custom sections in LLVM bitcode, e.g. with `-Clinker-plugin-lto`, are only
+/// accessible after LTO. So we link once while ignoring the symbols defined by
+/// the missing assembly and extract it from the output.
+///
+/// This doubles the LTO work of every build with bitcode inputs. The output of
+/// this link is thrown away, so it is done without optimizations to keep the
+/// cost down.
+///
+/// The output also contains the assembly of all regular objects, which was
+/// already compiled, so entries with the same content are skipped. Assembly
+/// generated by our macros always defines global symbols, so two such entries
+/// couldn't be linked together anyway. But identical `unsafe_embed_asm!` blocks
+/// without any global symbols in a regular object and in bitcode are only
+/// linked once.
+///
+/// Assembly is compiled with the target features of the output, unless
+/// `--features=` overrides them.
+fn process_bitcode(
+	arch: Arch,
+	features: Option<&[&str]>,
+	extra_features: &[&str],
+	known_assemblies: &HashSet<String>,
+	asm_objects: &mut Vec<PathBuf>,
+	mut command: Command,
+	output_path: &Path,
+) -> Result<(), VersionError> {
+	let lto_path = output_path.with_extension("lto.wasm");
+	let status = command
+		.arg("--unresolved-symbols=ignore-all")
+		// Custom sections don't depend on the optimization level.
+		.args(["--lto-O0", "--lto-CGO0"])
+		.arg("-o")
+		.arg(&lto_path)
+		.status()
+		.unwrap();
+	assert!(
+		status.success(),
+		"failed to link LLVM bitcode inputs, which is required to extract `js-bindgen` custom \
+		 sections from them"
+	);
+
+	let lto_output = ReadFile::new(&lto_path).expect("LTO output file should be readable");
+	let (assemblies, output_features) = read_custom_sections(&lto_output)?;
+
+	let mut features = features.map_or(output_features, <[_]>::to_vec);
+	features.extend_from_slice(extra_features);
+
+	// Assembly from regular object files was already taken care of.
+	for (index, assembly) in assemblies
+		.into_iter()
+		.filter(|assembly| !known_assemblies.contains(*assembly))
+		.enumerate()
+	{
+		let asm_path = output_path.with_extension(format!("lto.asm.{}.o", index + 1));
+		let asm_object = js_bindgen_ld_shared::assembly_to_object(arch, &features, assembly)
+			.unwrap_or_else(|error| {
+				panic!(
+					"failed to assemble `js_bindgen.assembly` section #{index} from LLVM \
+					 bitcode: {error}"
+				)
+			});
+
+		fs::write(&asm_path, asm_object).expect("output assembly object should be writable");
+		asm_objects.push(asm_path);
+	}
+
+	drop(lto_output);
+	fs::remove_file(lto_path).expect("LTO output file should be removable");
+
+	Ok(())
+}
+
+/// Reads all assembly and the used target features. Fails if any of our custom
+/// sections was encoded with an unsupported version.
+fn read_custom_sections(object: &[u8]) -> Result<(Vec<&str>, Vec<&str>), VersionError> {
+	let mut assemblies = Vec::new();
+	let mut features = Vec::new();
 
 	for payload in Parser::new(0).parse_all(object) {
 		let payload = match payload {
@@ -124,32 +351,22 @@ fn process_object(
 			}
 		};
 
-		// We are only interested in reading custom sections with our name.
-		if let Payload::CustomSection(c) = payload
-			&& c.name() == "js_bindgen.assembly"
-		{
-			for assembly in JsBindgenAssemblySectionParser::new(&c) {
-				file_counter += 1;
-				let asm_path = archive_path.with_added_extension(format!("asm.{file_counter}.o"));
-
-				// Only compile if the file doesn't already exist. Existing fingerprinting
-				// ensures freshness:
-				// https://doc.rust-lang.org/1.92.0/nightly-rustc/cargo/core/compiler/fingerprint/index.html#fingerprints-and-unithashs
-				if !asm_path.exists() {
-					let mut asm_file = BufWriter::new(
-						File::create(&asm_path).expect("output assembly object should be writable"),
-					);
-
-					js_bindgen_ld_shared::assembly_to_object(arch_str, assembly, &mut asm_file)
-						.expect("compiling assembly should be valid");
-
-					asm_file.into_inner().unwrap().sync_all().unwrap();
-				}
-
-				add_args.push(asm_path.into());
+		// We are only interested in reading our custom sections and the target
+		// features.
+		if let Payload::CustomSection(c) = payload {
+			if c.name().starts_with("js_bindgen.") {
+				js_bindgen_ld_shared::check_custom_section_version(&c)?;
+			}
+
+			match c.name() {
+				"js_bindgen.assembly" => assemblies.extend(JsBindgenAssemblySectionParser::new(&c)),
+				"target_features" => features.extend(TargetFeaturesSectionParser::new(&c)),
+				_ => (),
 			}
 		}
 	}
+
+	Ok((assemblies, features))
 }
 
 fn process_main_memory(wasm_ld_args: &WasmLdArguments<'_>, add_args: &mut Vec<OsString>) {
@@ -194,11 +411,15 @@ fn process_main_memory(wasm_ld_args: &WasmLdArguments<'_>, add_args: &mut Vec<Os
 	// Embed main memory path.
 	let main_memory_obj_path = output_path.with_extension("main_memory.asm.o");
 	let mut module = Module::new();
+	let mut entry = Vec::new();
+	entry.extend_from_slice(&u16::try_from(main_memory.0.len()).unwrap().to_le_bytes());
+	entry.extend_from_slice(main_memory.0.as_bytes());
+	entry.extend_from_slice(&u16::try_from(main_memory.1.len()).unwrap().to_le_bytes());
+	entry.extend_from_slice(main_memory.1.as_bytes());
 	let mut data = Vec::new();
-	data.extend_from_slice(&u16::try_from(main_memory.0.len()).unwrap().to_le_bytes());
-	data.extend_from_slice(main_memory.0.as_bytes());
-	data.extend_from_slice(&u16::try_from(main_memory.1.len()).unwrap().to_le_bytes());
-	data.extend_from_slice(main_memory.1.as_bytes());
+	data.extend_from_slice(&CUSTOM_SECTION_VERSION.to_le_bytes());
+	data.extend_from_slice(&u32::try_from(entry.len()).unwrap().to_le_bytes());
+	data.append(&mut entry);
 	module.section(&CustomSection {
 		name: Cow::Borrowed("js_bindgen.main_memory"),
 		data: Cow::Owned(data),
@@ -1,4 +1,5 @@
 use std::ffi::{OsStr, OsString};
+use std::path::Path;
 
 use hashbrown::HashMap;
 
@@ -19,21 +20,25 @@ enum OptKind {
 }
 
 pub(crate) struct WasmLdArguments<'a> {
+	args: &'a [OsString],
 	table: HashMap<&'a str, Vec<&'a OsStr>>,
 	inputs: Vec<&'a OsString>,
+	/// Positions of `inputs` in `args`.
+	input_indices: Vec<usize>,
 }
 
-impl WasmLdArguments<'_> {
+impl<'a> WasmLdArguments<'a> {
 	// See the LLVM parser implementation:
 	// https://github.com/llvm/llvm-project/blob/llvmorg-21.1.8/llvm/lib/Option/OptTable.cpp#L436-L498.
-	pub(crate) fn new(args: &[OsString]) -> WasmLdArguments<'_> {
-		let mut args = args.iter();
+	pub(crate) fn new(all_args: &'a [OsString]) -> Self {
+		let mut args = all_args.iter().enumerate();
 		let mut table = HashMap::new();
 		let mut inputs = Vec::new();
+		let mut input_indices = Vec::new();
 
 		let option_table: HashMap<&str, OptKind> = HashMap::from(OPT_KIND);
 
-		while let Some(arg) = args.next() {
+		while let Some((index, arg)) = args.next() {
 			let bytes = arg.as_encoded_bytes();
 			// If a value does not start with `-`, it is treated as `INPUT`.
 			let Some(stripped) = bytes
@@ -45,6 +50,7 @@ impl WasmLdArguments<'_> {
 				.map(|bytes| unsafe { OsStr::from_encoded_bytes_unchecked(bytes) })
 			else {
 				inputs.push(arg);
+				input_indices.push(index);
 				continue;
 			};
 
@@ -68,6 +74,7 @@ impl WasmLdArguments<'_> {
 			let mut next = || {
 				args.next()
 					.unwrap_or_else(|| panic!("`{}` argument should have a value", arg.display()))
+					.1
 					.as_os_str()
 			};
 			let value = match kind {
@@ -84,7 +91,12 @@ impl WasmLdArguments<'_> {
 			}
 		}
 
-		WasmLdArguments { table, inputs }
+		WasmLdArguments {
+			args: all_args,
+			table,
+			inputs,
+			input_indices,
+		}
 	}
 
 	pub(crate) fn arg_single(&self, arg: &str) -> Option<&OsStr> {
@@ -104,7 +116,47 @@ impl WasmLdArguments<'_> {
 		}
 	}
 
+	/// Collects all values of a [`OptKind::CommaJoined`] argument, which can be
+	/// passed multiple times.
+	pub(crate) fn arg_comma_joined(&self, arg: &str) -> Option<Vec<&str>> {
+		self.table.get(arg).map(|values| {
+			values
+				.iter()
+				.flat_map(|value| {
+					value
+						.to_str()
+						.unwrap_or_else(|| panic!("`{arg}` parameters should be valid UTF-8"))
+						.split(',')
+				})
+				.filter(|value| !value.is_empty())
+				.collect()
+		})
+	}
+
 	pub(crate) fn inputs(&self) -> &[&OsString] {
 		&self.inputs
 	}
+
+	/// Returns all arguments with the object files sorted, so the output doesn't
+	/// depend on their order.
+	///
+	/// Only runs of object files are sorted. Archives are searched for undefined
+	/// symbols in order, so which archive's definition wins depends on their
+	/// position. Options like `--whole-archive` apply to the inputs following
+	/// them.
+	pub(crate) fn sorted_args(&self) -> Vec<&'a OsString> {
+		let mut args: Vec<_> = self.args.iter().collect();
+		let objects: Vec<_> = self
+			.input_indices
+			.iter()
+			.copied()
+			.filter(|&index| Path::new(&self.args[index]).extension() == Some(OsStr::new("o")))
+			.collect();
+
+		for run in objects.chunk_by(|a, b| a + 1 == *b) {
+			args[run[0]..=run[run.len() - 1]].sort_unstable();
+		}
+
+		args
+	}
 }
@@ -0,0 +1,496 @@
+//! End-to-end tests linking fixture objects through `js-bindgen-ld`.
+//!
+//! Every fixture is linked with its objects in every possible order. The
+//! output has to be byte-identical across all of them and the generated JS and
+//! TypeScript files are compared against the golden files in `tests/e2e`. Run
+//! with `BLESS=1` to update them.
+
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::{env, fs, str};
+
+use itertools::Itertools;
+use js_bindgen_ld_shared::{Arch, CUSTOM_SECTION_VERSION, POISON_LIBRARY};
+use wasm_encoder::{CustomSection, Module};
+
+struct Object {
+	name: &'static str,
+	assembly: &'static str,
+	imports: &'static [Import],
+	embeds: &'static [Embed],
+	exports: &'static [Export],
+}
+
+struct Import {
+	module: &'static str,
+	name: &'static str,
+	requires: Option<&'static str>,
+	js: &'static str,
+}
+
+struct Embed {
+	module: &'static str,
+	name: &'static str,
+	requires: Option<&'static str>,
+	js: &'static str,
+}
+
+struct Export {
+	name: &'static str,
+	js: &'static str,
+	ts: Option<&'static str>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct Output {
+	wasm: Vec<u8>,
+	js: String,
+	ts: String,
+}
+
+const LOG: Object = Object {
+	name: "log",
+	assembly: indoc::indoc!(
+		"
+		.import_module test_crate.import.log, test_crate
+		.import_name test_crate.import.log, log
+		.functype test_crate.import.log (i32) -> ()
+
+		.globl test_crate.log
+		test_crate.log:
+			.functype test_crate.log (i32) -> ()
+			local.get 0
+			call test_crate.import.log
+			end_function
+		"
+	),
+	imports: &[Import {
+		module: "test_crate",
+		name: "log",
+		requires: None,
+		js: "globalThis.console.log",
+	}],
+	embeds: &[],
+	exports: &[],
+};
+
+#[test]
+fn basic() {
+	test("basic", &["test_crate.log"], &[LOG]);
+}
+
+#[test]
+fn order() {
+	test(
+		"order",
+		&["web_sys.log", "web_sys.warn", "js_sys.decode"],
+		&[
+			Object {
+				name: "web_sys_log",
+				assembly: indoc::indoc!(
+					"
+					.import_module web_sys.import.console.log, web_sys
+					.import_name web_sys.import.console.log, console.log
+					.functype web_sys.import.console.log (i32) -> ()
+
+					.globl web_sys.log
+					web_sys.log:
+						.functype web_sys.log (i32) -> ()
+						local.get 0
+						call web_sys.import.console.log
+						end_function
+					"
+				),
+				imports: &[Import {
+					module: "web_sys",
+					name: "console.log",
+					requires: None,
+					js: "globalThis.console.log",
+				}],
+				embeds: &[],
+				exports: &[],
+			},
+			Object {
+				name: "web_sys_warn",
+				assembly: indoc::indoc!(
+					"
+					.import_module web_sys.import.console.warn, web_sys
+					.import_name web_sys.import.console.warn, console.warn
+					.functype web_sys.import.console.warn (i32) -> ()
+
+					.globl web_sys.warn
+					web_sys.warn:
+						.functype web_sys.warn (i32) -> ()
+						local.get 0
+						call web_sys.import.console.warn
+						end_function
+					"
+				),
+				imports: &[Import {
+					module: "web_sys",
+					name: "console.warn",
+					requires: None,
+					js: "globalThis.console.warn",
+				}],
+				embeds: &[],
+				exports: &[],
+			},
+			Object {
+				name: "js_sys",
+				assembly: indoc::indoc!(
+					"
+					.import_module js_sys.import.string_decode, js_sys
+					.import_name js_sys.import.string_decode, string_decode
+					.functype js_sys.import.string_decode (i32, i32) -> ()
+
+					.globl js_sys.decode
+					js_sys.decode:
+						.functype js_sys.decode (i32, i32) -> ()
+						local.get 0
+						local.get 1
+						call js_sys.import.string_decode
+						end_function
+					"
+				),
+				imports: &[Import {
+					module: "js_sys",
+					name: "string_decode",
+					requires: Some("string.decode"),
+					js: "(ptr, len) => this.#jsEmbed.js_sys['string.decode'](ptr, len)",
+				}],
+				embeds: &[
+					Embed {
+						module: "js_sys",
+						name: "string.decode",
+						requires: Some("string.decoder"),
+						js: indoc::indoc!(
+							"(ptr, len) => {
+								const view = new Uint8Array(this.#memory.buffer, ptr, len)
+								return this.#jsEmbed.js_sys['string.decoder'].decode(view)
+							}"
+						),
+					},
+					Embed {
+						module: "js_sys",
+						name: "string.decoder",
+						requires: None,
+						js: "new TextDecoder('utf-8')",
+					},
+				],
+				exports: &[],
+			},
+		],
+	);
+}
+
+/// Adds JS wrappers of exports to the `JsBindgen` class, typed by their type
+/// hints.
+#[test]
+fn export() {
+	test(
+		"export",
+		&["test_crate.log", "test_crate.add", "test_crate.get"],
+		&[
+			LOG,
+			Object {
+				name: "export",
+				assembly: indoc::indoc!(
+					"
+					.globl test_crate.add
+					test_crate.add:
+						.functype test_crate.add (i32, i32) -> (i32)
+						local.get 0
+						local.get 1
+						i32.add
+						end_function
+
+					.globl test_crate.get
+					test_crate.get:
+						.functype test_crate.get () -> (f64)
+						f64.const 1
+						end_function
+					"
+				),
+				imports: &[],
+				embeds: &[],
+				exports: &[
+					Export {
+						name: "add",
+						js: indoc::indoc!(
+							"(a, b) => {
+								return ((value) => value >>> 0)(this.#instance.exports['test_crate.add'](a, b))
+							}"
+						),
+						ts: Some("(a: number, b: number): number"),
+					},
+					Export {
+						name: "get",
+						js: "() => this.#instance.exports['test_crate.get']()",
+						ts: None,
+					},
+				],
+			},
+		],
+	);
+}
+
+/// Links the bitcode built from `tests/e2e/bitcode.ll` together with a regular
+/// object, which requires extracting assembly after LTO.
+#[test]
+fn bitcode() {
+	test_inputs(
+		"bitcode",
+		&["lto_crate.run"],
+		&[
+			(LOG.name, encode_object(&LOG)),
+			("bitcode", fs::read(e2e_dir().join("bitcode.bc")).unwrap()),
+		],
+	);
+}
+
+/// Links regular objects containing code, whose order would otherwise carry
+/// over to the output.
+#[test]
+fn code() {
+	let code = |name: &'static str, value: i32| {
+		let assembly = format!(
+			".globl {name}\n{name}:\n\t.functype {name} () -> (i32)\n\ti32.const \
+			 {value}\n\tend_function\n"
+		);
+		let object = js_bindgen_ld_shared::assembly_to_object(Arch::Wasm32, &[], &assembly);
+		(name, object.unwrap())
+	};
+
+	test_inputs(
+		"code",
+		&["test_crate.log", "code_a", "code_b"],
+		&[
+			(LOG.name, encode_object(&LOG)),
+			code("code_a", 1),
+			code("code_b", 2),
+		],
+	);
+}
+
+/// Links the same object with different features, which must not reuse the
+/// assembly compiled for the previous link.
+#[test]
+fn features() {
+	let dir = tempfile::tempdir().unwrap();
+	let input = (LOG.name, encode_object(&LOG));
+
+	link_in(dir.path(), &[], &["test_crate.log"], &[&input]);
+	link_in(
+		dir.path(),
+		&["--extra-features=bulk-memory"],
+		&["test_crate.log"],
+		&[&input],
+	);
+
+	let asm_objects = fs::read_dir(dir.path())
+		.unwrap()
+		.map(|entry| entry.unwrap().file_name().into_string().unwrap())
+		.filter(|name| name.starts_with("log.o.asm."))
+		.count();
+	assert_eq!(asm_objects, 2);
+}
+
+fn test(name: &str, exports: &[&str], objects: &[Object]) {
+	let inputs: Vec<_> = objects
+		.iter()
+		.map(|object| (object.name, encode_object(object)))
+		.collect();
+	test_inputs(name, exports, &inputs);
+}
+
+/// Links the inputs in every order and compares the result against the golden
+/// files.
+fn test_inputs(name: &str, exports: &[&str], inputs: &[(&str, Vec<u8>)]) {
+	let mut expected: Option<Output> = None;
+
+	for permutation in inputs.iter().permutations(inputs.len()) {
+		let order = permutation.iter().map(|(name, _)| name).join(", ");
+		let output = link(exports, &permutation);
+
+		if let Some(expected) = &expected {
+			assert!(
+				expected.wasm == output.wasm,
+				"found different Wasm output for order: {order}"
+			);
+			similar_asserts::assert_eq!(
+				expected.js,
+				output.js,
+				"found different JS output for order: {order}"
+			);
+			similar_asserts::assert_eq!(
+				expected.ts,
+				output.ts,
+				"found different TypeScript output for order: {order}"
+			);
+		} else {
+			expected = Some(output);
+		}
+	}
+
+	let expected = expected.unwrap();
+	let golden = e2e_dir().join(name);
+	assert_golden(&golden.with_extension("mjs"), &expected.js);
+	assert_golden(&golden.with_extension("d.mts"), &expected.ts);
+}
+
+fn link(exports: &[&str], inputs: &[&(&str, Vec<u8>)]) -> Output {
+	let dir = tempfile::tempdir().unwrap();
+	link_in(dir.path(), &[], exports, inputs)
+}
+
+fn link_in(dir: &Path, args: &[&str], exports: &[&str], inputs: &[&(&str, Vec<u8>)]) -> Output {
+	let output_path = dir.join("output.wasm");
+
+	let mut command = Command::new(env!("CARGO_BIN_EXE_js-bindgen-ld"));
+	command
+		.env("CARGO_CRATE_NAME", "test_crate")
+		.env("PATH", path_with_rust_lld())
+		.args(["-flavor", "wasm", "--no-entry", "-o"])
+		.arg(&output_path)
+		// `rustc` passes this for every crate depending on `js-sys`.
+		.args(["-l", POISON_LIBRARY])
+		.args(exports.iter().map(|export| format!("--export={export}")))
+		.args(args);
+
+	for (name, data) in inputs {
+		let path = dir.join(name).with_extension("o");
+		fs::write(&path, data).unwrap();
+		command.arg(path);
+	}
+
+	let output = command.output().unwrap();
+	assert!(
+		output.status.success(),
+		"failed to link:\n{}",
+		String::from_utf8_lossy(&output.stderr)
+	);
+
+	Output {
+		wasm: fs::read(&output_path).unwrap(),
+		js: fs::read_to_string(output_path.with_extension("mjs")).unwrap(),
+		ts: fs::read_to_string(output_path.with_extension("d.mts")).unwrap(),
+	}
+}
+
+/// Encodes an object file with the custom sections the macros would emit.
+fn encode_object(object: &Object) -> Vec<u8> {
+	let mut module = Module::new();
+
+	custom_section(
+		&mut module,
+		"js_bindgen.assembly",
+		entry(object.assembly.as_bytes()),
+	);
+
+	for import in object.imports {
+		let mut data = Vec::new();
+
+		if let Some(embed) = import.requires {
+			data.push(2);
+			data.extend_from_slice(&u16::try_from(embed.len()).unwrap().to_le_bytes());
+			data.extend_from_slice(embed.as_bytes());
+		} else {
+			data.push(0);
+		}
+
+		data.extend_from_slice(import.js.as_bytes());
+		custom_section(
+			&mut module,
+			&format!("js_bindgen.import.{}.{}", import.module, import.name),
+			entry(&data),
+		);
+	}
+
+	for embed in object.embeds {
+		let embed_name = embed.requires.unwrap_or_default();
+		let mut data = Vec::new();
+		data.extend_from_slice(&u16::try_from(embed_name.len()).unwrap().to_le_bytes());
+		data.extend_from_slice(embed_name.as_bytes());
+		data.extend_from_slice(embed.js.as_bytes());
+		custom_section(
+			&mut module,
+			&format!("js_bindgen.embed.{}.{}", embed.module, embed.name),
+			entry(&data),
+		);
+	}
+
+	for export in object.exports {
+		custom_section(
+			&mut module,
+			&format!("js_bindgen.export.{}", export.name),
+			entry(export.js.as_bytes()),
+		);
+
+		if let Some(ts) = export.ts {
+			custom_section(
+				&mut module,
+				&format!("js_bindgen.type.{}", export.name),
+				entry(ts.as_bytes()),
+			);
+		}
+	}
+
+	custom_section(&mut module, "linking", vec![2]);
+
+	module.finish()
+}
+
+fn custom_section(module: &mut Module, name: &str, data: Vec<u8>) {
+	module.section(&CustomSection {
+		name: Cow::Borrowed(name),
+		data: Cow::Owned(data),
+	});
+}
+
+/// Prefixes custom section data with the encoding version and its length.
+fn entry(data: &[u8]) -> Vec<u8> {
+	let mut entry = CUSTOM_SECTION_VERSION.to_le_bytes().to_vec();
+	entry.extend_from_slice(&u32::try_from(data.len()).unwrap().to_le_bytes());
+	entry.extend_from_slice(data);
+	entry
+}
+
+/// `rustc` usually adds the directory containing `rust-lld` to `PATH` when
+/// calling the linker.
+fn path_with_rust_lld() -> String {
+	let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+	let output = Command::new(rustc)
+		.args(["--print", "target-libdir"])
+		.output()
+		.unwrap();
+	assert!(output.status.success(), "failed to query `rustc`");
+
+	let target_libdir = PathBuf::from(str::from_utf8(&output.stdout).unwrap().trim());
+	let bin = target_libdir.parent().unwrap().join("bin");
+
+	env::join_paths(
+		[bin]
+			.into_iter()
+			.chain(env::split_paths(&env::var_os("PATH").unwrap_or_default())),
+	)
+	.unwrap()
+	.into_string()
+	.unwrap()
+}
+
+fn e2e_dir() -> PathBuf {
+	Path::new(env!("CARGO_MANIFEST_DIR"))
+		.join("tests")
+		.join("e2e")
+}
+
+fn assert_golden(path: &Path, actual: &str) {
+	if env::var_os("BLESS").is_some_and(|v| v == "1") {
+		fs::write(path, actual).unwrap();
+	} else {
+		let expected = fs::read_to_string(path)
+			.unwrap_or_else(|error| panic!("failed to read `{}`: {error}", path.display()));
+		similar_asserts::assert_eq!(expected, actual, "found outdated `{}`", path.display());
+	}
+}
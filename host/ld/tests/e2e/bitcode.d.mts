@@ -0,0 +1,18 @@
+export interface Exports extends WebAssembly.Exports {
+    'lto_crate.run'(arg0: number): void;
+}
+export interface Instance extends WebAssembly.Instance {
+    readonly exports: Exports;
+}
+export interface JsBindgen {
+}
+export declare class JsBindgen {
+    #private;
+    constructor(module: WebAssembly.Module);
+    get importObject(): WebAssembly.Imports;
+    extendImportObject(imports: WebAssembly.Imports): void;
+    setInstance(instance: WebAssembly.Instance): void;
+    instantiate(): Promise<Instance>;
+    static instantiateStreaming(): Promise<Instance>;
+    static instantiateStreaming(...args: Parameters<typeof fetch>): Promise<Instance>;
+}
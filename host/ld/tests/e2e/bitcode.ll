@@ -0,0 +1,18 @@
+; Built with `llvm-as bitcode.ll -o bitcode.bc`.
+
+target datalayout = "e-m:e-p:32:32-p10:8:8-p20:8:8-i64:64-n32:64-S128-ni:1:10:20"
+target triple = "wasm32-unknown-unknown"
+
+define void @"lto_crate.run"(i32 %0) {
+  call void @"test_crate.log"(i32 %0)
+  call void @"lto_crate.warn"(i32 %0)
+  ret void
+}
+
+declare void @"test_crate.log"(i32)
+
+declare void @"lto_crate.warn"(i32)
+
+!wasm.custom_sections = !{!0, !1}
+!0 = !{!"js_bindgen.assembly", !"\01\001\01\00\00.import_module lto_crate.import.console.warn, lto_crate\0A.import_name lto_crate.import.console.warn, console.warn\0A.functype lto_crate.import.console.warn (i32) -> ()\0A\0A.globl lto_crate.warn\0Alto_crate.warn:\0A\09.functype lto_crate.warn (i32) -> ()\0A\09local.get 0\0A\09call lto_crate.import.console.warn\0A\09end_function\0A"}
+!1 = !{!"js_bindgen.import.lto_crate.console.warn", !"\01\00\18\00\00\00\00globalThis.console.warn"}
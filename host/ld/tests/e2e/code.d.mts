@@ -0,0 +1,20 @@
+export interface Exports extends WebAssembly.Exports {
+    code_a(): number;
+    code_b(): number;
+    'test_crate.log'(arg0: number): void;
+}
+export interface Instance extends WebAssembly.Instance {
+    readonly exports: Exports;
+}
+export interface JsBindgen {
+}
+export declare class JsBindgen {
+    #private;
+    constructor(module: WebAssembly.Module);
+    get importObject(): WebAssembly.Imports;
+    extendImportObject(imports: WebAssembly.Imports): void;
+    setInstance(instance: WebAssembly.Instance): void;
+    instantiate(): Promise<Instance>;
+    static instantiateStreaming(): Promise<Instance>;
+    static instantiateStreaming(...args: Parameters<typeof fetch>): Promise<Instance>;
+}
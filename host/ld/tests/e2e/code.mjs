@@ -0,0 +1,86 @@
+export class JsBindgen {
+    #finished = false;
+    #importObject;
+    // @ts-expect-error TS6133
+    #instance;
+    // @ts-expect-error TS6133
+    #jsEmbed;
+    // @ts-expect-error TS6133
+    #memory = new WebAssembly.Memory({ initial: 1 });
+    #module;
+    constructor(module) {
+        this.#module = module;
+        this.#jsEmbed = {
+		};
+        this.#importObject = {
+			js_bindgen: { memory: this.#memory },
+			test_crate: {
+				'log': globalThis.console.log,
+			},
+		};
+        Object.assign(this, {
+		});
+    }
+    get importObject() {
+        if (this.#finished) {
+            throw "create a new `JsBindgen` class";
+        }
+        else {
+            return this.#importObject;
+        }
+    }
+    extendImportObject(imports) {
+        if (this.#finished) {
+            throw "create a new `JsBindgen` class";
+        }
+        for (const namespace in imports) {
+            if (!this.#importObject[namespace]) {
+                continue;
+            }
+            for (const symbol in imports[namespace]) {
+                if (this.#importObject[namespace][symbol]) {
+                    throw `found conflicting symbol: \`${namespace}:${symbol}\``;
+                }
+            }
+        }
+        for (const namespace in imports) {
+            if (!this.#importObject[namespace]) {
+                this.#importObject[namespace] = {};
+            }
+            Object.assign(this.#importObject[namespace], imports[namespace]);
+        }
+    }
+    setInstance(instance) {
+        if (this.#finished) {
+            throw "create a new `JsBindgen` class";
+        }
+        this.#instance = instance;
+        this.#finished = true;
+    }
+    instantiate() {
+        if (this.#finished) {
+            throw "create a new `JsBindgen` class";
+        }
+        this.#finished = true;
+        return WebAssembly.instantiate(this.#module, this.#importObject).then((instance) => {
+            this.#instance = instance;
+            return instance;
+        });
+    }
+    static async instantiateStreaming(...args) {
+        let response;
+        if (args.length === 0) {
+            const wasm = undefined;
+            if (wasm !== undefined) {
+                return new JsBindgen(await WebAssembly.compile(wasm)).instantiate();
+            }
+            const url = import.meta.url.replace(/\.mjs$/, ".wasm");
+            response = fetch(url);
+        }
+        else {
+            response = fetch(...args);
+        }
+        const module = await WebAssembly.compileStreaming(response);
+        return new JsBindgen(module).instantiate();
+    }
+}
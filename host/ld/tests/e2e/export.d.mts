@@ -0,0 +1,22 @@
+export interface Exports extends WebAssembly.Exports {
+    'test_crate.add'(arg0: number, arg1: number): number;
+    'test_crate.get'(): number;
+    'test_crate.log'(arg0: number): void;
+}
+export interface Instance extends WebAssembly.Instance {
+    readonly exports: Exports;
+}
+export interface JsBindgen {
+    add(a: number, b: number): number;
+    get(...args: unknown[]): unknown;
+}
+export declare class JsBindgen {
+    #private;
+    constructor(module: WebAssembly.Module);
+    get importObject(): WebAssembly.Imports;
+    extendImportObject(imports: WebAssembly.Imports): void;
+    setInstance(instance: WebAssembly.Instance): void;
+    instantiate(): Promise<Instance>;
+    static instantiateStreaming(): Promise<Instance>;
+    static instantiateStreaming(...args: Parameters<typeof fetch>): Promise<Instance>;
+}
@@ -1,6 +1,7 @@
 use std::fmt::Display;
 use std::iter::{self, Peekable};
 
+pub use js_bindgen_shared::CUSTOM_SECTION_VERSION;
 use proc_macro2::{
 	Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree, token_stream,
 };
@@ -25,10 +26,14 @@ pub enum ArgumentKind {
 ///
 /// 	const _: () = {
 /// 		#[repr(C)]
-/// 		struct Layout([u8; 4], #([u8; LEN_<index>]),*);
+/// 		struct Layout([u8; 2], [u8; 4], #([u8; LEN_<index>]),*);
 ///
 /// 		#[link_section = name]
-/// 		static CUSTOM_SECTION: Layout = Layout(::core::primitive::u32::to_le_bytes(LEN), #(ARR_<index>),*);
+/// 		static CUSTOM_SECTION: Layout = Layout(
+/// 			::core::primitive::u16::to_le_bytes(CUSTOM_SECTION_VERSION),
+/// 			::core::primitive::u32::to_le_bytes(LEN),
+/// 			#(ARR_<index>),*
+/// 		);
 /// 	};
 /// };
 /// ```
@@ -223,8 +228,17 @@ pub fn custom_section(name: &str, data: &[Argument]) -> TokenStream {
 		)],
 	);
 
-	// `[u8; 4], #([u8; LEN_<index>]),*`
+	// `[u8; 2], [u8; 4], #([u8; LEN_<index>]),*`
 	let tys = [
+		group(
+			Delimiter::Bracket,
+			[
+				ident("u8"),
+				Punct::new(';', Spacing::Alone).into(),
+				Literal::usize_unsuffixed(2).into(),
+			],
+		),
+		Punct::new(',', Spacing::Alone).into(),
 		group(
 			Delimiter::Bracket,
 			[
@@ -291,10 +305,19 @@ pub fn custom_section(name: &str, data: &[Argument]) -> TokenStream {
 		),
 	];
 
-	// (::core::primitive::u32::to_le_bytes(LEN), #(ARR_<index>),*)
+	// (::core::primitive::u16::to_le_bytes(<version>), ::core::primitive::u32::to_le_bytes(LEN),
+	// #(ARR_<index>),*)
 	let values = group(
 		Delimiter::Parenthesis,
-		path(["core", "primitive", "u32", "to_le_bytes"], span)
+		path(["core", "primitive", "u16", "to_le_bytes"], span)
+			.chain([
+				group(
+					Delimiter::Parenthesis,
+					iter::once(Literal::u16_unsuffixed(CUSTOM_SECTION_VERSION).into()),
+				),
+				Punct::new(',', Spacing::Alone).into(),
+			])
+			.chain(path(["core", "primitive", "u32", "to_le_bytes"], span))
 			.chain([
 				group(Delimiter::Parenthesis, iter::once(ident("LEN"))),
 				Punct::new(',', Spacing::Alone).into(),
@@ -319,23 +342,45 @@ pub fn custom_section(name: &str, data: &[Argument]) -> TokenStream {
 		Punct::new(';', Spacing::Alone).into(),
 	];
 
+	// Custom sections are only read by `js-bindgen-ld`, other targets get
+	// nothing.
+	// `#[cfg(target_family = "wasm")]`
+	let cfg = [
+		Punct::new('#', Spacing::Alone).into(),
+		group(
+			Delimiter::Bracket,
+			[
+				ident("cfg"),
+				group(
+					Delimiter::Parenthesis,
+					[
+						ident("target_family"),
+						Punct::new('=', Spacing::Alone).into(),
+						Literal::string("wasm").into(),
+					],
+				),
+			],
+		),
+	];
+
 	// `const _: () = { ... }`
-	r#const(
-		"_",
-		iter::once(group(Delimiter::Parenthesis, iter::empty())),
-		iter::once(group(
-			Delimiter::Brace,
-			consts.chain(len).chain(r#const(
-				"_",
-				iter::once(group(Delimiter::Parenthesis, iter::empty())),
-				iter::once(group(
-					Delimiter::Brace,
-					layout.into_iter().chain(link_section).chain(custom_section),
+	cfg.into_iter()
+		.chain(r#const(
+			"_",
+			iter::once(group(Delimiter::Parenthesis, iter::empty())),
+			iter::once(group(
+				Delimiter::Brace,
+				consts.chain(len).chain(r#const(
+					"_",
+					iter::once(group(Delimiter::Parenthesis, iter::empty())),
+					iter::once(group(
+						Delimiter::Brace,
+						layout.into_iter().chain(link_section).chain(custom_section),
+					)),
 				)),
 			)),
-		)),
-	)
-	.collect()
+		))
+		.collect()
 }
 
 pub fn parse_meta_name_value(
@@ -378,10 +423,17 @@ pub fn parse_ty_or_value(
 					found = true;
 				} else if p.as_char() == '*' {
 					let star = stream.next().unwrap();
-					let r#const =
-						expect_ident(&mut stream, "const", star.span(), "`*const`", true)?;
-					span.end = r#const.span();
-					out.extend_from_slice(&[star, r#const.into()]);
+					let mutability = parse_ident(&mut stream, star.span(), "`*const` or `*mut`")?;
+
+					if mutability != "const" && mutability != "mut" {
+						return Err(compile_error(
+							(star.span(), mutability.span()),
+							"expected `*const` or `*mut`",
+						));
+					}
+
+					span.end = mutability.span();
+					out.extend_from_slice(&[star, mutability.into()]);
 					found = true;
 				}
 			}
@@ -40,3 +40,8 @@ impl Deref for ReadFile {
 		}
 	}
 }
+
+/// Version of the custom section encoding. Every entry in our custom sections
+/// starts with the version it was encoded with. Has to be bumped on every
+/// incompatible change, which requires `js-bindgen-ld` to be updated as well.
+pub const CUSTOM_SECTION_VERSION: u16 = 1;